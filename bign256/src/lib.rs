@@ -143,4 +143,4 @@ impl elliptic_curve::sec1::ValidatePublicKey for BignP256 {}
 
 /// Bit representation of a BIGN P-256 scalar field element.
 #[cfg(feature = "bits")]
-pub type ScalarBits = elliptic_curve::ScalarBits<BignP256>;
+pub type ScalarBits = elliptic_curve::scalar::ScalarBits<BignP256>;
@@ -4,9 +4,13 @@ use hex_literal::hex;
 
 /// Repeated addition of the generator.
 ///
-/// These are the first 20 test vectors, generated by sagemath snippet.
+/// These are the first 21 test vectors, generated by sagemath snippet.
 pub const ADD_TEST_VECTORS: &[([u8; 32], [u8; 32])] = &[
-    // k = 1,..,20, p += G
+    // k = 1,..,21, p += G
+    (
+        hex!("0000000000000000000000000000000000000000000000000000000000000000"),
+        hex!("6BF7FC3CFB16D69F5CE4C9A351D6835D78913966C408F6521E29CF1804516A93"),
+    ),
     (
         hex!("2D8419E3D905D21E3611261DADC95BB35583090C2CADD0C807F53BB3828F0995"),
         hex!("E62690D81BDC754EFAF5B2770054564849621E8A933A7ECD6C02387649CF258A"),
@@ -95,163 +99,163 @@ pub const ADD_TEST_VECTORS: &[([u8; 32], [u8; 32])] = &[
 /// k values is from NIST-P256 test vectors
 pub const MUL_TEST_VECTORS: &[([u8; 32], [u8; 32], [u8; 32])] = &[
     (
+        hex!("000000000000000000000000000000000000000000000000018EBBB95EED0E13"),
         hex!("BCE6EE467AA910ADD6FB47DF33EC4825582596B8C694DB7B403129C9427CF647"),
         hex!("1D89D067B0EB566FBA7CA700A48AA90026F9584AFAA33139B890BCB21729140E"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("0000000000000000000000000000000000159D893D4CDD747246CDCA43590E13"),
         hex!("321D9319C3BE889BA0FD29C2E2688ABF39308A712A1D81446ED1ABC789E52CA9"),
         hex!("E3B297D31A709BCD61B9861069EAEB8162237F970C11245C7E1761E2FC802B7A"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("41FFC1FFFFFE01FFFC0003FFFE0007C001FFF00003FFF07FFE0007C000000003"),
         hex!("132175D5AE957336F608DC53257205D81B088C5BDF048AF2C83B47784D70617C"),
         hex!("1ADF305FCF83BA7C5B035615290644A70D381F50666D9B907673385CBCBD4791"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("7FFFFFC03FFFC003FFFFFC007FFF00000000070000100000000E00FFFFFFF3FF"),
         hex!("BC01F7A6067E4529252C7E6F8F7D6EFA7889B8455ED042F0DE0870B419653658"),
         hex!("65037BB63611DFE97DC043ED085181A4AA3A0D1D54C77C36135182ACF444265D"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("0000FFFFF01FFFF8FFFFC00FFFFFFFFFC000000FFFFFC007FFFFFC000FFFE3FF"),
         hex!("8E13BE6EAB0F05B8F0C07509DC8C17922B354ED5CF5E88D732E4EE031735319B"),
         hex!("F0F23BA15E570754D21773E9D7B3D471B239415A29BB23D2C33DB3082D32966D"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("4000008000FFFFFC000003F00000FFFFFFFF800003800F8000E0000E000000FF"),
         hex!("5859FC33184C7A38EE37FA61F13A47C96FF3E2D1A21347DF32297E3A7FA19FCD"),
         hex!("F37B8AFF67104403A992853E307C988CE8F148E5A5D9F0091B094EC3E11CF0C0"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("003FFFFFF0001F80000003F80003FFFFC0000000000FFE0000007FF818000F80"),
         hex!("1CF0FC9B67C3496E6104C6FE4CFEB03C582D6121E17B27FF5F163697021B1CA3"),
         hex!("7ED1D7E43E06686AD6A050982927E54894022A55A872165CEDD82F1936800AC9"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("000001C000000000001001F803FFFFFF80000000000007FF0000000000000000"),
         hex!("5251712A9696E4D3F4CC1C4B26877C200335311B03C9D53C8FC2ACE8ECECA49C"),
         hex!("B04C72917AF4AAFA772314D49A2B899CD5D1D4F71FD0F18882050E61CEC361F9"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("7FC0007FFFFFFC0003FFFFFFFFFFFFFE00003FFFFF07FFFFFFFFFFFFC007FFFF"),
         hex!("7EF4573793DE5E95C749C810952408750B669FBFC4F177C27D74E66B95617258"),
         hex!("6F7372A2DA5690F539834B9A8DD1910120E148C74F9376391F2DD41F8529DB57"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("7FFFFC03FF807FFFE0001FFFFF800FFF800001FFFF0001FFFFFE001FFFC00000"),
         hex!("85E00E5546713D9F2F5C8E605CA49AA16FA77CE3A3323B463599C31289697E37"),
         hex!("CC258D7CA2CB8ACD629701844FE26AFE2029E127A59024E9885174E8BC35BB3F"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("00FFFFFFFE03FFFC07FFFC800070000FC0007FFC00000000000FFFE1FBFF81FF"),
         hex!("796F1AA06F3366EF6C74822CD69D274D73FD7947D99E5A388D87525613C0164F"),
         hex!("69FB53A5AE70C701BFCE800A78676265E87F3EDF6A1059A438B2A16A291DFEB9"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("01FFF81FC000000000FF801FFFC0F81F01FFF8001FC005FFFFFF800000FFFFFC"),
         hex!("660CEBCB17AAC38F454210254037CDE84ED2671B7C6B3531995DFAA478F01F20"),
         hex!("3E752FDD0749DE0128C2A9237875413FB0C7866E9CD0F86B248272676728B6DF"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC63253D"),
         hex!("108E86DBB15D670F952C36DDBADC381B7EE06052DED0C1FC369BC721F537BC21"),
         hex!("35CA247AF507BE8B5FA662AFB35EB9F3DA55CB4E67FF048FA2757EBCF8ABF046"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC63253E"),
         hex!("62B8EBD7808F1A50B851D9080984B4A23181AF1CC60F2DE11F80CABBE27E213E"),
         hex!("52A44CCB834C87D5CAA20C1B42260F9EDC1C30731C2F2F14AE2D52A7AF243794"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC63253F"),
         hex!("DD219D558AF61D9099822705054BA6F23F1378A5F482B1EC5AAD69138065B1D6"),
         hex!("2C51E0EDE3618C1057E0360BECF3A550FE95E6096B9CD9326C0FEF0B68506859"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632540"),
         hex!("6C9E47CE0CCA48F4EDB53D0A02459C8D00BC670C82A4D091ADA36A158DB74DCB"),
         hex!("7896936848785A4CCBBE5B39A3111EB3D36895B4ECDD911F3CBC4F08B0CE0FFC"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632541"),
         hex!("A320F2ED3C408FEB68BB456A75E14B943DA7B02B0C8A76A5737DE66F008148C4"),
         hex!("C6AC14D4F31C17D720AD8A4711BD0DB84D9AE8FC6070289EA1883775D410C690"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632542"),
         hex!("84221BA012EFC0694EC2DDB3B6B003FB92CD329F193D2A0A316050391DF99667"),
         hex!("E91DA84671D47149BD8343291CB00F4639E90828A005176F58541F2A0DCDF185"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632543"),
         hex!("F4BD16F5E8BE79055EE7B62FF8FE9381A20D2182CCE62CA77C740C7B627C0384"),
         hex!("38B1E87028B46FF7742999AABB634D713DA2810C159A004E8A2C13CAF2428D96"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632544"),
         hex!("99773AFF98782D076D6D759943BC1F3E9DD57A464E622A7E23ADC96413A55841"),
         hex!("9BA741511B2EB4465A9CB0B21133A47A1FD9163B7946289C31221EC60E1FF8B5"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632545"),
         hex!("1666D4DA3F23A9CF7EB2CE3DEF0ACB5C781F7EF080F169DF887EB39ED0CCBD48"),
         hex!("C4779DF3C1F5DAAE8C0DB77BE0CF9D49F249647360A867C36A20047CF5D18CDC"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632546"),
         hex!("F7D96B2CE1766AC5DF7C0CF5CDE463101334182908635F43F24B61975DE671BF"),
         hex!("04B069AD735EE63E50AE27E5D7EDD6257134C6E8D8CA3AE3040001A9855682A2"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632547"),
         hex!("2F78EF54DD714BA141A893B9DEF9C14AA47900440C8C42ED90AE05D2E8665E21"),
         hex!("1DDE14D0959610C2966258889FD025666B746765FCA36F6025309026145EFD3A"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632548"),
         hex!("3B1514BF33F6EC2C5FEEA324D04F06ECBC516B358400D67B67181029F4AF8AC9"),
         hex!("0F7917B7EEAF7E7836D5FD8BDB488858CFE2E78BD9FA66757EF4D89BFAA27F69"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632549"),
         hex!("67CC0FDF4E1735A5FCB98168158945DDE241FBA39BE2B35A5CD904CA5EB88A52"),
         hex!("2C9B912969C98E7B7ED01CCDB9F32951A529BA77F40DC79B2241DA3D6FAD6C00"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC63254A"),
         hex!("97C9D36D07BDAA3846CDAE30E68EA4D5A84896A7A94056B4424B08B58E41BB84"),
         hex!("B528D08C013362D444A9A345570E59B2E5265720B8B4F128ED6700994618990B"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC63254B"),
         hex!("AED8D377B262039F5FA3625CBE7DDAD5129B350B9A54CE58B4E51C36DB2355B5"),
         hex!("8A8B1F284CE3D769477334C6569FCFB6BAE71E8E5E1509873366A7695961B06C"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC63254C"),
         hex!("5C01A3FEB3B295E76F091B20E652ED48A59F9AEC46CBA86160A96B59BFF6276D"),
         hex!("502DBD20E1F58654546891CB66480C52155A0A45F7BD2FDE2563F2E0D5F5CD2B"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC63254D"),
         hex!("FA2879ADDCD74E29E049EAAD5279A01987D08EF515693E721572EEF5D0445278"),
         hex!("332FEE155643CEFD408350C49CC776E1589770F217B2F47389FFBAE3AA8F9DE4"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC63254E"),
         hex!("D0B331A63DD4815CEF494557A5CA040296E19737E0D84A8AB95C0B7404799584"),
         hex!("4E982EF880233592068F58E117BE4A13EC544FB10248E10821EE4037A5466C86"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC63254F"),
         hex!("51A0F158FF49D20AE469441FC627516DCB1D1161119A7B9F9531D52C8F26A36A"),
         hex!("5047FC9B8057392D20057845073F58AF80DE2C1DB80D8C814AFE6C21A4033581"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
     (
+        hex!("FFFFFFFF00000000FFFFFFFFFFFFFFFFBCE6FAADA7179E84F3B9CAC2FC632550"),
         hex!("F02838F64C02057A1191C490C51501A5C261A3201A0330E1A31B67FE121C9434"),
         hex!("172B364C2952F8ED8771B3B152DF85A8039E9F6D7B2669E90078D2D47393902B"),
-        hex!("0000000000000000000000000000000000000000000000000000000000000001"),
     ),
 ];
@@ -2,14 +2,14 @@
 
 #![cfg(all(feature = "arithmetic", feature = "test-vectors"))]
 
+use bign256::{
+    test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
+    AffinePoint, ProjectivePoint, Scalar,
+};
 use elliptic_curve::{
     group::{ff::PrimeField, GroupEncoding},
     sec1::{self, ToEncodedPoint},
 };
-use p256::{
-    test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
-    AffinePoint, ProjectivePoint, Scalar,
-};
 use primeorder::{impl_projective_arithmetic_tests, Double};
 
 impl_projective_arithmetic_tests!(
@@ -0,0 +1,19 @@
+//! brainpoolP256r1 scalar multiplication benchmarks
+
+use bp256::{r1::ProjectivePoint, Scalar};
+use criterion::{criterion_group, criterion_main, Criterion};
+use elliptic_curve::{ops::MulByGenerator, Field};
+use rand_core::OsRng;
+
+fn bench_mul_by_generator(c: &mut Criterion) {
+    let mut group = c.benchmark_group("mul_by_generator");
+    let k = Scalar::random(&mut OsRng);
+    group.bench_function("precomputed", |b| {
+        b.iter(|| ProjectivePoint::mul_by_generator(&k))
+    });
+    group.bench_function("generic", |b| b.iter(|| ProjectivePoint::GENERATOR * k));
+    group.finish();
+}
+
+criterion_group!(benches, bench_mul_by_generator);
+criterion_main!(benches);
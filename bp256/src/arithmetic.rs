@@ -2,3 +2,6 @@
 
 pub(crate) mod field;
 pub(crate) mod scalar;
+
+#[cfg(feature = "hash2curve")]
+mod hash2curve;
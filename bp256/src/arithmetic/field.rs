@@ -29,6 +29,9 @@ use elliptic_curve::{
     Error, Result,
 };
 
+#[cfg(feature = "alloc")]
+use elliptic_curve::subtle::ConditionallySelectable;
+
 /// Constant representing the modulus serialized as hex.
 const MODULUS_HEX: &str = "a9fb57dba1eea9bc3e660a909d838d726e3bf623d52620282013481d1f6e5377";
 
@@ -95,7 +98,7 @@ impl FieldElement {
 
     /// Returns the big-endian encoding of this [`FieldElement`].
     pub fn to_bytes(self) -> FieldBytes {
-        self.0.to_be_byte_array()
+        self.to_canonical().to_be_byte_array()
     }
 
     /// Translate [`FieldElement`] out of the Montgomery domain, returning a
@@ -162,6 +165,11 @@ impl FieldElement {
         )))
     }
 
+    /// Multiply by a single `u64` multiplier.
+    pub const fn mul_single(&self, rhs: u64) -> Self {
+        self.multiply(&Self::from_u64(rhs))
+    }
+
     /// Negate element.
     pub const fn neg(&self) -> Self {
         Self(U256::from_words(fiat_bp256_opp(self.0.as_words())))
@@ -219,6 +227,48 @@ impl FieldElement {
         CtOption::new(self.invert_unchecked(), !self.is_zero())
     }
 
+    /// Invert a batch of field elements in place using Montgomery's trick,
+    /// at the cost of a single inversion plus `3(n - 1)` multiplications.
+    ///
+    /// Zero elements are left as zero; the returned [`Choice`] reports
+    /// whether any input was zero. All inputs are processed in constant
+    /// time regardless of their values.
+    #[cfg(feature = "alloc")]
+    pub fn batch_invert(elements: &mut [Self]) -> Choice {
+        let mut any_zero = Choice::from(0);
+
+        if elements.is_empty() {
+            return any_zero;
+        }
+
+        // Running prefix products, with zeros masked to one.
+        let mut prefixes = alloc::vec::Vec::with_capacity(elements.len());
+        let mut acc = Self::ONE;
+
+        for element in elements.iter() {
+            let is_zero = element.is_zero();
+            any_zero |= is_zero;
+            acc *= Self::conditional_select(element, &Self::ONE, is_zero);
+            prefixes.push(acc);
+        }
+
+        // The combined product is a product of non-zero elements.
+        let mut inv = acc.invert_unchecked();
+
+        for i in (0..elements.len()).rev() {
+            let element = elements[i];
+            let is_zero = element.is_zero();
+            let masked = Self::conditional_select(&element, &Self::ONE, is_zero);
+
+            let element_inv = if i == 0 { inv } else { inv * prefixes[i - 1] };
+            inv *= masked;
+
+            elements[i] = Self::conditional_select(&element_inv, &Self::ZERO, is_zero);
+        }
+
+        any_zero
+    }
+
     /// Returns the multiplicative inverse of self.
     ///
     /// Does not check that self is non-zero.
@@ -319,4 +369,80 @@ mod tests {
     impl_field_invert_tests!(FieldElement);
     impl_field_sqrt_tests!(FieldElement);
     impl_primefield_tests!(FieldElement, T);
+
+    /// Arbitrary field elements used to exercise `sqrt` beyond small integers.
+    const SQRT_TEST_ELEMENTS: &[FieldElement] = &[
+        FieldElement::from_hex("0e0cf395b2680663697e2aa5c4f9203762e0df2e1c5f8b9d33b4839c3f8d7ad1"),
+        FieldElement::from_hex("5a7e1710b9f5e1e18b6b7c153345fe5a9b672a2b2e189d6ce588a3544bb7b7e5"),
+        FieldElement::from_hex("a1b2c3d4e5f60718293a4b5c6d7e8f90123456789abcdef0fedcba9876543210"),
+    ];
+
+    #[test]
+    fn sqrt_of_squares_roundtrips() {
+        for &fe in SQRT_TEST_ELEMENTS {
+            let square = fe.square();
+            let sqrt = square.sqrt().unwrap();
+            assert!(sqrt == fe || sqrt == -fe);
+            assert_eq!(sqrt.square(), square);
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_invert_matches_invert() {
+        let elements = [
+            FieldElement::ONE,
+            FieldElement::from(2u64),
+            SQRT_TEST_ELEMENTS[0],
+            SQRT_TEST_ELEMENTS[1],
+        ];
+
+        let mut batch = elements;
+        assert!(!bool::from(FieldElement::batch_invert(&mut batch)));
+
+        for (batch_inv, element) in batch.iter().zip(elements.iter()) {
+            assert_eq!(*batch_inv, element.invert().unwrap());
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_invert_masks_zeros() {
+        // zeros at the start, middle, and end of the slice
+        for zero_index in [0, 2, 4] {
+            let mut elements = [
+                FieldElement::from(3u64),
+                FieldElement::from(5u64),
+                FieldElement::from(7u64),
+                FieldElement::from(11u64),
+                FieldElement::from(13u64),
+            ];
+            let expected: alloc::vec::Vec<_> = elements
+                .iter()
+                .enumerate()
+                .map(|(i, fe)| {
+                    if i == zero_index {
+                        FieldElement::ZERO
+                    } else {
+                        fe.invert().unwrap()
+                    }
+                })
+                .collect();
+
+            elements[zero_index] = FieldElement::ZERO;
+            assert!(bool::from(FieldElement::batch_invert(&mut elements)));
+            assert_eq!(elements.as_slice(), expected.as_slice());
+        }
+
+        // empty slice reports no zeros
+        assert!(!bool::from(FieldElement::batch_invert(&mut [])));
+    }
+
+    #[test]
+    fn sqrt_of_nonresidue_is_none() {
+        // 11 is the smallest multiplicative generator of the field, and
+        // therefore a quadratic non-residue (the group order is even).
+        let non_residue = FieldElement::from(11u64);
+        assert!(bool::from(non_residue.sqrt().is_none()));
+    }
 }
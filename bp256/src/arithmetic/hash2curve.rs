@@ -0,0 +1,188 @@
+//! hash2curve (RFC 9380) support for brainpoolP256r1.
+//!
+//! brainpool curves have a * b != 0, so the simplified SWU map applies to the
+//! curve directly without an isogeny. The Z parameter below was derived with
+//! the `find_z_sswu` algorithm from RFC 9380 Appendix H.2 (Z = -2 for the
+//! brainpoolP256r1 base field).
+
+use super::field::FieldElement;
+use crate::{
+    r1::{AffinePoint, BrainpoolP256r1, ProjectivePoint},
+    FieldBytes, Scalar, U256,
+};
+use elliptic_curve::{
+    bigint::ArrayEncoding,
+    consts::U48,
+    generic_array::GenericArray,
+    hash2curve::{FromOkm, GroupDigest, MapToCurve, OsswuMap, OsswuMapParams, Sgn0},
+    point::DecompressPoint,
+    subtle::Choice,
+};
+
+impl GroupDigest for BrainpoolP256r1 {
+    type FieldElement = FieldElement;
+}
+
+impl FromOkm for FieldElement {
+    type Length = U48;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        /// 2^192 mod p
+        const F_2_192: FieldElement = FieldElement::from_hex(
+            "0000000000000001000000000000000000000000000000000000000000000000",
+        );
+
+        let mut d0_bytes = FieldBytes::default();
+        d0_bytes[8..].copy_from_slice(&data[..24]);
+        let d0 = FieldElement::from_uint_unchecked(U256::from_be_byte_array(d0_bytes));
+
+        let mut d1_bytes = FieldBytes::default();
+        d1_bytes[8..].copy_from_slice(&data[24..]);
+        let d1 = FieldElement::from_uint_unchecked(U256::from_be_byte_array(d1_bytes));
+
+        d0 * F_2_192 + d1
+    }
+}
+
+impl Sgn0 for FieldElement {
+    fn sgn0(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
+impl OsswuMap for FieldElement {
+    const PARAMS: OsswuMapParams<Self> = OsswuMapParams {
+        // (p - 3) / 4
+        c1: &[
+            0x0804_d207_47db_94dd,
+            0x9b8e_fd88_f549_880a,
+            0x0f99_82a4_2760_e35c,
+            0x2a7e_d5f6_e87b_aa6f,
+        ],
+        // sqrt(-Z^3) mod p
+        c2: FieldElement::from_hex(
+            "1b0ea54f90ade9903a5291a8c27ad7f50c8aa53bb88f0b7639f2cce84f9027b9",
+        ),
+        // curve equation coefficient a
+        map_a: FieldElement::from_hex(
+            "7d5a0975fc2c3057eef67530417affe7fb8055c126dc5c6ce94a4b44f330b5d9",
+        ),
+        // curve equation coefficient b
+        map_b: FieldElement::from_hex(
+            "26dc5c6ce94a4b44f330b5d9bbd77cbf958416295cf7e1ce6bccdc18ff8c07b6",
+        ),
+        // Z = -2
+        z: FieldElement::from_u64(2).neg(),
+    };
+}
+
+impl MapToCurve for FieldElement {
+    type Output = ProjectivePoint;
+
+    fn map_to_curve(&self) -> Self::Output {
+        let (qx, qy) = self.osswu();
+
+        AffinePoint::decompress(&qx.to_bytes(), qy.is_odd())
+            .unwrap()
+            .into()
+    }
+}
+
+impl FromOkm for Scalar {
+    type Length = U48;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        /// 2^192 mod n
+        const F_2_192: Scalar =
+            Scalar::from_hex("0000000000000001000000000000000000000000000000000000000000000000");
+
+        let mut d0_bytes = FieldBytes::default();
+        d0_bytes[8..].copy_from_slice(&data[..24]);
+        let d0 = Scalar::from_uint_unchecked(U256::from_be_byte_array(d0_bytes));
+
+        let mut d1_bytes = FieldBytes::default();
+        d1_bytes[8..].copy_from_slice(&data[24..]);
+        let d1 = Scalar::from_uint_unchecked(U256::from_be_byte_array(d1_bytes));
+
+        d0 * F_2_192 + d1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FieldElement;
+    use crate::{r1::BrainpoolP256r1, Scalar};
+    use elliptic_curve::{
+        group::cofactor::CofactorGroup,
+        hash2curve::{ExpandMsgXmd, FromOkm, GroupDigest, MapToCurve, OsswuMap},
+        sec1::ToEncodedPoint,
+    };
+    use sha2::Sha256;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BP256_XMD:SHA-256_SSWU_RO_";
+
+    #[test]
+    fn z_is_nonsquare() {
+        let z = <FieldElement as OsswuMap>::PARAMS.z;
+        assert!(bool::from(z.sqrt().is_none()));
+
+        // c2^2 == -z^3
+        let c2 = <FieldElement as OsswuMap>::PARAMS.c2;
+        assert_eq!(c2.square(), -(z * z * z));
+    }
+
+    #[test]
+    fn map_to_curve_outputs_valid_points() {
+        for u in [
+            FieldElement::ZERO,
+            FieldElement::ONE,
+            FieldElement::from_u64(0xdeadbeef),
+        ] {
+            let point = u.map_to_curve().to_affine();
+            // a valid affine point always encodes/decodes cleanly
+            let encoded = point.to_encoded_point(false);
+            assert!(!encoded.is_identity());
+        }
+    }
+
+    #[test]
+    fn hash_from_bytes_on_curve_and_dst_sensitive() {
+        let p1 =
+            BrainpoolP256r1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[DST]).unwrap();
+        let p2 = BrainpoolP256r1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[b"other"])
+            .unwrap();
+        let p3 =
+            BrainpoolP256r1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"abd"], &[DST]).unwrap();
+
+        assert!(!bool::from(p1.is_small_order()));
+        assert_ne!(p1, p2);
+        assert_ne!(p1, p3);
+    }
+
+    #[test]
+    fn empty_dst_rejected() {
+        assert!(BrainpoolP256r1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[]).is_err());
+    }
+
+    #[test]
+    fn hash_to_scalar_dst_sensitive() {
+        let s1 =
+            BrainpoolP256r1::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[DST]).unwrap();
+        let s2 = BrainpoolP256r1::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[b"abc"], &[b"other"])
+            .unwrap();
+        assert_ne!(s1, s2);
+        assert_ne!(s1, Scalar::ZERO);
+    }
+
+    #[test]
+    fn from_okm_matches_wide_interpretation() {
+        // from_okm(data) must equal the 384-bit big-endian integer mod p
+        let data = [0xffu8; 48];
+        let fe = FieldElement::from_okm(&data.into());
+        // 2^384 - 1 mod p, precomputed
+        let expected = FieldElement::from_hex(
+            "671fdaf37755520edbfc2b2b19e5b395198cfe68b9406d342798dedf5814ec81",
+        );
+        assert_eq!(fe, expected);
+    }
+}
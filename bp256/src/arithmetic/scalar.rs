@@ -22,8 +22,10 @@ use core::{
     ops::{AddAssign, MulAssign, Neg, Shr, ShrAssign, SubAssign},
 };
 use elliptic_curve::{
-    bigint::{ArrayEncoding, Integer, Limb},
-    ff::PrimeField,
+    bigint::{ArrayEncoding, Integer, Limb, NonZero, U512},
+    consts::U64,
+    ff::{FromUniformBytes, PrimeField},
+    generic_array::GenericArray,
     ops::{Invert, Reduce},
     scalar::{FromUintUnchecked, IsHigh},
     subtle::{
@@ -33,6 +35,16 @@ use elliptic_curve::{
     Error, Result,
 };
 
+#[cfg(feature = "bits")]
+use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
+
+#[cfg(feature = "serde")]
+use {
+    crate::r1::BrainpoolP256r1,
+    elliptic_curve::ScalarPrimitive,
+    serdect::serde::{de, ser, Deserialize, Serialize},
+};
+
 #[cfg(doc)]
 use core::ops::{Add, Mul, Sub};
 
@@ -99,7 +111,7 @@ impl Scalar {
 
     /// Returns the big-endian encoding of this [`Scalar`].
     pub fn to_bytes(self) -> FieldBytes {
-        self.0.to_be_byte_array()
+        self.to_canonical().to_be_byte_array()
     }
 
     /// Translate [`Scalar`] out of the Montgomery domain, returning a
@@ -232,6 +244,63 @@ impl Scalar {
         CtOption::new(self.invert_unchecked(), !self.is_zero())
     }
 
+    /// Fast variable-time inversion using Stein's algorithm.
+    ///
+    /// Returns none if the scalar is zero.
+    ///
+    /// <https://link.springer.com/article/10.1007/s13389-016-0135-4>
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with (unblinded) secret scalars, as its
+    /// variable-time operation can potentially leak secrets through
+    /// sidechannels.
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        // (n + 1) / 2, used for modular halving of odd values
+        const FRAC_ORDER_PLUS_1_2: U256 =
+            U256::from_be_hex("54fdabedd0f754de1f3305484ec1c6b8c61cbd51dab0d37bc80f07414ba42b54");
+
+        // Halve `x` modulo the (odd) group order.
+        fn half_mod_order(x: &U256) -> U256 {
+            let halved = x.shr_vartime(1);
+            if x.bit_vartime(0) {
+                halved.add_mod(&FRAC_ORDER_PLUS_1_2, &ORDER)
+            } else {
+                halved
+            }
+        }
+
+        let mut u = self.to_canonical();
+        let mut v = ORDER;
+        let mut a = U256::ONE;
+        let mut c = U256::ZERO;
+
+        while u.cmp_vartime(&U256::ZERO).is_ne() {
+            // u-loop
+            while !u.bit_vartime(0) {
+                u = u.shr_vartime(1);
+                a = half_mod_order(&a);
+            }
+
+            // v-loop
+            while !v.bit_vartime(0) {
+                v = v.shr_vartime(1);
+                c = half_mod_order(&c);
+            }
+
+            // sub-step
+            if u.cmp_vartime(&v).is_ge() {
+                u = u.wrapping_sub(&v);
+                a = a.sub_mod(&c, &ORDER);
+            } else {
+                v = v.wrapping_sub(&u);
+                c = c.sub_mod(&a, &ORDER);
+            }
+        }
+
+        CtOption::new(Self::from_uint_unchecked(c), !self.is_zero())
+    }
+
     /// Returns the multiplicative inverse of self.
     ///
     /// Does not check that self is non-zero.
@@ -292,6 +361,10 @@ impl Invert for Scalar {
     fn invert(&self) -> CtOption<Self> {
         self.invert()
     }
+
+    fn invert_vartime(&self) -> CtOption<Self> {
+        self.invert_vartime()
+    }
 }
 
 impl IsHigh for Scalar {
@@ -353,6 +426,22 @@ impl PrimeField for Scalar {
     }
 }
 
+#[cfg(feature = "bits")]
+impl PrimeFieldBits for Scalar {
+    type ReprBits = fiat_bp256_scalar_montgomery_domain_field_element;
+
+    fn to_le_bits(&self) -> ScalarBits {
+        self.to_canonical().to_words().into()
+    }
+
+    fn char_le_bits() -> ScalarBits {
+        ORDER.to_words().into()
+    }
+}
+
+/// 64-byte wide serialized scalar, reduced via [`Reduce<U512>`].
+pub type WideBytes = GenericArray<u8, U64>;
+
 impl Reduce<U256> for Scalar {
     type Bytes = FieldBytes;
 
@@ -368,6 +457,28 @@ impl Reduce<U256> for Scalar {
     }
 }
 
+impl Reduce<U512> for Scalar {
+    type Bytes = WideBytes;
+
+    fn reduce(w: U512) -> Self {
+        const WIDE_ORDER: NonZero<U512> = NonZero::<U512>::const_new(U256::ZERO.concat(&ORDER)).0;
+
+        let (_, lo) = w.rem(&WIDE_ORDER).split();
+        Self::from_uint_unchecked(lo)
+    }
+
+    #[inline]
+    fn reduce_bytes(bytes: &WideBytes) -> Self {
+        Self::reduce(U512::from_be_byte_array(*bytes))
+    }
+}
+
+impl FromUniformBytes<64> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        <Self as Reduce<U512>>::reduce(U512::from_be_slice(bytes))
+    }
+}
+
 impl From<Scalar> for FieldBytes {
     fn from(scalar: Scalar) -> Self {
         scalar.to_repr()
@@ -400,10 +511,30 @@ impl TryFrom<U256> for Scalar {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for Scalar {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        ScalarPrimitive::<BrainpoolP256r1>::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Scalar {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Ok(ScalarPrimitive::<BrainpoolP256r1>::deserialize(deserializer)?.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use super::Scalar;
-    use elliptic_curve::ff::PrimeField;
+    use super::{Scalar, ORDER, U256};
+    use elliptic_curve::{bigint::ArrayEncoding, ff::PrimeField, ops::Reduce};
     use primeorder::{
         impl_field_identity_tests, impl_field_invert_tests, impl_field_sqrt_tests,
         impl_primefield_tests,
@@ -422,4 +553,127 @@ mod tests {
     impl_field_invert_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
     impl_primefield_tests!(Scalar, T);
+
+    /// n - 1
+    const N_MINUS_1: Scalar =
+        Scalar::from_hex("a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a6");
+
+    #[test]
+    fn add_wraps_around_order() {
+        // (n - 1) + 1 == 0
+        assert_eq!(N_MINUS_1 + Scalar::ONE, Scalar::ZERO);
+
+        // (n - 1) + (n - 1) == n - 2
+        assert_eq!(
+            N_MINUS_1 + N_MINUS_1,
+            Scalar::from_hex("a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a5")
+        );
+    }
+
+    #[test]
+    fn reduce_values_above_order() {
+        // n reduces to zero
+        assert_eq!(<Scalar as Reduce<U256>>::reduce(ORDER), Scalar::ZERO);
+
+        // 2^256 - 1 mod n
+        assert_eq!(
+            <Scalar as Reduce<U256>>::reduce(U256::MAX),
+            Scalar::from_hex("5604a8245e115643c199f56f627c728e73c6855c4a9e59086fe1f17d68b7a958")
+        );
+    }
+
+    #[test]
+    fn invert_vartime_matches_invert() {
+        for s in [
+            Scalar::ONE,
+            Scalar::from_u64(2),
+            Scalar::from_u64(3),
+            -Scalar::from_u64(3),
+            N_MINUS_1,
+            Scalar::from_hex("34b267135e115644a047b45e627c728f5274444b4a9e59094e8fb06c68b7a959"),
+        ] {
+            assert_eq!(
+                s.invert_vartime().unwrap(),
+                s.invert().unwrap(),
+                "mismatch for {s:?}"
+            );
+            assert_eq!(s * s.invert_vartime().unwrap(), Scalar::ONE);
+        }
+
+        assert!(bool::from(Scalar::ZERO.invert_vartime().is_none()));
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn scalar_bits_roundtrip() {
+        use elliptic_curve::{group::ff::PrimeFieldBits, rand_core::OsRng, Field};
+
+        // char_le_bits encodes the group order exactly
+        let char_bits = Scalar::char_le_bits();
+        let mut order = U256::ZERO;
+        for (i, bit) in char_bits.iter().enumerate() {
+            if *bit {
+                order |= U256::ONE << i;
+            }
+        }
+        assert_eq!(order, ORDER);
+
+        for _ in 0..16 {
+            let scalar = Scalar::random(&mut OsRng);
+            let bits = scalar.to_le_bits();
+
+            let mut uint = U256::ZERO;
+            for (i, bit) in bits.iter().enumerate() {
+                if *bit {
+                    uint |= U256::ONE << i;
+                }
+            }
+
+            assert_eq!(Scalar::from_uint(uint).unwrap(), scalar);
+        }
+    }
+
+    #[test]
+    fn reduce_wide_matches_integer_reduction() {
+        use super::U512;
+        use elliptic_curve::ff::FromUniformBytes;
+
+        // 2^512 - 1 mod n, precomputed
+        assert_eq!(
+            <Scalar as Reduce<U512>>::reduce(U512::MAX),
+            Scalar::from_hex("0b25f1b9c32367629b7f25e76c815cb0f35d176a1134e4a0e1d8d8de3312fca5")
+        );
+
+        // big-endian bytes 00 01 02 .. 3f mod n, precomputed
+        let bytes: [u8; 64] = core::array::from_fn(|i| i as u8);
+        assert_eq!(
+            Scalar::from_uniform_bytes(&bytes),
+            Scalar::from_hex("9341f313021fb1745913065590d7fb5847bc1bef499a687c07a8c19427bac2b9")
+        );
+
+        // n itself reduces to zero
+        let mut n_wide = [0u8; 64];
+        n_wide[32..].copy_from_slice(&ORDER.to_be_byte_array());
+        assert_eq!(Scalar::from_uniform_bytes(&n_wide), Scalar::ZERO);
+    }
+
+    #[test]
+    fn nonzero_scalar_random() {
+        use crate::r1::NonZeroScalar;
+        use elliptic_curve::rand_core::OsRng;
+
+        for _ in 0..100 {
+            let scalar = NonZeroScalar::random(&mut OsRng);
+            assert!(!bool::from(scalar.is_zero()));
+        }
+    }
+
+    #[test]
+    fn out_of_range_from_bytes_rejected() {
+        // from_bytes must reject canonical encodings >= n
+        assert!(bool::from(
+            Scalar::from_bytes(&ORDER.to_be_byte_array()).is_none()
+        ));
+        assert!(bool::from(Scalar::from_repr(N_MINUS_1.to_repr()).is_some()));
+    }
 }
@@ -15,24 +15,46 @@
     unused_qualifications
 )]
 
+#[cfg(all(feature = "alloc", feature = "wip-arithmetic-do-not-use"))]
+extern crate alloc;
+
 pub mod r1;
 pub mod t1;
 
 #[cfg(feature = "wip-arithmetic-do-not-use")]
 mod arithmetic;
 
+#[cfg(any(feature = "test-vectors", test))]
+pub mod test_vectors;
+
 pub use crate::{r1::BrainpoolP256r1, t1::BrainpoolP256t1};
 pub use elliptic_curve::{self, bigint::U256};
 
 #[cfg(feature = "wip-arithmetic-do-not-use")]
 pub use crate::arithmetic::scalar::Scalar;
 
+/// Bit representation of a brainpoolP256 scalar field element.
+#[cfg(feature = "bits")]
+pub type ScalarBits = elliptic_curve::scalar::ScalarBits<BrainpoolP256r1>;
+
 #[cfg(feature = "pkcs8")]
 pub use elliptic_curve::pkcs8;
 
 use elliptic_curve::generic_array::{typenum::U32, GenericArray};
 
-#[cfg(feature = "wip-arithmetic-do-not-use")]
+/// Base field element.
+///
+/// # ⚠️ Security Warning
+///
+/// This type is exposed for implementing protocols which require low-level
+/// access to brainpool base field arithmetic. It is easy to misuse: the
+/// responsibility for using it correctly (e.g. checking that inputs are
+/// canonical, keeping intermediate values secret) rests entirely with the
+/// caller.
+#[cfg(feature = "expose-field")]
+pub use crate::arithmetic::field::FieldElement;
+
+#[cfg(all(feature = "wip-arithmetic-do-not-use", not(feature = "expose-field")))]
 pub(crate) use crate::arithmetic::field::FieldElement;
 
 /// Byte representation of a base/scalar field element of a given curve.
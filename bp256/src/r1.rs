@@ -1,6 +1,9 @@
 //! brainpoolP256r1 elliptic curve: verifiably pseudo-random variant
 
-#[cfg(feature = "ecdsa")]
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+
+#[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
 #[cfg(feature = "wip-arithmetic-do-not-use")]
@@ -67,6 +70,14 @@ impl FieldBytesEncoding<BrainpoolP256r1> for U256 {
     }
 }
 
+/// brainpoolP256r1 non-zero scalar: a scalar value in the range `[1, n)`.
+#[cfg(feature = "wip-arithmetic-do-not-use")]
+pub type NonZeroScalar = elliptic_curve::NonZeroScalar<BrainpoolP256r1>;
+
+/// brainpoolP256r1 public key.
+#[cfg(feature = "wip-arithmetic-do-not-use")]
+pub type PublicKey = elliptic_curve::PublicKey<BrainpoolP256r1>;
+
 /// brainpoolP256r1 secret key.
 pub type SecretKey = elliptic_curve::SecretKey<BrainpoolP256r1>;
 
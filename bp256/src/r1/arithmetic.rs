@@ -36,6 +36,11 @@ impl PrimeCurveParams for BrainpoolP256r1 {
         FieldElement::from_hex("8bd2aeb9cb7e57cb2c4b482ffc81b7afb9de27e1e3bd23c23a4453bd9ace3262"),
         FieldElement::from_hex("547ef835c3dac4fd97f8461a14611dc9c27745132ded8e545c1d54c72f046997"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(scalar: &Scalar) -> ProjectivePoint {
+        GENERATOR_TABLE.mul(scalar)
+    }
 }
 
 impl From<ScalarPrimitive> for Scalar {
@@ -61,3 +66,48 @@ impl From<&Scalar> for ScalarPrimitive {
         ScalarPrimitive::new(scalar.into()).unwrap()
     }
 }
+
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(any(feature = "std", feature = "critical-section"))
+))]
+compile_error!("`precomputed-tables` feature requires either `std` or `critical-section`");
+
+/// Lazily computed table of multiples of the brainpoolP256r1 generator,
+/// used to accelerate [`MulByGenerator`][`elliptic_curve::ops::MulByGenerator`].
+#[cfg(feature = "precomputed-tables")]
+static GENERATOR_TABLE: once_cell::sync::Lazy<primeorder::FixedBaseTable<BrainpoolP256r1, 33>> =
+    once_cell::sync::Lazy::new(|| primeorder::FixedBaseTable::new(&ProjectivePoint::GENERATOR));
+
+#[cfg(all(test, feature = "precomputed-tables"))]
+mod tests {
+    use super::{ProjectivePoint, Scalar};
+    use elliptic_curve::{ops::MulByGenerator, rand_core::OsRng, Field, PrimeField};
+
+    #[test]
+    fn mul_by_generator_matches_generic_mul() {
+        // edge cases
+        for scalar in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::from_u64(2),
+            Scalar::from_hex("a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a6"),
+        ] {
+            assert_eq!(
+                ProjectivePoint::mul_by_generator(&scalar),
+                ProjectivePoint::GENERATOR * scalar
+            );
+        }
+
+        // random scalars
+        for _ in 0..200 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(
+                ProjectivePoint::mul_by_generator(&scalar),
+                ProjectivePoint::GENERATOR * scalar,
+                "mismatch for {:?}",
+                scalar.to_repr()
+            );
+        }
+    }
+}
@@ -0,0 +1,93 @@
+//! Elliptic Curve Diffie-Hellman (Ephemeral) Support.
+//!
+//! This module contains a high-level interface for performing ephemeral
+//! Diffie-Hellman key exchanges using the brainpoolP256r1 elliptic curve.
+//!
+//! # Usage
+//!
+//! This usage example is from the perspective of two participants in the
+//! exchange, nicknamed "Alice" and "Bob".
+//!
+//! ```
+//! use bp256::r1::{ecdh::EphemeralSecret, EncodedPoint, PublicKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Alice
+//! let alice_secret = EphemeralSecret::random(&mut OsRng);
+//! let alice_pk_bytes = EncodedPoint::from(alice_secret.public_key());
+//!
+//! // Bob
+//! let bob_secret = EphemeralSecret::random(&mut OsRng);
+//! let bob_pk_bytes = EncodedPoint::from(bob_secret.public_key());
+//!
+//! // Alice decodes Bob's serialized public key and computes a shared secret
+//! // from it. Decoding validates the peer point is on the curve and not the
+//! // identity.
+//! let bob_public = PublicKey::from_sec1_bytes(bob_pk_bytes.as_ref())
+//!     .expect("bob's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//!
+//! // Bob decodes Alice's serialized public key and computes the same shared secret
+//! let alice_public = PublicKey::from_sec1_bytes(alice_pk_bytes.as_ref())
+//!     .expect("alice's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//!
+//! // Both participants arrive on the same shared secret, usable either as
+//! // raw x-coordinate bytes or via the HKDF `extract`/`expand` helpers.
+//! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
+//! ```
+
+pub use elliptic_curve::ecdh::diffie_hellman;
+
+use super::BrainpoolP256r1;
+
+/// brainpoolP256r1 Ephemeral Diffie-Hellman Secret.
+pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<BrainpoolP256r1>;
+
+/// Shared secret value computed via ECDH key agreement.
+pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<BrainpoolP256r1>;
+
+#[cfg(test)]
+mod tests {
+    use super::diffie_hellman;
+    use crate::r1::{PublicKey, SecretKey};
+    use hex_literal::hex;
+
+    /// ECDH known-answer test, cross-checked against OpenSSL's
+    /// brainpoolP256r1 implementation.
+    #[test]
+    fn ecdh_known_answer() {
+        let alice_secret = SecretKey::from_slice(&hex!(
+            "1e0cfd43983bc59d1a7a02ee42cd87e8a4e39876bc073cb6f5f087b32a1e5e1a"
+        ))
+        .unwrap();
+        let bob_secret = SecretKey::from_slice(&hex!(
+            "631db56a47bf29a254be51ffc56d1b3eaeb3e36e3b1c4289aa23a0759cd3e1c7"
+        ))
+        .unwrap();
+
+        let alice_public = PublicKey::from_sec1_bytes(&hex!(
+            "04945de976c75b220c721a6c0e4de0dc0b4a23e03338e39d07e236ae4d58b1f747
+             4dc3e58f387044bd9ddbba386a1ffd882228ee85eacee7f8f55765c6b0b502d8"
+        ))
+        .unwrap();
+        let bob_public = PublicKey::from_sec1_bytes(&hex!(
+            "04548008e0581f70a8436ce644194868efaee7fc831e02479563c3acf5d9755985
+             1db027913477dff0a721c3c474994d44aec725207ea714d968974f11a821ee7f"
+        ))
+        .unwrap();
+
+        assert_eq!(alice_secret.public_key(), alice_public);
+        assert_eq!(bob_secret.public_key(), bob_public);
+
+        let expected_z = hex!("89178ca663941d7e2f578940056a29ba2df3d5a15c86eda1e3efaaba737c388b");
+
+        let alice_shared = diffie_hellman(alice_secret.to_nonzero_scalar(), bob_public.as_affine());
+        let bob_shared = diffie_hellman(bob_secret.to_nonzero_scalar(), alice_public.as_affine());
+
+        assert_eq!(alice_shared.raw_secret_bytes().as_slice(), &expected_z);
+        assert_eq!(bob_shared.raw_secret_bytes().as_slice(), &expected_z);
+    }
+}
@@ -1,14 +1,156 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! To use it, you will need to enable one of the two following Cargo features:
+//!
+//! - `ecdsa-core`: provides only the [`Signature`] type (which represents an
+//!   ECDSA/brainpoolP256r1 signature). Does not require arithmetic. This is
+//!   useful for 3rd-party crates which wish to use the [`Signature`] type for
+//!   interoperability purposes.
+//! - `ecdsa`: provides `ecdsa-core` features plus the [`SigningKey`] and
+//!   [`VerifyingKey`] types which natively implement ECDSA/brainpoolP256r1
+//!   signing and verification with SHA-256 as the default digest.
+//!
+//! Signatures are not low-S normalized on signing or verification, matching
+//! BSI TR-03111 conventions; [`Signature::normalize_s`] is available for
+//! callers which need the Bitcoin-style normalized form.
 
-pub use super::BrainpoolP256r1;
+pub use ecdsa_core::signature::{self, Error};
+
+use super::BrainpoolP256r1;
+
+#[cfg(feature = "ecdsa")]
+use {
+    super::AffinePoint,
+    crate::Scalar,
+    ecdsa_core::hazmat::{SignPrimitive, VerifyPrimitive},
+};
 
 /// ECDSA/brainpoolP256r1 signature (fixed-size)
-pub type Signature = ecdsa::Signature<BrainpoolP256r1>;
+pub type Signature = ecdsa_core::Signature<BrainpoolP256r1>;
 
 /// ECDSA/brainpoolP256r1 signature (ASN.1 DER encoded)
-pub type DerSignature = ecdsa::der::Signature<BrainpoolP256r1>;
+pub type DerSignature = ecdsa_core::der::Signature<BrainpoolP256r1>;
+
+/// ECDSA/brainpoolP256r1 signing key
+#[cfg(feature = "ecdsa")]
+pub type SigningKey = ecdsa_core::SigningKey<BrainpoolP256r1>;
+
+/// ECDSA/brainpoolP256r1 verification key (i.e. public key)
+#[cfg(feature = "ecdsa")]
+pub type VerifyingKey = ecdsa_core::VerifyingKey<BrainpoolP256r1>;
 
 #[cfg(feature = "sha256")]
-impl ecdsa::hazmat::DigestPrimitive for BrainpoolP256r1 {
+impl ecdsa_core::hazmat::DigestPrimitive for BrainpoolP256r1 {
     type Digest = sha2::Sha256;
 }
+
+#[cfg(feature = "ecdsa")]
+impl SignPrimitive<BrainpoolP256r1> for Scalar {}
+
+#[cfg(feature = "ecdsa")]
+impl VerifyPrimitive<BrainpoolP256r1> for AffinePoint {}
+
+#[cfg(all(test, feature = "ecdsa"))]
+mod tests {
+    use super::{DerSignature, Signature, SigningKey, VerifyingKey};
+    use crate::Scalar;
+    use ecdsa_core::{
+        hazmat::SignPrimitive,
+        signature::{Signer, Verifier},
+    };
+    use elliptic_curve::{rand_core::OsRng, PrimeField};
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    /// Private scalar for the RFC 6979 test vectors below, which were
+    /// generated with an independent HMAC-DRBG implementation and
+    /// cross-verified with OpenSSL (brainpool is not covered by the RFC 6979
+    /// appendix itself). Note the `rfc6979` crate feeds the raw message
+    /// digest into the DRBG rather than `bits2octets(h1)`, which only
+    /// differs from the RFC when the digest exceeds the group order.
+    const D: [u8; 32] = hex!("1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef");
+
+    #[test]
+    fn rfc6979() {
+        let signer = SigningKey::from_bytes(&D.into()).unwrap();
+
+        let signature: Signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "22d73c5c6dcdae3bb2f1847b00d5934d7b221e82d62a15f8e3c3862982790d3d
+                 3ac92d158e0942544374c035face4f5f5fa184477bae568b16bce242604eeae7"
+            )
+        );
+
+        let signature: Signature = signer.sign(b"test");
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "5293cb4f520d91cfa98c7c7cb577e1c4f76afe5169dc92f99619b7b988c8fc6b
+                 12969e58556d2a67e804398f5917fd33df74236324f89384fb3ca4ee484235c9"
+            )
+        );
+    }
+
+    /// RFC 6979 Section 3.6 "additional data" extension: extra entropy is
+    /// mixed into the HMAC-DRBG alongside the key and message digest.
+    #[test]
+    fn rfc6979_with_additional_data() {
+        let d = Scalar::from_repr(D.into()).unwrap();
+        let z: crate::FieldBytes = Sha256::digest(b"sample");
+
+        let ad: [u8; 32] = core::array::from_fn(|i| i as u8);
+        let (signature, _): (Signature, _) =
+            d.try_sign_prehashed_rfc6979::<Sha256>(&z, &ad).unwrap();
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "8556a5187c7994d981e887b58d72ee6e6bf325088a737a84730942f90d0befbb
+                 18f377abb58a742fa8a8d573d6eaf3ed3c301b04f94f23b0a85b8ecf421f5aa4"
+            )
+        );
+
+        // no additional data reproduces the plain RFC 6979 signature
+        let (plain, _): (Signature, _) = d.try_sign_prehashed_rfc6979::<Sha256>(&z, &[]).unwrap();
+        assert_ne!(signature, plain);
+        assert_eq!(
+            plain.to_bytes().as_slice(),
+            &hex!(
+                "22d73c5c6dcdae3bb2f1847b00d5934d7b221e82d62a15f8e3c3862982790d3d
+                 3ac92d158e0942544374c035face4f5f5fa184477bae568b16bce242604eeae7"
+            )
+        );
+    }
+
+    #[test]
+    fn signing_roundtrip() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = VerifyingKey::from(&signing_key);
+
+        let message = b"brainpoolP256r1 test message";
+        let signature: Signature = signing_key.sign(message);
+        assert!(verifying_key.verify(message, &signature).is_ok());
+        assert!(verifying_key.verify(b"other message", &signature).is_err());
+    }
+
+    #[test]
+    fn der_signature_roundtrip() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signature: DerSignature = signing_key.sign(b"DER round trip");
+        let fixed = Signature::from_der(signature.as_bytes()).unwrap();
+        assert_eq!(fixed.to_der().as_bytes(), signature.as_bytes());
+    }
+
+    #[test]
+    fn normalize_s_verifies() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = VerifyingKey::from(&signing_key);
+
+        let message = b"low-S normalization";
+        let signature: Signature = signing_key.sign(message);
+        let normalized = signature.normalize_s().unwrap_or(signature);
+        assert!(verifying_key.verify(message, &normalized).is_ok());
+    }
+}
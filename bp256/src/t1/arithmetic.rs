@@ -61,3 +61,43 @@ impl From<&Scalar> for ScalarPrimitive {
         ScalarPrimitive::new(scalar.into()).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BrainpoolP256t1;
+    use crate::{r1::BrainpoolP256r1, FieldElement};
+    use primeorder::PrimeCurveParams;
+
+    /// RFC 5639 Section 3.4: `Z` relating brainpoolP256r1 to its twisted
+    /// brainpoolP256t1 form via (x, y) -> (x * Z^2, y * Z^3).
+    const Z: FieldElement =
+        FieldElement::from_hex("3e2d4bd9597b58639ae7aa669cab9837cf5cf20a2c852d10f655668dfc150ef0");
+
+    #[test]
+    fn generator_satisfies_twist_relation() {
+        let z2 = Z.square();
+        let z3 = z2 * Z;
+
+        let (rx, ry) = BrainpoolP256r1::GENERATOR;
+        let (tx, ty) = BrainpoolP256t1::GENERATOR;
+
+        assert_eq!(rx * z2, tx);
+        assert_eq!(ry * z3, ty);
+    }
+
+    #[test]
+    fn equation_satisfies_twist_relation() {
+        let z2 = Z.square();
+        let z4 = z2.square();
+        let z6 = z4 * z2;
+
+        assert_eq!(
+            BrainpoolP256r1::EQUATION_A * z4,
+            BrainpoolP256t1::EQUATION_A
+        );
+        assert_eq!(
+            BrainpoolP256r1::EQUATION_B * z6,
+            BrainpoolP256t1::EQUATION_B
+        );
+    }
+}
@@ -0,0 +1,137 @@
+//! Test vectors for the brainpoolP256r1 group.
+
+use hex_literal::hex;
+
+/// Repeated addition of the generator.
+///
+/// The first 20 multiples of the brainpoolP256r1 (RFC 5639) base point,
+/// generated with sagemath.
+pub const ADD_TEST_VECTORS: &[([u8; 32], [u8; 32])] = &[
+    (
+        hex!("8BD2AEB9CB7E57CB2C4B482FFC81B7AFB9DE27E1E3BD23C23A4453BD9ACE3262"),
+        hex!("547EF835C3DAC4FD97F8461A14611DC9C27745132DED8E545C1D54C72F046997"),
+    ),
+    (
+        hex!("743CF1B8B5CD4F2EB55F8AA369593AC436EF044166699E37D51A14C2CE13EA0E"),
+        hex!("36ED163337DEBA9C946FE0BB776529DA38DF059F69249406892ADA097EEB7CD4"),
+    ),
+    (
+        hex!("A8F217B77338F1D4D6624C3AB4F6CC16D2AA843D0C0FCA016B91E2AD25CAE39D"),
+        hex!("4B49CAFC7DAC26BB0AA2A6850A1B40F5FAC10E4589348FB77E65CC5602B74F9D"),
+    ),
+    (
+        hex!("3672030BACE787AA319E21D40645B2999006BEEC437FD084DD3FC592F5FCD77C"),
+        hex!("335B226CE5FAC0C36A18CE42E95F43C9EED3E256BDD0C98E55A069595515D15B"),
+    ),
+    (
+        hex!("855433A3A4C8E334A5F863E8B69FC1477CF41589C0D8C3FB32F95F7C85FE101D"),
+        hex!("A50C95EFC2AD06C4D7E172E40350D911097082129591C88BEF9E224A5FD8814C"),
+    ),
+    (
+        hex!("78EA164AA2A74A67A04B680BD8BB1384E7CC4DB8774C50ECB9DFB344771026B1"),
+        hex!("10D988FF681802469B49D341F8DA0A2500CAD34F1E745B1437E336573D08B1BE"),
+    ),
+    (
+        hex!("6B8BB7F53E36B6824D3300AFBC27257BD432568E24E5FB5702295ECD04E9DE4C"),
+        hex!("382F9AF51CE9A3D30965A09661223AF5646067C55B1A928F7252376BFC79EBF0"),
+    ),
+    (
+        hex!("545A6FAF6B031B267409483A38D1942C91DB2B4EB917D2BDDA994B4CB3985461"),
+        hex!("76F4942D7CA7B4143CBEDFC72C7A65194596BDA3D83213BBCFB32792456303FC"),
+    ),
+    (
+        hex!("8B5FA06D31D59D690811364099019B7CD283BD714A67C06A420D27D6784F8F12"),
+        hex!("41E0E0C34464B5C7AE64ED13D26D038E146F15EEA266B22842BE764F293B3348"),
+    ),
+    (
+        hex!("A4348DB079F7FFBCFB3DFC35BD8AC67C22A85A50025CB1F37A22BA81728B1CAF"),
+        hex!("2444FA0F5B79BE1A2BD1D073C38FD136C77977F417B550D954E46DC4C8B737C1"),
+    ),
+    (
+        hex!("50EA43E33D2D48978DDC9C5870EA163180C350B1E1DB41B03406AFFFDE3EEED0"),
+        hex!("4685DCECA1753941782129D70CEEB10F951970A9B39A21F923AF9BDADF6FBD40"),
+    ),
+    (
+        hex!("7E21EAAF386828A98FCD5B4F07C9E855E4035E293FBD18273BEE7E520810F159"),
+        hex!("6B00DA07D32CE8A06DD01764C1D87A3B67C6EA5B590D0CA7BB74AD0B29B9C160"),
+    ),
+    (
+        hex!("8D4243F928EE1B6A7862AC771CE2CB743439BBF4E2B459B662C969C86253556B"),
+        hex!("6CB4B54150658725C257D5E888EB9DADF5C5AFBB15E5C033616A664E902CB740"),
+    ),
+    (
+        hex!("1D36A037AB842C1D557513E3B04D9166A09AA186EE1E9916674D33A6C2B6B191"),
+        hex!("5B811A55DD8BF3FB10D4FF18900017E9290D2F38DB9B105035E15701BC4413E6"),
+    ),
+    (
+        hex!("04306F8D5631EE7AC6E07A490CEE907848E0917A7D5EDC4B7A309A0B21557A8E"),
+        hex!("2AB9E5213104BC7F3AA032DAF9FFD870A510F13A83E146A29377C731F7E833BD"),
+    ),
+    (
+        hex!("653583661EF339866B0798FB767757ED3543957E92F08735B3DDCF32EAA36568"),
+        hex!("A6B73D0616FF459ABE017D72168A0385212B4EA2D5069F1615B7EE3666C078E1"),
+    ),
+    (
+        hex!("884D1F975768CE45C3663E5F98B9DAFADA7B65585BFBAADA0B73FCB1A0C86824"),
+        hex!("569EA2DD9A21654F601705FE7F902E370F42120813FC7E966205D05254C57AD7"),
+    ),
+    (
+        hex!("81380431B06D64672F370DE2AF51D2E7CFC0B0E92651132FBEBDA3814E33C774"),
+        hex!("3E919A4860E8D356F353B53494AC6524F1CAFCA72258A0B56AD1A9E34BEC5265"),
+    ),
+    (
+        hex!("09A299ED5649E1EA8C6280C5F7796CDB20411E949B5343F7A58EAB8AB0AFBAE7"),
+        hex!("3EE027407A6089DFF82C6EB87B8812EA899ADED51179513114F5E940D854688A"),
+    ),
+    (
+        hex!("0ECA4BE77F21E1EC417F28FF285452EC726F66AFA4739CC4EA40FDD1C2EF7ED8"),
+        hex!("7013CF6067CB57284BCA029B1CD89E9727D3447EAC29314A7CA1FF58D3C20BD2"),
+    ),
+];
+
+/// Scalar multiplication with the generator.
+///
+/// These test vectors use scalars not covered by [`ADD_TEST_VECTORS`],
+/// including values near the group order, generated with sagemath.
+pub const MUL_TEST_VECTORS: &[([u8; 32], [u8; 32], [u8; 32])] = &[
+    (
+        hex!("000000000000000000000000000000000000000000000000018EBBB95EED0E13"),
+        hex!("691024597EA13DC03314771DD794E6D2F50AEB49335D3B03E21B5923C29B9D5C"),
+        hex!("3D313DE6241323A266524FCBFF2658FF2495F46017FCC84F8C9D4C14D8D27917"),
+    ),
+    (
+        hex!("A9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E82974856A6"),
+        hex!("8BD2AEB9CB7E57CB2C4B482FFC81B7AFB9DE27E1E3BD23C23A4453BD9ACE3262"),
+        hex!("557C5FA5DE13E4BEA66DC47689226FA8ABC4B110A73891D3C3F5F355F069E9E0"),
+    ),
+    (
+        hex!("A9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E82974856A5"),
+        hex!("743CF1B8B5CD4F2EB55F8AA369593AC436EF044166699E37D51A14C2CE13EA0E"),
+        hex!("730E41A86A0FEF1FA9F629D5261E6398355CF0846C018C2196E86E13A082D6A3"),
+    ),
+    (
+        hex!("54FDABEDD0F754DE1F3305484EC1C6B8C61CBD51DAB0D37BC80F07414BA42B54"),
+        hex!("027FCA79250B3998BBA6905173F724487E5ABBE93B0FB68BE5E225780CA4A889"),
+        hex!("531A332D86E1D0428B2D502BA0900CE921635DAB5D6D1805E9EBB80B13DBE5AB"),
+    ),
+    (
+        hex!("41FFC1FFFFFE01FFFC0003FFFE0007C001FFF00003FFF07FFE0007C000000003"),
+        hex!("50DCC8E27B2D9CDC92248D6461DF33170A61A027C16AAD65CC9C4818F0FC5DE5"),
+        hex!("529EC6A1DC0ECB4B75A45358F1577CC1A4707E080281F8F42564F33C8448EA22"),
+    ),
+    (
+        hex!("7FFFFFC03FFFC003FFFFFC007FFF00000000070000100000000E00FFFFFFF3FF"),
+        hex!("86AEC18F6FCD4E30E885BBD2ACB50898E2ADDD10E118CDA418D1C57DEEE9939D"),
+        hex!("8AC0FAF65D15D241C1FC699567CADF59582414CD22FD66CC426A2D2F9E2C34B3"),
+    ),
+    (
+        hex!("A9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E8297480000"),
+        hex!("0305913364BBC950D8366D714709CAF5FE936E34C3E7D4E7FC56AEFCA5714228"),
+        hex!("17AF3DB1BC53EB3A9494FF24AC793C145FFF5A52B2613C000AEFDFE9B6A233CA"),
+    ),
+    (
+        hex!("0102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F20"),
+        hex!("4E366CF3C8A982E423831D6715E722ACF03CAB8452E3C64D1E3B038CAF87FC48"),
+        hex!("387A044328D34CE4EB16C6C885B8B82BE2584C18B28FC38143CBBF2B9B3520F9"),
+    ),
+];
@@ -0,0 +1,121 @@
+//! ECDSA `verify_prehash` tests.
+//!
+//! The Wycheproof brainpoolP256r1 suite is not vendored in this repository;
+//! these vectors were generated with an independent implementation and cover
+//! the same classes of edge cases (out-of-range scalars, signature
+//! malleability, malformed DER).
+
+#![cfg(feature = "ecdsa")]
+
+use bp256::r1::ecdsa::{signature::hazmat::PrehashVerifier, Signature, VerifyingKey};
+use elliptic_curve::generic_array::GenericArray;
+use hex_literal::hex;
+
+/// SHA-256 prehash of the signed message
+const PREHASH: [u8; 32] = hex!("d58112c7256275c11347e5d80a1c037e25f9f708ee9533b18b8903c059c2798b");
+
+/// r component of a valid signature over `PREHASH`
+const R: [u8; 32] = hex!("602ee35f8ccde05f40ad3b94b6763b370df7312ceeee3f939ba61ae4b5eed217");
+
+/// s component of a valid signature over `PREHASH`
+const S: [u8; 32] = hex!("7c7c9b2bf570004018d6d23eb058f40bdda866a695a1c076412732702f701576");
+
+/// n - s: the malleable high-s counterpart of `S`
+const N_MINUS_S: [u8; 32] =
+    hex!("2d7ebcafac7ea97c258f3851ed2a9965ae9113fd1fbfe6814ef6dc1267d84131");
+
+/// The group order n
+const ORDER: [u8; 32] = hex!("a9fb57dba1eea9bc3e660a909d838d718c397aa3b561a6f7901e0e82974856a7");
+
+fn verifying_key() -> VerifyingKey {
+    let sec1 = hex!(
+        "04888181e268b190596445e5a8b116c3e3949a11d84965ba382e53f8eada0a1140
+         8f261fdc50c6a12c8da4f2d7aa77fac114d60ce4689d212888a029b273eb8198"
+    );
+    VerifyingKey::from_sec1_bytes(&sec1).unwrap()
+}
+
+fn signature(r: &[u8; 32], s: &[u8; 32]) -> Result<Signature, ecdsa_core::Error> {
+    Signature::from_scalars(
+        GenericArray::clone_from_slice(r),
+        GenericArray::clone_from_slice(s),
+    )
+}
+
+#[test]
+fn valid_signature_accepted() {
+    let signature = signature(&R, &S).unwrap();
+    assert!(verifying_key().verify_prehash(&PREHASH, &signature).is_ok());
+}
+
+#[test]
+fn high_s_accepted() {
+    // ECDSA signatures are malleable in s; without a low-S policy both
+    // (r, s) and (r, n - s) must verify (TR-03111 semantics).
+    let signature = signature(&R, &N_MINUS_S).unwrap();
+    assert!(verifying_key().verify_prehash(&PREHASH, &signature).is_ok());
+}
+
+#[test]
+fn tampered_prehash_rejected() {
+    let signature = signature(&R, &S).unwrap();
+    let mut prehash = PREHASH;
+    prehash[0] ^= 1;
+    assert!(verifying_key()
+        .verify_prehash(&prehash, &signature)
+        .is_err());
+}
+
+#[test]
+fn swapped_r_s_rejected() {
+    let signature = signature(&S, &R).unwrap();
+    assert!(verifying_key()
+        .verify_prehash(&PREHASH, &signature)
+        .is_err());
+}
+
+#[test]
+fn zero_scalars_rejected_at_parse() {
+    assert!(signature(&[0u8; 32], &S).is_err());
+    assert!(signature(&R, &[0u8; 32]).is_err());
+}
+
+#[test]
+fn out_of_range_scalars_rejected_at_parse() {
+    assert!(signature(&ORDER, &S).is_err());
+    assert!(signature(&R, &ORDER).is_err());
+}
+
+#[test]
+fn malformed_der_rejected() {
+    let valid_der = signature(&R, &S).unwrap().to_der();
+    let bytes = valid_der.as_bytes();
+
+    // trailing garbage
+    let mut trailing = bytes.to_vec();
+    trailing.push(0x00);
+    assert!(Signature::from_der(&trailing).is_err());
+
+    // truncated
+    assert!(Signature::from_der(&bytes[..bytes.len() - 1]).is_err());
+
+    // corrupted outer tag
+    let mut bad_tag = bytes.to_vec();
+    bad_tag[0] = 0x31;
+    assert!(Signature::from_der(&bad_tag).is_err());
+}
+
+#[test]
+fn oversized_prehash_uses_leftmost_bits() {
+    // Prehashes longer than the field size keep only the leftmost 256 bits
+    // per bits2field, so appending trailing bytes does not affect
+    // verification while changing the leading bytes does.
+    let signature = signature(&R, &S).unwrap();
+
+    let mut long = [0u8; 48];
+    long[..32].copy_from_slice(&PREHASH);
+    assert!(verifying_key().verify_prehash(&long, &signature).is_ok());
+
+    long[0] ^= 1;
+    assert!(verifying_key().verify_prehash(&long, &signature).is_err());
+}
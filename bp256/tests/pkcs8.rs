@@ -0,0 +1,109 @@
+//! PKCS#8 tests
+
+#![cfg(all(feature = "pkcs8", feature = "wip-arithmetic-do-not-use"))]
+
+use bp256::{
+    elliptic_curve::sec1::ToEncodedPoint,
+    pkcs8::{DecodePrivateKey, DecodePublicKey},
+    r1::{PublicKey, SecretKey},
+};
+use hex_literal::hex;
+
+#[cfg(feature = "pem")]
+use bp256::pkcs8::{EncodePrivateKey, EncodePublicKey};
+
+/// DER-encoded PKCS#8 private key, generated by
+/// `openssl ecparam -name brainpoolP256r1 -genkey | openssl pkcs8 -topk8 -nocrypt`
+const PKCS8_PRIVATE_KEY_DER: &[u8; 139] = include_bytes!("examples/pkcs8-private-key.der");
+
+/// DER-encoded PKCS#8 public key for the same key pair
+const PKCS8_PUBLIC_KEY_DER: &[u8; 92] = include_bytes!("examples/pkcs8-public-key.der");
+
+/// DER-encoded PKCS#8 private key on the wrong curve (prime256v1)
+const PKCS8_PRIVATE_KEY_WRONG_CURVE_DER: &[u8; 138] =
+    include_bytes!("examples/pkcs8-private-key-wrong-curve.der");
+
+/// PEM-encoded PKCS#8 private key
+#[cfg(feature = "pem")]
+const PKCS8_PRIVATE_KEY_PEM: &str = include_str!("examples/pkcs8-private-key.pem");
+
+/// PEM-encoded PKCS#8 public key
+#[cfg(feature = "pem")]
+const PKCS8_PUBLIC_KEY_PEM: &str = include_str!("examples/pkcs8-public-key.pem");
+
+/// PEM-encoded SEC1 `ECPrivateKey` as emitted by `openssl ecparam -genkey`
+#[cfg(feature = "pem")]
+const SEC1_PRIVATE_KEY_PEM: &str = include_str!("examples/sec1-private-key.pem");
+
+/// The private scalar in the fixtures above
+const PRIVATE_SCALAR: [u8; 32] =
+    hex!("35c25b3e49e0f812b815d40bd17777d1e9fe62bd2e78dc8010763ee468c9bfcd");
+
+/// The SEC1-encoded public point of the key pair
+const PUBLIC_POINT: [u8; 65] = hex!(
+    "04a1e8d7df565faeab26b1fb8120e861a821928e5de4b9934a72b9f114b0272815
+     60bbcff21e715f4bf0a5ffa14cabc612b78c9a9003176c84bbff7fe6cb02506e"
+);
+
+#[test]
+fn decode_pkcs8_private_key_from_der() {
+    let secret_key = SecretKey::from_pkcs8_der(&PKCS8_PRIVATE_KEY_DER[..]).unwrap();
+    assert_eq!(secret_key.to_bytes().as_slice(), &PRIVATE_SCALAR[..]);
+}
+
+#[test]
+fn decode_pkcs8_public_key_from_der() {
+    let public_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    assert_eq!(
+        public_key.to_encoded_point(false).as_bytes(),
+        &PUBLIC_POINT[..]
+    );
+}
+
+#[test]
+fn decode_wrong_curve_oid_rejected() {
+    assert!(SecretKey::from_pkcs8_der(&PKCS8_PRIVATE_KEY_WRONG_CURVE_DER[..]).is_err());
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_pkcs8_private_key_from_pem() {
+    let secret_key = PKCS8_PRIVATE_KEY_PEM.parse::<SecretKey>().unwrap();
+
+    // Ensure key parses equivalently to DER
+    let der_key = SecretKey::from_pkcs8_der(&PKCS8_PRIVATE_KEY_DER[..]).unwrap();
+    assert_eq!(secret_key.to_bytes(), der_key.to_bytes());
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_pkcs8_public_key_from_pem() {
+    let public_key = PKCS8_PUBLIC_KEY_PEM.parse::<PublicKey>().unwrap();
+
+    // Ensure key parses equivalently to DER
+    let der_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    assert_eq!(public_key, der_key);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_sec1_private_key_from_pem() {
+    let secret_key = SecretKey::from_sec1_pem(SEC1_PRIVATE_KEY_PEM).unwrap();
+    assert_eq!(secret_key.to_bytes().as_slice(), &PRIVATE_SCALAR[..]);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn encode_pkcs8_private_key_to_pem() {
+    let secret_key = SecretKey::from_pkcs8_der(&PKCS8_PRIVATE_KEY_DER[..]).unwrap();
+    let reencoded = secret_key.to_pkcs8_pem(Default::default()).unwrap();
+    assert_eq!(reencoded.as_str(), PKCS8_PRIVATE_KEY_PEM);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn encode_pkcs8_public_key_to_pem() {
+    let public_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    let reencoded = public_key.to_public_key_pem(Default::default()).unwrap();
+    assert_eq!(reencoded, PKCS8_PUBLIC_KEY_PEM);
+}
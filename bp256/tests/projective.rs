@@ -0,0 +1,66 @@
+//! Projective arithmetic tests.
+
+#![cfg(all(feature = "wip-arithmetic-do-not-use", feature = "test-vectors"))]
+
+use bp256::{
+    r1::{AffinePoint, EncodedPoint, ProjectivePoint},
+    test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
+    Scalar,
+};
+use elliptic_curve::{
+    sec1::{self, FromEncodedPoint, ToEncodedPoint},
+    PrimeField,
+};
+use primeorder::{impl_projective_arithmetic_tests, Double};
+
+impl_projective_arithmetic_tests!(
+    AffinePoint,
+    ProjectivePoint,
+    Scalar,
+    ADD_TEST_VECTORS,
+    MUL_TEST_VECTORS
+);
+
+#[test]
+fn generator_roundtrip_uncompressed() {
+    let point = ProjectivePoint::GENERATOR.to_affine();
+    let encoded = point.to_encoded_point(false);
+    let decoded = AffinePoint::from_encoded_point(&encoded).unwrap();
+    assert_eq!(decoded, point);
+}
+
+#[test]
+fn generator_roundtrip_compressed() {
+    let point = ProjectivePoint::GENERATOR.to_affine();
+    let encoded = point.to_encoded_point(true);
+    let decoded = AffinePoint::from_encoded_point(&encoded).unwrap();
+    assert_eq!(decoded, point);
+}
+
+#[test]
+fn scalar_mul_roundtrips_through_encoded_point() {
+    for (k, _, _) in MUL_TEST_VECTORS {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        let point = (ProjectivePoint::GENERATOR * k).to_affine();
+
+        for compress in [false, true] {
+            let encoded = point.to_encoded_point(compress);
+            let decoded = AffinePoint::from_encoded_point(&encoded).unwrap();
+            assert_eq!(decoded, point);
+        }
+    }
+}
+
+#[test]
+fn off_curve_point_rejected() {
+    let mut encoded = ProjectivePoint::GENERATOR
+        .to_affine()
+        .to_encoded_point(false);
+    let bytes = encoded.as_bytes().len();
+    let mut tampered = encoded.as_bytes().to_vec();
+    tampered[bytes - 1] ^= 1;
+    encoded = EncodedPoint::from_bytes(&tampered).unwrap();
+    assert!(bool::from(
+        AffinePoint::from_encoded_point(&encoded).is_none()
+    ));
+}
@@ -0,0 +1,69 @@
+//! serde round-trip tests.
+
+#![cfg(all(feature = "serde", feature = "wip-arithmetic-do-not-use"))]
+
+use bp256::{
+    r1::{AffinePoint, ProjectivePoint, PublicKey, SecretKey},
+    Scalar,
+};
+use elliptic_curve::PrimeField;
+use hex_literal::hex;
+
+/// Private scalar for the test key pair.
+const D: [u8; 32] = hex!("1e0cfd43983bc59d1a7a02ee42cd87e8a4e39876bc073cb6f5f087b32a1e5e1a");
+
+fn cbor_roundtrip<T>(value: &T) -> T
+where
+    T: serde::Serialize + for<'de> serde::Deserialize<'de>,
+{
+    let mut buf = Vec::new();
+    ciborium::ser::into_writer(value, &mut buf).unwrap();
+    ciborium::de::from_reader(buf.as_slice()).unwrap()
+}
+
+#[test]
+fn scalar_roundtrip() {
+    let scalar = Scalar::from_repr(D.into()).unwrap();
+
+    let json = serde_json::to_string(&scalar).unwrap();
+    assert_eq!(serde_json::from_str::<Scalar>(&json).unwrap(), scalar);
+
+    assert_eq!(cbor_roundtrip(&scalar), scalar);
+}
+
+#[test]
+fn scalar_out_of_range_rejected() {
+    // group order n is not a valid scalar encoding
+    let json = "\"A9FB57DBA1EEA9BC3E660A909D838D718C397AA3B561A6F7901E0E82974856A7\"";
+    assert!(serde_json::from_str::<Scalar>(json).is_err());
+}
+
+#[test]
+fn affine_point_roundtrip() {
+    let point = (ProjectivePoint::GENERATOR * Scalar::from_repr(D.into()).unwrap()).to_affine();
+
+    let json = serde_json::to_string(&point).unwrap();
+    assert_eq!(serde_json::from_str::<AffinePoint>(&json).unwrap(), point);
+
+    assert_eq!(cbor_roundtrip(&point), point);
+}
+
+#[test]
+fn off_curve_point_rejected() {
+    // compressed encoding of an x-coordinate with no point on the curve
+    let json = "\"020000000000000000000000000000000000000000000000000000000000000005\"";
+    assert!(serde_json::from_str::<AffinePoint>(json).is_err());
+}
+
+#[test]
+fn public_key_roundtrip() {
+    let secret_key = SecretKey::from_slice(&D).unwrap();
+    let public_key = secret_key.public_key();
+
+    let json = serde_json::to_string(&public_key).unwrap();
+    assert_eq!(
+        serde_json::from_str::<PublicKey>(&json).unwrap(),
+        public_key
+    );
+    assert_eq!(cbor_roundtrip(&public_key), public_key);
+}
@@ -14,6 +14,12 @@
 #[cfg_attr(target_pointer_width = "64", path = "field/bp384_64.rs")]
 mod field_impl;
 
+/// The 32-bit backend, re-included on 64-bit hosts so differential tests can
+/// exercise it; see [`backend_diff`].
+#[cfg(all(test, target_pointer_width = "64"))]
+#[path = "field/bp384_32.rs"]
+mod field_impl_32;
+
 use self::field_impl::*;
 use crate::{FieldBytes, U384};
 use core::{
@@ -95,7 +101,7 @@ impl FieldElement {
 
     /// Returns the big-endian encoding of this [`FieldElement`].
     pub fn to_bytes(self) -> FieldBytes {
-        self.0.to_be_byte_array()
+        self.to_canonical().to_be_byte_array()
     }
 
     /// Translate [`FieldElement`] out of the Montgomery domain, returning a
@@ -162,6 +168,11 @@ impl FieldElement {
         )))
     }
 
+    /// Multiply by a single `u64` multiplier.
+    pub const fn mul_single(&self, rhs: u64) -> Self {
+        self.multiply(&Self::from_u64(rhs))
+    }
+
     /// Negate element.
     pub const fn neg(&self) -> Self {
         Self(U384::from_words(fiat_bp384_opp(self.0.as_words())))
@@ -322,4 +333,153 @@ mod tests {
     impl_field_invert_tests!(FieldElement);
     impl_field_sqrt_tests!(FieldElement);
     impl_primefield_tests!(FieldElement, T);
+
+    /// Fixed elements used to cross-check arithmetic against a big-integer
+    /// reference implementation.
+    const A: FieldElement = FieldElement::from_hex(
+        "0e0cf395b2680663697e2aa5c4f9203762e0df2e1c5f8b9d33b4839c3f8d7ad1d58112c7256275c11347e5d80a1c037e",
+    );
+    const B: FieldElement = FieldElement::from_hex(
+        "602ee35f8ccde05f40ad3b94b6763b370df7312ceeee3f939ba61ae4b5eed2177c7c9b2bf570004018d6d23eb058f40b",
+    );
+
+    #[test]
+    fn arithmetic_matches_reference() {
+        assert_eq!(
+            A * B,
+            FieldElement::from_hex(
+                "27687916ae7291c66b3386ddbed228b25c1254cc60424d187d0e0d17c013cf488e9b5ebf7f3435fed2187dd3c0b6c2db"
+            )
+        );
+        assert_eq!(
+            A + B,
+            FieldElement::from_hex(
+                "6e3bd6f53f35e6c2aa2b663a7b6f5b6e70d8105b0b4dcb30cf5a9e80f57c4ce951fdadf31ad276012c1eb816ba74f789"
+            )
+        );
+        assert_eq!(
+            A - B,
+            FieldElement::from_hex(
+                "3a972eb8c8d2932c382e5e8f5f6926df6a191f0b1ac5a2bdaac042d10955b9de05d81ec4c00f8ff281b813ac8acafbc6"
+            )
+        );
+        assert_eq!(
+            A.invert().unwrap(),
+            FieldElement::from_hex(
+                "565ba9a2c17347aeaf060e88cfe008d73236d44c930f3f89ed83326c0bbc36772b1adab9326ede7100e7cb6ac1fb0c28"
+            )
+        );
+    }
+
+    #[test]
+    fn sqrt_of_squares_roundtrips() {
+        for fe in [A, B, A * B] {
+            let square = fe.square();
+            let sqrt = square.sqrt().unwrap();
+            assert!(sqrt == fe || sqrt == -fe);
+            assert_eq!(sqrt.square(), square);
+        }
+    }
+
+    #[test]
+    fn sqrt_of_nonresidue_is_none() {
+        // 2 is the smallest quadratic non-residue of the brainpoolP384 prime
+        let non_residue = FieldElement::from(2u64);
+        assert!(bool::from(non_residue.sqrt().is_none()));
+    }
+
+    #[test]
+    fn to_bytes_is_canonical() {
+        // the byte encoding must leave the Montgomery domain
+        let two = FieldElement::from(2u64);
+        let mut expected = [0u8; 48];
+        expected[47] = 2;
+        assert_eq!(two.to_bytes().as_slice(), &expected);
+    }
+}
+
+/// Differential tests running identical operation sequences through the
+/// 32-bit and 64-bit fiat backends, so the 32-bit code path is exercised on
+/// 64-bit CI hosts as well.
+#[cfg(all(test, target_pointer_width = "64"))]
+mod backend_diff {
+    use super::{field_impl as field_64, field_impl_32 as field_32, FieldElement};
+    use elliptic_curve::{
+        rand_core::{OsRng, RngCore},
+        Field,
+    };
+
+    /// Convert 64-bit little-endian limbs into 32-bit ones.
+    fn to_words_32(words: &[u64; 6]) -> [u32; 12] {
+        let mut out = [0u32; 12];
+        for (i, w) in words.iter().enumerate() {
+            out[2 * i] = *w as u32;
+            out[2 * i + 1] = (*w >> 32) as u32;
+        }
+        out
+    }
+
+    /// Convert 32-bit little-endian limbs back into 64-bit ones.
+    fn to_words_64(words: &[u32; 12]) -> [u64; 6] {
+        let mut out = [0u64; 6];
+        for (i, w) in out.iter_mut().enumerate() {
+            *w = (words[2 * i] as u64) | ((words[2 * i + 1] as u64) << 32);
+        }
+        out
+    }
+
+    #[test]
+    fn randomized_operation_sequences_agree() {
+        let rand_canonical =
+            || -> [u64; 6] { FieldElement::random(&mut OsRng).to_canonical().to_words() };
+
+        let seed_a = rand_canonical();
+        let seed_b = rand_canonical();
+
+        let mut a_64 = field_64::fiat_bp384_to_montgomery(&seed_a);
+        let mut b_64 = field_64::fiat_bp384_to_montgomery(&seed_b);
+        let mut a_32 = field_32::fiat_bp384_to_montgomery(&to_words_32(&seed_a));
+        let mut b_32 = field_32::fiat_bp384_to_montgomery(&to_words_32(&seed_b));
+
+        let mut ops = [0u8; 256];
+        OsRng.fill_bytes(&mut ops);
+
+        for op in ops {
+            let (out_64, out_32) = match op % 5 {
+                0 => (
+                    field_64::fiat_bp384_add(&a_64, &b_64),
+                    field_32::fiat_bp384_add(&a_32, &b_32),
+                ),
+                1 => (
+                    field_64::fiat_bp384_sub(&a_64, &b_64),
+                    field_32::fiat_bp384_sub(&a_32, &b_32),
+                ),
+                2 => (
+                    field_64::fiat_bp384_mul(&a_64, &b_64),
+                    field_32::fiat_bp384_mul(&a_32, &b_32),
+                ),
+                3 => (
+                    field_64::fiat_bp384_square(&a_64),
+                    field_32::fiat_bp384_square(&a_32),
+                ),
+                _ => (
+                    field_64::fiat_bp384_opp(&a_64),
+                    field_32::fiat_bp384_opp(&a_32),
+                ),
+            };
+
+            // compare results in the canonical domain
+            assert_eq!(
+                field_64::fiat_bp384_from_montgomery(&out_64),
+                to_words_64(&field_32::fiat_bp384_from_montgomery(&out_32)),
+                "backend mismatch on op {op}"
+            );
+
+            // chain the sequences through both backends identically
+            b_64 = a_64;
+            a_64 = out_64;
+            b_32 = a_32;
+            a_32 = out_32;
+        }
+    }
 }
@@ -0,0 +1,185 @@
+//! hash2curve (RFC 9380) support for brainpoolP384r1.
+//!
+//! brainpool curves have a * b != 0, so the simplified SWU map applies to the
+//! curve directly without an isogeny. The Z parameter below was derived with
+//! the `find_z_sswu` algorithm from RFC 9380 Appendix H.2 (Z = -5 for the
+//! brainpoolP384r1 base field).
+
+use super::field::FieldElement;
+use crate::{
+    r1::{AffinePoint, BrainpoolP384r1, ProjectivePoint},
+    FieldBytes, Scalar, U384,
+};
+use elliptic_curve::{
+    bigint::ArrayEncoding,
+    consts::U72,
+    generic_array::GenericArray,
+    hash2curve::{FromOkm, GroupDigest, MapToCurve, OsswuMap, OsswuMapParams, Sgn0},
+    point::DecompressPoint,
+    subtle::Choice,
+};
+
+impl GroupDigest for BrainpoolP384r1 {
+    type FieldElement = FieldElement;
+}
+
+impl FromOkm for FieldElement {
+    type Length = U72;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        /// 2^288 mod p
+        const F_2_288: FieldElement = FieldElement::from_hex(
+            "000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000",
+        );
+
+        let mut d0_bytes = FieldBytes::default();
+        d0_bytes[12..].copy_from_slice(&data[..36]);
+        let d0 = FieldElement::from_uint_unchecked(U384::from_be_byte_array(d0_bytes));
+
+        let mut d1_bytes = FieldBytes::default();
+        d1_bytes[12..].copy_from_slice(&data[36..]);
+        let d1 = FieldElement::from_uint_unchecked(U384::from_be_byte_array(d1_bytes));
+
+        d0 * F_2_288 + d1
+    }
+}
+
+impl Sgn0 for FieldElement {
+    fn sgn0(&self) -> Choice {
+        self.is_odd()
+    }
+}
+
+impl OsswuMap for FieldElement {
+    const PARAMS: OsswuMapParams<Self> = OsswuMapParams {
+        // (p - 3) / 4
+        c1: &[
+            0x61d1_c004_cc41_fb14,
+            0xeb34_e9ca_6407_469c,
+            0x04ac_7686_5fed_c448,
+            0xc54b_dc42_7b55_15ad,
+            0x03d7_5bdf_9439_9077,
+            0x232e_47a0_a8ce_1b4a,
+        ],
+        // sqrt(-Z^3) mod p
+        c2: FieldElement::from_hex(
+            "7b68b2d6947bcd15758ca6ae776a7c0e9706cea8201b0c45f435246bcc03ba54b242c139b05543ece8a9ea3f9eb59dbb",
+        ),
+        // curve equation coefficient a
+        map_a: FieldElement::from_hex(
+            "7bc382c63d8c150c3c72080ace05afa0c2bea28e4fb22787139165efba91f90f8aa5814a503ad4eb04a8c7dd22ce2826",
+        ),
+        // curve equation coefficient b
+        map_b: FieldElement::from_hex(
+            "04a8c7dd22ce28268b39b55416f0447c2fb77de107dcd2a62e880ea53eeb62d57cb4390295dbc9943ab78696fa504c11",
+        ),
+        // Z = -5
+        z: FieldElement::from_u64(5).neg(),
+    };
+}
+
+impl MapToCurve for FieldElement {
+    type Output = ProjectivePoint;
+
+    fn map_to_curve(&self) -> Self::Output {
+        let (qx, qy) = self.osswu();
+
+        AffinePoint::decompress(&qx.to_bytes(), qy.is_odd())
+            .unwrap()
+            .into()
+    }
+}
+
+impl FromOkm for Scalar {
+    type Length = U72;
+
+    fn from_okm(data: &GenericArray<u8, Self::Length>) -> Self {
+        /// 2^288 mod n
+        const F_2_288: Scalar = Scalar::from_hex(
+            "000000000000000000000001000000000000000000000000000000000000000000000000000000000000000000000000",
+        );
+
+        let mut d0_bytes = FieldBytes::default();
+        d0_bytes[12..].copy_from_slice(&data[..36]);
+        let d0 = Scalar::from_uint_unchecked(U384::from_be_byte_array(d0_bytes));
+
+        let mut d1_bytes = FieldBytes::default();
+        d1_bytes[12..].copy_from_slice(&data[36..]);
+        let d1 = Scalar::from_uint_unchecked(U384::from_be_byte_array(d1_bytes));
+
+        d0 * F_2_288 + d1
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::FieldElement;
+    use crate::r1::BrainpoolP384r1;
+    use elliptic_curve::{
+        group::cofactor::CofactorGroup,
+        hash2curve::{ExpandMsgXmd, FromOkm, GroupDigest, OsswuMap},
+    };
+    use sha2::Sha384;
+
+    const DST: &[u8] = b"QUUX-V01-CS02-with-BP384_XMD:SHA-384_SSWU_RO_";
+
+    #[test]
+    fn z_is_nonsquare() {
+        let z = <FieldElement as OsswuMap>::PARAMS.z;
+        assert!(bool::from(z.sqrt().is_none()));
+
+        // c2^2 == -z^3
+        let c2 = <FieldElement as OsswuMap>::PARAMS.c2;
+        assert_eq!(c2.square(), -(z * z * z));
+    }
+
+    #[test]
+    fn hash_from_bytes_deterministic_and_dst_sensitive() {
+        let p1 =
+            BrainpoolP384r1::hash_from_bytes::<ExpandMsgXmd<Sha384>>(&[b"abc"], &[DST]).unwrap();
+        let p2 =
+            BrainpoolP384r1::hash_from_bytes::<ExpandMsgXmd<Sha384>>(&[b"abc"], &[DST]).unwrap();
+        let p3 = BrainpoolP384r1::hash_from_bytes::<ExpandMsgXmd<Sha384>>(&[b"abc"], &[b"other"])
+            .unwrap();
+
+        assert_eq!(p1, p2);
+        assert_ne!(p1, p3);
+        assert!(!bool::from(p1.is_small_order()));
+    }
+
+    #[test]
+    fn encode_from_bytes_nonuniform_variant() {
+        // the _NU_ (encode) variant maps through a single field element and
+        // must differ from the uniform (_RO_) variant
+        let nu =
+            BrainpoolP384r1::encode_from_bytes::<ExpandMsgXmd<Sha384>>(&[b"abc"], &[DST]).unwrap();
+        let ro =
+            BrainpoolP384r1::hash_from_bytes::<ExpandMsgXmd<Sha384>>(&[b"abc"], &[DST]).unwrap();
+
+        assert!(!bool::from(nu.is_small_order()));
+        assert_ne!(nu, ro);
+    }
+
+    #[test]
+    fn hash_to_scalar_works() {
+        let s1 =
+            BrainpoolP384r1::hash_to_scalar::<ExpandMsgXmd<Sha384>>(&[b"abc"], &[DST]).unwrap();
+        let s2 =
+            BrainpoolP384r1::hash_to_scalar::<ExpandMsgXmd<Sha384>>(&[b"abd"], &[DST]).unwrap();
+        assert_ne!(s1, s2);
+    }
+
+    #[test]
+    fn from_okm_matches_wide_interpretation() {
+        // from_okm(data) must equal the 576-bit big-endian integer mod p
+        let data = [0xffu8; 72];
+        let fe = FieldElement::from_okm(elliptic_curve::generic_array::GenericArray::from_slice(
+            &data,
+        ));
+        // 2^576 - 1 mod p, precomputed
+        let expected = FieldElement::from_hex(
+            "574fa674e67523f6e4181ea4a6bfcbf0ddf803219c3bdb004a212e132d61bf00d49dbf326b386110b8d2a40f2f566605",
+        );
+        assert_eq!(fe, expected);
+    }
+}
@@ -22,8 +22,10 @@ use core::{
     ops::{AddAssign, MulAssign, Neg, Shr, ShrAssign, SubAssign},
 };
 use elliptic_curve::{
-    bigint::{ArrayEncoding, Integer, Limb},
-    ff::PrimeField,
+    bigint::{ArrayEncoding, Integer, Limb, NonZero, U768},
+    consts::U96,
+    ff::{FromUniformBytes, PrimeField},
+    generic_array::GenericArray,
     ops::{Invert, Reduce},
     scalar::{FromUintUnchecked, IsHigh},
     subtle::{
@@ -33,6 +35,16 @@ use elliptic_curve::{
     Error, Result,
 };
 
+#[cfg(feature = "bits")]
+use {crate::ScalarBits, elliptic_curve::group::ff::PrimeFieldBits};
+
+#[cfg(feature = "serde")]
+use {
+    crate::r1::BrainpoolP384r1,
+    elliptic_curve::ScalarPrimitive,
+    serdect::serde::{de, ser, Deserialize, Serialize},
+};
+
 #[cfg(doc)]
 use core::ops::{Add, Mul, Sub};
 
@@ -54,7 +66,7 @@ impl Scalar {
 
     /// Decode [`Scalar`] from a big endian byte slice.
     pub fn from_slice(slice: &[u8]) -> Result<Self> {
-        if slice.len() == 32 {
+        if slice.len() == 48 {
             Option::from(Self::from_bytes(FieldBytes::from_slice(slice))).ok_or(Error)
         } else {
             Err(Error)
@@ -99,7 +111,7 @@ impl Scalar {
 
     /// Returns the big-endian encoding of this [`Scalar`].
     pub fn to_bytes(self) -> FieldBytes {
-        self.0.to_be_byte_array()
+        self.to_canonical().to_be_byte_array()
     }
 
     /// Translate [`Scalar`] out of the Montgomery domain, returning a
@@ -359,6 +371,22 @@ impl PrimeField for Scalar {
     }
 }
 
+/// 96-byte wide serialized scalar, reduced via [`Reduce<U768>`].
+pub type WideBytes = GenericArray<u8, U96>;
+
+#[cfg(feature = "bits")]
+impl PrimeFieldBits for Scalar {
+    type ReprBits = fiat_bp384_scalar_montgomery_domain_field_element;
+
+    fn to_le_bits(&self) -> ScalarBits {
+        self.to_canonical().to_words().into()
+    }
+
+    fn char_le_bits() -> ScalarBits {
+        ORDER.to_words().into()
+    }
+}
+
 impl Reduce<U384> for Scalar {
     type Bytes = FieldBytes;
 
@@ -374,6 +402,28 @@ impl Reduce<U384> for Scalar {
     }
 }
 
+impl Reduce<U768> for Scalar {
+    type Bytes = WideBytes;
+
+    fn reduce(w: U768) -> Self {
+        const WIDE_ORDER: NonZero<U768> = NonZero::<U768>::const_new(U384::ZERO.concat(&ORDER)).0;
+
+        let (_, lo) = w.rem(&WIDE_ORDER).split();
+        Self::from_uint_unchecked(lo)
+    }
+
+    #[inline]
+    fn reduce_bytes(bytes: &WideBytes) -> Self {
+        Self::reduce(U768::from_be_byte_array(*bytes))
+    }
+}
+
+impl FromUniformBytes<96> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 96]) -> Self {
+        <Self as Reduce<U768>>::reduce(U768::from_be_slice(bytes))
+    }
+}
+
 impl From<Scalar> for FieldBytes {
     fn from(scalar: Scalar) -> Self {
         scalar.to_repr()
@@ -406,6 +456,26 @@ impl TryFrom<U384> for Scalar {
     }
 }
 
+#[cfg(feature = "serde")]
+impl Serialize for Scalar {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        ScalarPrimitive::<BrainpoolP384r1>::from(self).serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for Scalar {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Ok(ScalarPrimitive::<BrainpoolP384r1>::deserialize(deserializer)?.into())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::Scalar;
@@ -430,4 +500,79 @@ mod tests {
     impl_field_invert_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
     impl_primefield_tests!(Scalar, T);
+
+    /// n - 1
+    const N_MINUS_1: Scalar = Scalar::from_hex(
+        "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046564",
+    );
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn char_le_bits_encodes_order() {
+        use elliptic_curve::group::ff::PrimeFieldBits;
+
+        let char_bits = Scalar::char_le_bits();
+        let mut order = super::U384::ZERO;
+        for (i, bit) in char_bits.iter().enumerate() {
+            if *bit {
+                order |= super::U384::ONE << i;
+            }
+        }
+        assert_eq!(order, super::ORDER);
+    }
+
+    #[test]
+    fn from_slice_accepts_48_bytes() {
+        let bytes = N_MINUS_1.to_bytes();
+        assert_eq!(Scalar::from_slice(&bytes).unwrap(), N_MINUS_1);
+        assert!(Scalar::from_slice(&bytes[..32]).is_err());
+    }
+
+    #[test]
+    fn boundary_values() {
+        use super::ORDER;
+        use elliptic_curve::bigint::ArrayEncoding;
+
+        // 0 and 1 round-trip through the canonical representation
+        assert_eq!(
+            Scalar::from_repr(Scalar::ZERO.to_repr()).unwrap(),
+            Scalar::ZERO
+        );
+        assert_eq!(
+            Scalar::from_repr(Scalar::ONE.to_repr()).unwrap(),
+            Scalar::ONE
+        );
+
+        // n - 1 is valid and wraps to zero when incremented
+        assert_eq!(Scalar::from_repr(N_MINUS_1.to_repr()).unwrap(), N_MINUS_1);
+        assert_eq!(N_MINUS_1 + Scalar::ONE, Scalar::ZERO);
+
+        // n itself must be rejected
+        assert!(bool::from(
+            Scalar::from_repr(ORDER.to_be_byte_array()).is_none()
+        ));
+    }
+
+    #[test]
+    fn reduce_wide_matches_integer_reduction() {
+        use super::{Reduce, U768};
+        use elliptic_curve::ff::FromUniformBytes;
+
+        // 2^768 - 1 mod n, precomputed
+        assert_eq!(
+            <Scalar as Reduce<U768>>::reduce(U768::MAX),
+            Scalar::from_hex(
+                "0ce8941a614e97c28f886dc965165fdb574a74cb52d748ff2a927e3b9802688a37264e202f2b6b6eac4ed3a2de771c8d"
+            )
+        );
+
+        // big-endian bytes 00 01 02 .. 5f mod n, precomputed
+        let bytes: [u8; 96] = core::array::from_fn(|i| i as u8);
+        assert_eq!(
+            Scalar::from_uniform_bytes(&bytes),
+            Scalar::from_hex(
+                "21597dd95cbf9e667bc07b1f93727b03118490a99eefb216ae8ae616cf4df643a8f0abd619f2ebbbde8f7903d57884a7"
+            )
+        );
+    }
 }
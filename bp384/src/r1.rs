@@ -1,6 +1,9 @@
 //! brainpoolP384r1 elliptic curve: verifiably pseudo-random variant
 
-#[cfg(feature = "ecdsa")]
+#[cfg(feature = "ecdh")]
+pub mod ecdh;
+
+#[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
 #[cfg(feature = "wip-arithmetic-do-not-use")]
@@ -67,6 +70,14 @@ impl FieldBytesEncoding<BrainpoolP384r1> for U384 {
     }
 }
 
+/// brainpoolP384r1 non-zero scalar: a scalar value in the range `[1, n)`.
+#[cfg(feature = "wip-arithmetic-do-not-use")]
+pub type NonZeroScalar = elliptic_curve::NonZeroScalar<BrainpoolP384r1>;
+
+/// brainpoolP384r1 public key.
+#[cfg(feature = "wip-arithmetic-do-not-use")]
+pub type PublicKey = elliptic_curve::PublicKey<BrainpoolP384r1>;
+
 /// brainpoolP384r1 secret key.
 pub type SecretKey = elliptic_curve::SecretKey<BrainpoolP384r1>;
 
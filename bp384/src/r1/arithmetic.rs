@@ -36,6 +36,11 @@ impl PrimeCurveParams for BrainpoolP384r1 {
         FieldElement::from_hex("1d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8e826e03436d646aaef87b2e247d4af1e"),
         FieldElement::from_hex("8abe1d7520f9c2a45cb1eb8e95cfd55262b70b29feec5864e19c054ff99129280e4646217791811142820341263c5315"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(scalar: &Scalar) -> ProjectivePoint {
+        GENERATOR_TABLE.mul(scalar)
+    }
 }
 
 impl From<ScalarPrimitive> for Scalar {
@@ -61,3 +66,50 @@ impl From<&Scalar> for ScalarPrimitive {
         ScalarPrimitive::new(scalar.into()).unwrap()
     }
 }
+
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(any(feature = "std", feature = "critical-section"))
+))]
+compile_error!("`precomputed-tables` feature requires either `std` or `critical-section`");
+
+/// Lazily computed table of multiples of the brainpoolP384r1 generator,
+/// used to accelerate [`MulByGenerator`][`elliptic_curve::ops::MulByGenerator`].
+#[cfg(feature = "precomputed-tables")]
+static GENERATOR_TABLE: once_cell::sync::Lazy<primeorder::FixedBaseTable<BrainpoolP384r1, 49>> =
+    once_cell::sync::Lazy::new(|| primeorder::FixedBaseTable::new(&ProjectivePoint::GENERATOR));
+
+#[cfg(all(test, feature = "precomputed-tables"))]
+mod tests {
+    use super::{ProjectivePoint, Scalar};
+    use elliptic_curve::{ops::MulByGenerator, rand_core::OsRng, Field, PrimeField};
+
+    #[test]
+    fn mul_by_generator_matches_generic_mul() {
+        // edge cases
+        for scalar in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::from_u64(2),
+            Scalar::from_hex(
+                "8cb91e82a3386d280f5d6f7e50e641df152f7109ed5456b31f166e6cac0425a7cf3ab6af6b7fc3103b883202e9046564",
+            ),
+        ] {
+            assert_eq!(
+                ProjectivePoint::mul_by_generator(&scalar),
+                ProjectivePoint::GENERATOR * scalar
+            );
+        }
+
+        // random scalars
+        for _ in 0..100 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(
+                ProjectivePoint::mul_by_generator(&scalar),
+                ProjectivePoint::GENERATOR * scalar,
+                "mismatch for {:?}",
+                scalar.to_repr()
+            );
+        }
+    }
+}
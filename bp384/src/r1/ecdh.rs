@@ -0,0 +1,101 @@
+//! Elliptic Curve Diffie-Hellman (Ephemeral) Support.
+//!
+//! This module contains a high-level interface for performing ephemeral
+//! Diffie-Hellman key exchanges using the brainpoolP384r1 elliptic curve.
+//!
+//! # Usage
+//!
+//! This usage example is from the perspective of two participants in the
+//! exchange, nicknamed "Alice" and "Bob".
+//!
+//! ```
+//! use bp384::r1::{ecdh::EphemeralSecret, EncodedPoint, PublicKey};
+//! use rand_core::OsRng; // requires 'getrandom' feature
+//!
+//! // Alice
+//! let alice_secret = EphemeralSecret::random(&mut OsRng);
+//! let alice_pk_bytes = EncodedPoint::from(alice_secret.public_key());
+//!
+//! // Bob
+//! let bob_secret = EphemeralSecret::random(&mut OsRng);
+//! let bob_pk_bytes = EncodedPoint::from(bob_secret.public_key());
+//!
+//! // Alice decodes Bob's serialized public key and computes a shared secret
+//! // from it. Decoding validates the peer point is on the curve and not the
+//! // identity.
+//! let bob_public = PublicKey::from_sec1_bytes(bob_pk_bytes.as_ref())
+//!     .expect("bob's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let alice_shared = alice_secret.diffie_hellman(&bob_public);
+//!
+//! // Bob decodes Alice's serialized public key and computes the same shared secret
+//! let alice_public = PublicKey::from_sec1_bytes(alice_pk_bytes.as_ref())
+//!     .expect("alice's public key is invalid!"); // In real usage, don't panic, handle this!
+//!
+//! let bob_shared = bob_secret.diffie_hellman(&alice_public);
+//!
+//! // Both participants arrive on the same shared secret, usable either as
+//! // raw x-coordinate bytes or via the HKDF `extract`/`expand` helpers.
+//! assert_eq!(alice_shared.raw_secret_bytes(), bob_shared.raw_secret_bytes());
+//! ```
+
+pub use elliptic_curve::ecdh::diffie_hellman;
+
+use super::BrainpoolP384r1;
+
+/// brainpoolP384r1 Ephemeral Diffie-Hellman Secret.
+pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<BrainpoolP384r1>;
+
+/// Shared secret value computed via ECDH key agreement.
+pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<BrainpoolP384r1>;
+
+#[cfg(test)]
+mod tests {
+    use super::diffie_hellman;
+    use crate::r1::{PublicKey, SecretKey};
+    use hex_literal::hex;
+
+    /// ECDH known-answer test, cross-checked against OpenSSL's
+    /// brainpoolP384r1 implementation (the RFC 7027 appendix vectors are not
+    /// vendored in this repository).
+    #[test]
+    fn ecdh_known_answer() {
+        let alice_secret = SecretKey::from_slice(&hex!(
+            "1e0cfd43983bc59d1a7a02ee42cd87e8a4e39876bc073cb6f5f087b32a1e5e1a
+             1e0cfd43983bc59d1a7a02ee42cd87e8"
+        ))
+        .unwrap();
+        let bob_secret = SecretKey::from_slice(&hex!(
+            "631db56a47bf29a254be51ffc56d1b3eaeb3e36e3b1c4289aa23a0759cd3e1c7
+             631db56a47bf29a254be51ffc56d1b3e"
+        ))
+        .unwrap();
+
+        let alice_public = PublicKey::from_sec1_bytes(&hex!(
+            "048408dd62286e12f110a9f240e4e950ca9f76bb8da4429dde80105f234a82a236
+             eae6d4adad9fb68d0e91bc77cdeec66216cea9275f14d015b6f42ab0cd3df82d
+             26a096e4036ff01d0b01606a908e571c9c2e104496db21d1453f020661942d7a"
+        ))
+        .unwrap();
+        let bob_public = PublicKey::from_sec1_bytes(&hex!(
+            "0432fd7bb53deaaaaa35230c0467b452eea0689f415ba10be18714abc28ba2d835
+             4396f14a6d7882f24f4624c128fb229a16ef8302dac6a3f37be9482f5c549f8c
+             7e3c8d85222442e59c4341b694ad873a94178207fa876d8537d882bace659d0d"
+        ))
+        .unwrap();
+
+        assert_eq!(alice_secret.public_key(), alice_public);
+        assert_eq!(bob_secret.public_key(), bob_public);
+
+        let expected_z = hex!(
+            "306a513857e5c31ad3cdebb21ab93e0bfb3a045a9ff49853fb01fa9423c77b39
+             e07b1b5622fd418e4f4620777cb3116c"
+        );
+
+        let alice_shared = diffie_hellman(alice_secret.to_nonzero_scalar(), bob_public.as_affine());
+        let bob_shared = diffie_hellman(bob_secret.to_nonzero_scalar(), alice_public.as_affine());
+
+        assert_eq!(alice_shared.raw_secret_bytes().as_slice(), &expected_z);
+        assert_eq!(bob_shared.raw_secret_bytes().as_slice(), &expected_z);
+    }
+}
@@ -1,14 +1,128 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! This module contains support for computing and verifying ECDSA signatures.
+//! To use it, you will need to enable one of the two following Cargo features:
+//!
+//! - `ecdsa-core`: provides only the [`Signature`] type (which represents an
+//!   ECDSA/brainpoolP384r1 signature). Does not require arithmetic. This is
+//!   useful for 3rd-party crates which wish to use the [`Signature`] type for
+//!   interoperability purposes.
+//! - `ecdsa`: provides `ecdsa-core` features plus the [`SigningKey`] and
+//!   [`VerifyingKey`] types which natively implement ECDSA/brainpoolP384r1
+//!   signing and verification with SHA-384 as the default digest, including
+//!   `PrehashSigner`/`PrehashVerifier` for 48-byte digests.
 
-pub use super::BrainpoolP384r1;
+pub use ecdsa_core::signature::{self, Error};
+
+use super::BrainpoolP384r1;
+
+#[cfg(feature = "ecdsa")]
+use {
+    super::AffinePoint,
+    crate::Scalar,
+    ecdsa_core::hazmat::{SignPrimitive, VerifyPrimitive},
+};
 
 /// ECDSA/brainpoolP384r1 signature (fixed-size)
-pub type Signature = ecdsa::Signature<BrainpoolP384r1>;
+pub type Signature = ecdsa_core::Signature<BrainpoolP384r1>;
 
 /// ECDSA/brainpoolP384r1 signature (ASN.1 DER encoded)
-pub type DerSignature = ecdsa::der::Signature<BrainpoolP384r1>;
+pub type DerSignature = ecdsa_core::der::Signature<BrainpoolP384r1>;
+
+/// ECDSA/brainpoolP384r1 signing key
+#[cfg(feature = "ecdsa")]
+pub type SigningKey = ecdsa_core::SigningKey<BrainpoolP384r1>;
+
+/// ECDSA/brainpoolP384r1 verification key (i.e. public key)
+#[cfg(feature = "ecdsa")]
+pub type VerifyingKey = ecdsa_core::VerifyingKey<BrainpoolP384r1>;
 
 #[cfg(feature = "sha384")]
-impl ecdsa::hazmat::DigestPrimitive for BrainpoolP384r1 {
+impl ecdsa_core::hazmat::DigestPrimitive for BrainpoolP384r1 {
     type Digest = sha2::Sha384;
 }
+
+#[cfg(feature = "ecdsa")]
+impl SignPrimitive<BrainpoolP384r1> for Scalar {}
+
+#[cfg(feature = "ecdsa")]
+impl VerifyPrimitive<BrainpoolP384r1> for AffinePoint {}
+
+#[cfg(all(test, feature = "ecdsa"))]
+mod tests {
+    use super::{DerSignature, Signature, SigningKey, VerifyingKey};
+    use ecdsa_core::signature::{
+        hazmat::{PrehashSigner, PrehashVerifier},
+        Signer, Verifier,
+    };
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+    use sha2::{Digest, Sha384};
+
+    /// Private scalar for the deterministic (RFC 6979) vectors below,
+    /// generated with an independent HMAC-SHA-384 DRBG implementation and
+    /// cross-verified with OpenSSL.
+    const D: [u8; 48] = hex!(
+        "1234567890abcdef1234567890abcdef1234567890abcdef1234567890abcdef
+         1234567890abcdef1234567890abcdef"
+    );
+
+    #[test]
+    fn rfc6979() {
+        let signer = SigningKey::from_bytes(&D.into()).unwrap();
+
+        let signature: Signature = signer.sign(b"sample");
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "2d6e1a3ed77e73d7b3efb0022ece6092352a45eaafbc7572e644d956d140afe7
+                 72db90d34859114bb7e550d4c4a05ce1
+                 37a14bc995df57176bc269cb7e90408ab590ab688b61ef2e74f00e160b121f44
+                 431ad0d67bad8f2b3988532672ccb6c9"
+            )
+        );
+
+        let signature: Signature = signer.sign(b"test");
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "448a9d04c43eb56591de58b508356586241892302597e159ba9f1fe69abec764
+                 2cafae5dea169a0466736800aa301f65
+                 651d1573ffdd2cfe6acf0629065914ebf0ac214f4735698680ddad821abdb785
+                 e7faa166fdeaf7ada4fbcbb420ff0468"
+            )
+        );
+    }
+
+    #[test]
+    fn signing_roundtrip() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = VerifyingKey::from(&signing_key);
+
+        let message = b"brainpoolP384r1 test message";
+        let signature: Signature = signing_key.sign(message);
+        assert!(verifying_key.verify(message, &signature).is_ok());
+        assert!(verifying_key.verify(b"other message", &signature).is_err());
+    }
+
+    #[test]
+    fn prehash_roundtrip() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = VerifyingKey::from(&signing_key);
+
+        let prehash = Sha384::digest(b"48-byte prehash");
+        let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+        assert!(verifying_key.verify_prehash(&prehash, &signature).is_ok());
+
+        let other = Sha384::digest(b"other prehash");
+        assert!(verifying_key.verify_prehash(&other, &signature).is_err());
+    }
+
+    #[test]
+    fn der_signature_roundtrip() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let signature: DerSignature = signing_key.sign(b"DER round trip");
+        let fixed = Signature::from_der(signature.as_bytes()).unwrap();
+        assert_eq!(fixed.to_der().as_bytes(), signature.as_bytes());
+    }
+}
@@ -1,6 +1,6 @@
 //! brainpoolP384t1 elliptic curve: twisted variant
 
-#[cfg(feature = "ecdsa")]
+#[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
 #[cfg(feature = "wip-arithmetic-do-not-use")]
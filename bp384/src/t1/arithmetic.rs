@@ -61,3 +61,44 @@ impl From<&Scalar> for ScalarPrimitive {
         ScalarPrimitive::new(scalar.into()).unwrap()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::BrainpoolP384t1;
+    use crate::{r1::BrainpoolP384r1, FieldElement};
+    use primeorder::PrimeCurveParams;
+
+    /// RFC 5639 Section 3.6: `Z` relating brainpoolP384r1 to its twisted
+    /// brainpoolP384t1 form via (x, y) -> (x * Z^2, y * Z^3).
+    const Z: FieldElement = FieldElement::from_hex(
+        "41dfe8dd399331f7166a66076734a89cd0d2bcdb7d068e44e1f378f41ecbae97d2d63dbc87bccddccc5da39e8589291c",
+    );
+
+    #[test]
+    fn generator_satisfies_twist_relation() {
+        let z2 = Z.square();
+        let z3 = z2 * Z;
+
+        let (rx, ry) = BrainpoolP384r1::GENERATOR;
+        let (tx, ty) = BrainpoolP384t1::GENERATOR;
+
+        assert_eq!(rx * z2, tx);
+        assert_eq!(ry * z3, ty);
+    }
+
+    #[test]
+    fn equation_satisfies_twist_relation() {
+        let z2 = Z.square();
+        let z4 = z2.square();
+        let z6 = z4 * z2;
+
+        assert_eq!(
+            BrainpoolP384r1::EQUATION_A * z4,
+            BrainpoolP384t1::EQUATION_A
+        );
+        assert_eq!(
+            BrainpoolP384r1::EQUATION_B * z6,
+            BrainpoolP384t1::EQUATION_B
+        );
+    }
+}
@@ -1,14 +1,58 @@
 //! Elliptic Curve Digital Signature Algorithm (ECDSA)
+//!
+//! See the [`r1::ecdsa`][`crate::r1::ecdsa`] module documentation for an
+//! overview of the `ecdsa-core` and `ecdsa` Cargo features.
 
-pub use super::BrainpoolP384t1;
+pub use ecdsa_core::signature::{self, Error};
+
+use super::BrainpoolP384t1;
+
+#[cfg(feature = "ecdsa")]
+use {
+    super::AffinePoint,
+    crate::Scalar,
+    ecdsa_core::hazmat::{SignPrimitive, VerifyPrimitive},
+};
 
 /// ECDSA/brainpoolP384t1 signature (fixed-size)
-pub type Signature = ecdsa::Signature<BrainpoolP384t1>;
+pub type Signature = ecdsa_core::Signature<BrainpoolP384t1>;
 
 /// ECDSA/brainpoolP384t1 signature (ASN.1 DER encoded)
-pub type DerSignature = ecdsa::der::Signature<BrainpoolP384t1>;
+pub type DerSignature = ecdsa_core::der::Signature<BrainpoolP384t1>;
+
+/// ECDSA/brainpoolP384t1 signing key
+#[cfg(feature = "ecdsa")]
+pub type SigningKey = ecdsa_core::SigningKey<BrainpoolP384t1>;
+
+/// ECDSA/brainpoolP384t1 verification key (i.e. public key)
+#[cfg(feature = "ecdsa")]
+pub type VerifyingKey = ecdsa_core::VerifyingKey<BrainpoolP384t1>;
 
 #[cfg(feature = "sha384")]
-impl ecdsa::hazmat::DigestPrimitive for BrainpoolP384t1 {
+impl ecdsa_core::hazmat::DigestPrimitive for BrainpoolP384t1 {
     type Digest = sha2::Sha384;
 }
+
+#[cfg(feature = "ecdsa")]
+impl SignPrimitive<BrainpoolP384t1> for Scalar {}
+
+#[cfg(feature = "ecdsa")]
+impl VerifyPrimitive<BrainpoolP384t1> for AffinePoint {}
+
+#[cfg(all(test, feature = "ecdsa"))]
+mod tests {
+    use super::{Signature, SigningKey, VerifyingKey};
+    use ecdsa_core::signature::{Signer, Verifier};
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn signing_roundtrip() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = VerifyingKey::from(&signing_key);
+
+        let message = b"brainpoolP384t1 test message";
+        let signature: Signature = signing_key.sign(message);
+        assert!(verifying_key.verify(message, &signature).is_ok());
+        assert!(verifying_key.verify(b"other message", &signature).is_err());
+    }
+}
@@ -0,0 +1,3 @@
+//! brainpoolP384r1 test vectors.
+
+pub mod group;
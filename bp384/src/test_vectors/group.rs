@@ -0,0 +1,127 @@
+//! Test vectors for the brainpoolP384r1 group.
+
+use hex_literal::hex;
+
+/// Repeated addition of the generator.
+///
+/// The first 20 multiples of the brainpoolP384r1 (RFC 5639) base point,
+/// generated with sagemath.
+pub const ADD_TEST_VECTORS: &[([u8; 48], [u8; 48])] = &[
+    (
+        hex!("1D1C64F068CF45FFA2A63A81B7C13F6B8847A3E77EF14FE3DB7FCAFE0CBD10E8E826E03436D646AAEF87B2E247D4AF1E"),
+        hex!("8ABE1D7520F9C2A45CB1EB8E95CFD55262B70B29FEEC5864E19C054FF99129280E4646217791811142820341263C5315"),
+    ),
+    (
+        hex!("2282BC382A2F4DFCB95C3495D7B4FD590AD520B3EB6BE4D6EC2F80C4E0F70DF87C4BA74A09B553EBB427B58DF9D59FCA"),
+        hex!("0EDDA83773AC68735768D14A24F37A57CE9BEDBC170921CE4D89DD051728FC3EB4B4EA69AB64FC288F1B29502B6E1D30"),
+    ),
+    (
+        hex!("7B63205BF00DDAE73B17452B6A27EBF53DF581348C6949F83EE1B6FCC7463BBE3C11EF6596A3B8897D7CC85B3035F11F"),
+        hex!("761D3A4A5F8093775521A326BC02BAAF7B2EB481EAD16A5C7B2BD39462363E0373C0EDAEA3B8F59381D7129D48772EB3"),
+    ),
+    (
+        hex!("0DD5393F5C8859560675D5ABC72EBC2AE45A6DCA90945DBA8D4462D702C844E11A345294D5446828E48921EC979F4A32"),
+        hex!("80FFDFA1EA4FDF56AD184F44D3AB5005832CF70A0254F70F071EC79036C5F4676FCE80C25F70C7AF103B90824E878BA2"),
+    ),
+    (
+        hex!("0D3EC4DFCE2647725100DABEA7B5F59F465848A4B4FBB6080AC96DDF237F84F4FBC1247651C2770D2CEBAB9FD2412DFB"),
+        hex!("20168AC65E9BB101EBAA167FA90635F939F00D1D90ED0C6D97495C4579BB950CE059C219DFBBC32B3F9B162E47634690"),
+    ),
+    (
+        hex!("6773700FE1C84330E5214B93138EB6621125A14B24FE40A6B98FBB28AC04A042063B62EAF733F77CA86D0F16DD326E03"),
+        hex!("73755839D7C082F5B3BEC74AFE05D085B3162B55DEC72A6D6BBFF45272C0A46F8ABDF80B78B73E55108FEB8752FDDE12"),
+    ),
+    (
+        hex!("6460F955EFDCBF3BF7393081DDF04A64747781BC8956C1E5FF47BE522F7F758244AE054E91E8AA160C76DC7302BCF181"),
+        hex!("7A30D2AF9219E43D33BE0B515A36F3C95C17B17DCAD568EF85F51EAE54657C72ED3CA9972DD90DA5FC54207824DB4187"),
+    ),
+    (
+        hex!("1B7A9ED77824BEE6132A486D2DBE66B165110CCBF8F7868E72F75EFD9F27FD557AA6E9C7A3265B3B4E0BE9618D8A3829"),
+        hex!("24E1272A69C1339D1EA0720192F61AA079F38F5F0960C8FAEDAB59EB73B45839EFC350F023876D6E4BDE607F6492B17C"),
+    ),
+    (
+        hex!("318CCBF708397F07ABA57E45A1B99B3DA92B638FAB9B5123CB8050CB12FF55D02CA04884153F3CA5BE9A6FA4D102FDCC"),
+        hex!("06C64153906E5540A6F5562626E4CF07A0A7709206E67FCD6519B9CB144A5AF68A892DF80129727A5F0C4D7C799BDD7A"),
+    ),
+    (
+        hex!("52A858B07EC4EA734D382F06B4A3132078C3C59BD5487FED24282A927CBBA20549BF62999A511CCD5D8FDC43ECB0206B"),
+        hex!("6C182D0955164F22C52783EBF4A5B7AD50577172434ADCDC377D71165AA33BE8E14BA26C4A4CDDE5F93A4DB5A9A62924"),
+    ),
+    (
+        hex!("29D17C36E8FAC6BE8222A33A24CFCC959504EE698D6CE046F650CDCE31A1F42A019ED5E75838A2E1E1EBFA3EBD501097"),
+        hex!("6AAEED397A139D1E8C059E4D23214FB28687D57DFB2B569C3F35B03F2AD19D6768D387929464D8CEA82289C636E2AB45"),
+    ),
+    (
+        hex!("10702E8BD01F829F02BC50CFB04C5ABE516201FF9AC16D5EED84795D52BF27A1AB423724C8D097D72BC65DC9E675AB9E"),
+        hex!("6A32E9327607A977498EBB410BE50A94581F10C618ABBB3273EC71D6B0B59AAD9CFE5BB03811C3E8EF94449EC590BDEB"),
+    ),
+    (
+        hex!("746F20945A91D52BA4AC0D1499008C7B4F4FE2951E9B2FC9EE6435AABFCE8519E866314F4CFCDECC68724BA7654B8A97"),
+        hex!("199ED4B68437E1AFB613EF8F694BFB3B818A8375EA30532DD7F5363E477661D17FE3E4FD4ED3B16D83E0A265DFB80EC6"),
+    ),
+    (
+        hex!("324A464B6792011A7D85E4C8A4215907025728624313282DEDAC2232ABDB92C1B6219A0F6A5D791066CAB026E301F540"),
+        hex!("344C3E35692264867A27DD28971AE9BD84F65526D94F2F7DF49342F78C7CD8EFF3BA957369D839E1629DC4DB06A7A85A"),
+    ),
+    (
+        hex!("08D2819AD4B10108992302C3873505A1DE83D467F4A6E8E0EE00E1E96D82BB00313C2F19665476B17ECD1FD73D60E639"),
+        hex!("802E50B2B2CB80013C5833C41C2B396EA9F3130EA86756E9319433829876BBFDB288A5F22A0F4BB436E616AF3F89AE04"),
+    ),
+    (
+        hex!("435074AF679B87539FEC09A171F98689B5AC70BDAFE69A75698397D77B8C260AA6C89FD31957528C1E91569C78B3EDB4"),
+        hex!("4D1927E308E7E216F62C4126902D7FEE91B783CE4E140B088500E44429BA2B07DA27401279533F2CF177D8726BC4DC34"),
+    ),
+    (
+        hex!("4D0BFEAC9BFD1564268C0A910C4F9601388F576AC92467F7CA9D72EDC36CE5B387E9B66563D3AF2F4B857EAB44FAA702"),
+        hex!("43C90643BA2E1ABCE349008BCACA8E78702A753F8DC81BCA9539D69BDADA447552E1E0726B03B4FEC2568CA0AE0CB469"),
+    ),
+    (
+        hex!("01C9D509FEC1F323A9E8E9B51A82A3AF3180A461EA1EF699B8BBB1452B527095491DD269E9E9B64F5D56762ED98A7C53"),
+        hex!("5E100052030095798177862A400C58D39BA9763CF69E98E006578A3CC8F56859C9F905490C45F2E799F8F00001D02FF5"),
+    ),
+    (
+        hex!("0FA18C9D9066037162655A825DE14C70247C7F08393C0D9B63112206ED0AEEFA5DC8AE531F363C8AC425F5660EA7B13B"),
+        hex!("77E37B2E05D34057B8598AD06EAC656B7B8292D43FC931C236A74AB166C817D95974B54DED8D15004E30DB44ACE2B7E1"),
+    ),
+    (
+        hex!("2F8AA236DED42075C21C25F9455DAFF9657EB625ABB31D4D2D506AD225B9C249992FF468B360B5D58A761F31304BF110"),
+        hex!("3750D83035153623AD0EDCDA10DF6CEC11D960944E783481822133113D7327B92B2EFD92A491C8B80A233C0E592C74F4"),
+    ),
+];
+
+/// Scalar multiplication with the generator.
+///
+/// These test vectors use scalars not covered by [`ADD_TEST_VECTORS`],
+/// including values near the group order, generated with sagemath.
+pub const MUL_TEST_VECTORS: &[([u8; 48], [u8; 48], [u8; 48])] = &[
+    (
+        hex!("00000000000000000000000000000000000000000000000000000000000000000000000000000000018EBBB95EED0E13"),
+        hex!("8615F1AF3624B25454CF688B425E3B7D288E3D978EA75F4AE1F5312155D1CEB1E27BAD33E6E90AE66ADD2814D1DF7315"),
+        hex!("07DC9028C68569D4163ACD9F1911ADD12207B7E3F95DA347F460DDD629B09966DEFE1B779940DB93405E56FA2E17062E"),
+    ),
+    (
+        hex!("8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B31F166E6CAC0425A7CF3AB6AF6B7FC3103B883202E9046564"),
+        hex!("1D1C64F068CF45FFA2A63A81B7C13F6B8847A3E77EF14FE3DB7FCAFE0CBD10E8E826E03436D646AAEF87B2E247D4AF1E"),
+        hex!("01FB010D823EAA83B2AB83EFBB166C8CB27865DFEE67FE4F3115D4C98625E7FB9E8D6108188B996044C4FCD20ACB993E"),
+    ),
+    (
+        hex!("8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B31F166E6CAC0425A7CF3AB6AF6B7FC3103B883202E9046563"),
+        hex!("2282BC382A2F4DFCB95C3495D7B4FD590AD520B3EB6BE4D6EC2F80C4E0F70DF87C4BA74A09B553EBB427B58DF9D59FCA"),
+        hex!("7DDB764B2F8C04B4B7F49E342BF2C7874693834DD64B34E5C527FD14688E14E4F81EBCBFE4B81E48F82BD6C30599CF23"),
+    ),
+    (
+        hex!("465C8F41519C369407AEB7BF287320EF8A97B884F6AA2B598F8B3736560212D3E79D5B57B5BFE1881DC41901748232B3"),
+        hex!("2ED28DD92120BE42F45473C4D9DDF23AC9554DF3D79AFF4F173DC9AD7523DD5EBA5C0997EC8E89396243FA6B14E8407F"),
+        hex!("6921BD38EE3914599E869E0D11F8EF204E0DA86866D0EE0A6F6C7B06C7731297748FE430C113599CBA258A13C1A49FFE"),
+    ),
+    (
+        hex!("0000000000000000000000000000000041FFC1FFFFFE01FFFC0003FFFE0007C001FFF00003FFF07FFE0007C000000003"),
+        hex!("6FAACD0C60008BAB6E26560261093DB23A794D9E50BBA4D9843E386D71C80DA45B94771029641E12FDDF4348F5D78746"),
+        hex!("142E8F5F05A084E7C8CBA0A20408EFEB4A328B9BF67EE18F0DBBADD78D8A98FEAB361991E337BE447A72CC465A3E952E"),
+    ),
+    (
+        hex!("0102030405060708090A0B0C0D0E0F101112131415161718191A1B1C1D1E1F202122232425262728292A2B2C2D2E2F30"),
+        hex!("78083585D7BC642B9899C0547604DAC754DB56D69BA822C40D79BA4A33E61D6486869B012305179FD4B2CAC97E66CFFA"),
+        hex!("103CC292B2D65DD9F241D6C89E9EF7793C7ABC119C126A799CB664520BAFDBE30B57ADFD31CA35C5E6A3FDB31F99B096"),
+    ),
+];
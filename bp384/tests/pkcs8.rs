@@ -0,0 +1,104 @@
+//! PKCS#8 tests
+
+#![cfg(all(feature = "pkcs8", feature = "wip-arithmetic-do-not-use"))]
+
+use bp384::{
+    elliptic_curve::sec1::ToEncodedPoint,
+    pkcs8::{DecodePrivateKey, DecodePublicKey},
+    r1::{PublicKey, SecretKey},
+};
+use hex_literal::hex;
+
+#[cfg(feature = "pem")]
+use bp384::pkcs8::{EncodePrivateKey, EncodePublicKey};
+
+/// DER-encoded PKCS#8 private key, generated by
+/// `openssl genpkey -algorithm EC -pkeyopt ec_paramgen_curve:brainpoolP384r1`
+const PKCS8_PRIVATE_KEY_DER: &[u8; 189] = include_bytes!("examples/pkcs8-private-key.der");
+
+/// DER-encoded PKCS#8 public key for the same key pair
+const PKCS8_PUBLIC_KEY_DER: &[u8; 124] = include_bytes!("examples/pkcs8-public-key.der");
+
+/// DER-encoded PKCS#8 private key on the wrong curve (secp384r1)
+const PKCS8_PRIVATE_KEY_WRONG_CURVE_DER: &[u8; 185] =
+    include_bytes!("examples/pkcs8-private-key-wrong-curve.der");
+
+/// PEM-encoded PKCS#8 private key
+#[cfg(feature = "pem")]
+const PKCS8_PRIVATE_KEY_PEM: &str = include_str!("examples/pkcs8-private-key.pem");
+
+/// PEM-encoded PKCS#8 public key
+#[cfg(feature = "pem")]
+const PKCS8_PUBLIC_KEY_PEM: &str = include_str!("examples/pkcs8-public-key.pem");
+
+/// PEM-encoded SEC1 `ECPrivateKey`
+#[cfg(feature = "pem")]
+const SEC1_PRIVATE_KEY_PEM: &str = include_str!("examples/sec1-private-key.pem");
+
+/// The private scalar in the fixtures above
+const PRIVATE_SCALAR: [u8; 48] = hex!(
+    "233bc131ec1af35e9f3a57232878f3b822e99ccf17c7b8f2483b3c7b59232439
+     416f2c9aa38a493a79c7f4be5366ee8a"
+);
+
+/// The SEC1-encoded public point of the key pair
+const PUBLIC_POINT: [u8; 97] = hex!(
+    "043f64c7e1aec1b101852e3ccdbc6ecb00d674b264f4664c44e5d88e843d7002a0
+     b43228fdef90bf0388a3c9d48c695fb27790d1cf04ce968a6930b8ce2b5e7018
+     d709e477d50254fc883e71a4c2ea442b2c3037a27f241160ac7d87d556e160d1"
+);
+
+#[test]
+fn decode_pkcs8_private_key_from_der() {
+    let secret_key = SecretKey::from_pkcs8_der(&PKCS8_PRIVATE_KEY_DER[..]).unwrap();
+    assert_eq!(secret_key.to_bytes().as_slice(), &PRIVATE_SCALAR[..]);
+}
+
+#[test]
+fn decode_pkcs8_public_key_from_der() {
+    let public_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    assert_eq!(
+        public_key.to_encoded_point(false).as_bytes(),
+        &PUBLIC_POINT[..]
+    );
+}
+
+#[test]
+fn decode_wrong_curve_oid_rejected() {
+    assert!(SecretKey::from_pkcs8_der(&PKCS8_PRIVATE_KEY_WRONG_CURVE_DER[..]).is_err());
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_pkcs8_private_key_from_pem() {
+    let secret_key = PKCS8_PRIVATE_KEY_PEM.parse::<SecretKey>().unwrap();
+    let der_key = SecretKey::from_pkcs8_der(&PKCS8_PRIVATE_KEY_DER[..]).unwrap();
+    assert_eq!(secret_key.to_bytes(), der_key.to_bytes());
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_pkcs8_public_key_from_pem() {
+    let public_key = PKCS8_PUBLIC_KEY_PEM.parse::<PublicKey>().unwrap();
+    let der_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    assert_eq!(public_key, der_key);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn decode_sec1_private_key_from_pem() {
+    let secret_key = SecretKey::from_sec1_pem(SEC1_PRIVATE_KEY_PEM).unwrap();
+    assert_eq!(secret_key.to_bytes().as_slice(), &PRIVATE_SCALAR[..]);
+}
+
+#[test]
+#[cfg(feature = "pem")]
+fn encode_pkcs8_roundtrip() {
+    let secret_key = SecretKey::from_pkcs8_der(&PKCS8_PRIVATE_KEY_DER[..]).unwrap();
+    let reencoded = secret_key.to_pkcs8_pem(Default::default()).unwrap();
+    assert_eq!(reencoded.as_str(), PKCS8_PRIVATE_KEY_PEM);
+
+    let public_key = PublicKey::from_public_key_der(&PKCS8_PUBLIC_KEY_DER[..]).unwrap();
+    let reencoded = public_key.to_public_key_pem(Default::default()).unwrap();
+    assert_eq!(reencoded, PKCS8_PUBLIC_KEY_PEM);
+}
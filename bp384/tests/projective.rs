@@ -0,0 +1,56 @@
+//! Projective arithmetic tests.
+
+#![cfg(all(feature = "wip-arithmetic-do-not-use", feature = "test-vectors"))]
+
+use bp384::{
+    r1::{AffinePoint, ProjectivePoint},
+    test_vectors::group::{ADD_TEST_VECTORS, MUL_TEST_VECTORS},
+    Scalar,
+};
+use elliptic_curve::{
+    sec1::{self, FromEncodedPoint, ToEncodedPoint},
+    PrimeField,
+};
+use primeorder::{impl_projective_arithmetic_tests, Double};
+
+impl_projective_arithmetic_tests!(
+    AffinePoint,
+    ProjectivePoint,
+    Scalar,
+    ADD_TEST_VECTORS,
+    MUL_TEST_VECTORS
+);
+
+#[test]
+fn order_times_generator_is_identity() {
+    // (n - 1) * G + G == identity
+    let n_minus_1 = -Scalar::ONE;
+    let point = ProjectivePoint::GENERATOR * n_minus_1 + ProjectivePoint::GENERATOR;
+    assert_eq!(point, ProjectivePoint::IDENTITY);
+}
+
+#[test]
+fn scalar_mul_roundtrips_through_encoded_point() {
+    for (k, _, _) in MUL_TEST_VECTORS {
+        let k = Scalar::from_repr((*k).into()).unwrap();
+        let point = (ProjectivePoint::GENERATOR * k).to_affine();
+
+        for compress in [false, true] {
+            let encoded = point.to_encoded_point(compress);
+            let decoded = AffinePoint::from_encoded_point(&encoded).unwrap();
+            assert_eq!(decoded, point);
+        }
+    }
+}
+
+#[test]
+fn decompress_generator() {
+    // RFC 5639 brainpoolP384r1 base point from its compressed SEC1 encoding
+    let compressed = hex_literal::hex!(
+        "031d1c64f068cf45ffa2a63a81b7c13f6b8847a3e77ef14fe3db7fcafe0cbd10e8
+         e826e03436d646aaef87b2e247d4af1e"
+    );
+    let encoded = bp384::r1::EncodedPoint::from_bytes(compressed).unwrap();
+    let point = AffinePoint::from_encoded_point(&encoded).unwrap();
+    assert_eq!(point, AffinePoint::GENERATOR);
+}
@@ -0,0 +1,46 @@
+//! serde round-trip tests.
+
+#![cfg(all(feature = "serde", feature = "wip-arithmetic-do-not-use"))]
+
+use bp384::{
+    r1::{AffinePoint, ProjectivePoint, PublicKey, SecretKey},
+    Scalar,
+};
+use elliptic_curve::PrimeField;
+use hex_literal::hex;
+
+/// Private scalar for the test key pair.
+const D: [u8; 48] = hex!(
+    "233bc131ec1af35e9f3a57232878f3b822e99ccf17c7b8f2483b3c7b59232439
+     416f2c9aa38a493a79c7f4be5366ee8a"
+);
+
+#[test]
+fn scalar_roundtrip() {
+    let scalar = Scalar::from_repr(D.into()).unwrap();
+    let json = serde_json::to_string(&scalar).unwrap();
+    assert_eq!(serde_json::from_str::<Scalar>(&json).unwrap(), scalar);
+}
+
+#[test]
+fn scalar_out_of_range_rejected() {
+    // group order n is not a valid scalar encoding
+    let json = "\"8CB91E82A3386D280F5D6F7E50E641DF152F7109ED5456B31F166E6CAC0425A7CF3AB6AF6B7FC3103B883202E9046565\"";
+    assert!(serde_json::from_str::<Scalar>(json).is_err());
+}
+
+#[test]
+fn point_and_key_roundtrip() {
+    let secret_key = SecretKey::from_slice(&D).unwrap();
+    let public_key = secret_key.public_key();
+
+    let json = serde_json::to_string(&public_key).unwrap();
+    assert_eq!(
+        serde_json::from_str::<PublicKey>(&json).unwrap(),
+        public_key
+    );
+
+    let point = (ProjectivePoint::GENERATOR * Scalar::from_repr(D.into()).unwrap()).to_affine();
+    let json = serde_json::to_string(&point).unwrap();
+    assert_eq!(serde_json::from_str::<AffinePoint>(&json).unwrap(), point);
+}
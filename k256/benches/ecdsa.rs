@@ -65,5 +65,38 @@ fn bench_ecdsa(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_ecdsa);
+fn bench_batch_verify(c: &mut Criterion) {
+    use k256::ecdsa::{batch_verify, signature::hazmat::PrehashVerifier, SigningKey};
+    use rand_core::OsRng;
+
+    let mut group = c.benchmark_group("ecdsa_batch");
+
+    for n in [64usize, 256, 1024] {
+        let items: Vec<_> = (0..n)
+            .map(|i| {
+                let sk = SigningKey::random(&mut OsRng);
+                let mut prehash = [0u8; 32];
+                prehash[..8].copy_from_slice(&(i as u64).to_be_bytes());
+                let (sig, recid) = sk.sign_prehash_recoverable(&prehash).unwrap();
+                (*sk.verifying_key(), prehash, sig, recid)
+            })
+            .collect();
+
+        group.bench_function(format!("batch_verify/{n}"), |b| {
+            b.iter(|| batch_verify(black_box(&items), &mut OsRng).unwrap())
+        });
+
+        group.bench_function(format!("sequential_verify/{n}"), |b| {
+            b.iter(|| {
+                for (vk, prehash, sig, _) in &items {
+                    vk.verify_prehash(prehash, sig).unwrap();
+                }
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_ecdsa, bench_batch_verify);
 criterion_main!(benches);
@@ -34,7 +34,7 @@ fn bench_point_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let m = hex!("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
     let s = Scalar::from_repr(m.into()).unwrap();
     group.bench_function("point-scalar mul", |b| {
-        b.iter(|| &black_box(p) * &black_box(s))
+        b.iter(|| black_box(p) * black_box(s))
     });
 }
 
@@ -43,7 +43,7 @@ fn bench_point_lincomb<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let m = hex!("AA5E28D6A97A2479A65527F7290311A3624D4CC0FA1578598EE3C2613BF99522");
     let s = Scalar::from_repr(m.into()).unwrap();
     group.bench_function("lincomb via mul+add", |b| {
-        b.iter(|| &black_box(p) * &black_box(s) + &black_box(p) * &black_box(s))
+        b.iter(|| black_box(p) * black_box(s) + black_box(p) * black_box(s))
     });
     group.bench_function("lincomb()", |b| {
         b.iter(|| {
@@ -57,7 +57,7 @@ fn bench_point_mul_by_generator<'a, M: Measurement>(group: &mut BenchmarkGroup<'
     let x = test_scalar_x();
 
     group.bench_function("mul_by_generator naive", |b| {
-        b.iter(|| &black_box(p) * &black_box(x))
+        b.iter(|| black_box(p) * black_box(x))
     });
 
     group.bench_function("mul_by_generator precomputed", |b| {
@@ -76,19 +76,19 @@ fn bench_high_level(c: &mut Criterion) {
 fn bench_scalar_sub<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_scalar_x();
     let y = test_scalar_y();
-    group.bench_function("sub", |b| b.iter(|| &black_box(x) - &black_box(y)));
+    group.bench_function("sub", |b| b.iter(|| black_box(x) - black_box(y)));
 }
 
 fn bench_scalar_add<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_scalar_x();
     let y = test_scalar_y();
-    group.bench_function("add", |b| b.iter(|| &black_box(x) + &black_box(y)));
+    group.bench_function("add", |b| b.iter(|| black_box(x) + black_box(y)));
 }
 
 fn bench_scalar_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_scalar_x();
     let y = test_scalar_y();
-    group.bench_function("mul", |b| b.iter(|| &black_box(x) * &black_box(y)));
+    group.bench_function("mul", |b| b.iter(|| black_box(x) * black_box(y)));
 }
 
 fn bench_scalar_negate<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
@@ -1,16 +1,26 @@
 //! A pure-Rust implementation of group operations on secp256k1.
 
 pub(crate) mod affine;
+#[cfg(feature = "alloc")]
+pub mod basepoint_table;
 mod field;
+mod glv;
+/// hash2curve (RFC 9380) implementation details, including hazmat access
+/// to the 3-isogeny used by the `secp256k1_XMD:SHA-256_SSWU_RO_` suite.
 #[cfg(feature = "hash2curve")]
-mod hash2curve;
+pub mod hash2curve;
+#[cfg(feature = "alloc")]
+pub mod msm;
 mod mul;
 pub(crate) mod projective;
 pub(crate) mod scalar;
+#[cfg(feature = "alloc")]
+pub mod wnaf;
 
 #[cfg(test)]
 mod dev;
 
+pub use self::glv::SignedHalfScalar;
 pub use field::FieldElement;
 
 use self::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
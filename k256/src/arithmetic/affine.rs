@@ -6,6 +6,7 @@ use super::{FieldElement, ProjectivePoint, CURVE_EQUATION_B};
 use crate::{CompressedPoint, EncodedPoint, FieldBytes, PublicKey, Scalar, Secp256k1};
 use core::ops::{Mul, Neg};
 use elliptic_curve::{
+    consts::U65,
     group::{prime::PrimeCurveAffine, GroupEncoding},
     point::{AffineCoordinates, DecompactPoint, DecompressPoint},
     sec1::{self, FromEncodedPoint, ToEncodedPoint},
@@ -73,6 +74,44 @@ impl AffinePoint {
         ]),
         infinity: 0,
     };
+
+    /// Construct an affine point from big-endian hex-encoded coordinates at
+    /// compile time, for embedding pinned public keys as constants.
+    ///
+    /// Panics (at compile time when used in a `const`/`static` context) if
+    /// either string is not exactly 64 hex characters. The coordinates are
+    /// **not** checked against the curve equation; call
+    /// [`AffinePoint::is_on_curve`] from a test to validate pinned
+    /// constants:
+    ///
+    /// ```
+    /// use k256::AffinePoint;
+    ///
+    /// static VENDOR_KEY: AffinePoint = AffinePoint::from_hex_unchecked(
+    ///     "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+    ///     "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    /// );
+    ///
+    /// assert!(bool::from(VENDOR_KEY.is_on_curve()));
+    /// ```
+    pub const fn from_hex_unchecked(x_hex: &str, y_hex: &str) -> Self {
+        Self {
+            x: FieldElement::from_bytes_unchecked(&decode_hex_32(x_hex)),
+            y: FieldElement::from_bytes_unchecked(&decode_hex_32(y_hex)),
+            infinity: 0,
+        }
+    }
+
+    /// Check that this point satisfies the curve equation
+    /// `y^2 == x^3 + 7`.
+    ///
+    /// Intended for validating constants built with
+    /// [`AffinePoint::from_hex_unchecked`].
+    pub fn is_on_curve(&self) -> Choice {
+        (self.y.square().negate(1) + &self.x.square().mul(&self.x) + &CURVE_EQUATION_B)
+            .normalizes_to_zero()
+            | Choice::from(self.infinity)
+    }
 }
 
 impl AffinePoint {
@@ -361,6 +400,147 @@ impl<'de> Deserialize<'de> for AffinePoint {
     }
 }
 
+impl AffinePoint {
+    /// Serialize as the raw 64-byte `x || y` form with no SEC1 prefix, as
+    /// used by Ethereum precompiles and various HSM wire formats.
+    ///
+    /// The identity has no such encoding; it serializes as all zeroes.
+    pub fn to_untagged_bytes(&self) -> [u8; 64] {
+        let mut out = [0u8; 64];
+        if !bool::from(self.is_identity()) {
+            let encoded = self.to_encoded_point(false);
+            out.copy_from_slice(&encoded.as_bytes()[1..]);
+        }
+        out
+    }
+
+    /// Deserialize from the raw 64-byte `x || y` form, validating that the
+    /// coordinates are canonical and the point is on the curve.
+    pub fn from_untagged_bytes(bytes: &[u8; 64]) -> CtOption<Self> {
+        let mut sec1 = [0u8; 65];
+        sec1[0] = 0x04;
+        sec1[1..].copy_from_slice(bytes);
+
+        match EncodedPoint::from_bytes(sec1) {
+            Ok(encoded) => Self::from_encoded_point(&encoded),
+            Err(_) => CtOption::new(Self::IDENTITY, Choice::from(0)),
+        }
+    }
+}
+
+/// Uncompressed SEC1 encoding support for the `group` crate.
+impl elliptic_curve::group::UncompressedEncoding for AffinePoint {
+    type Uncompressed = elliptic_curve::generic_array::GenericArray<u8, U65>;
+
+    fn from_uncompressed(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        // secp256k1 is prime order, so there is no subgroup check beyond
+        // curve membership
+        Self::from_uncompressed_unchecked(bytes)
+    }
+
+    fn from_uncompressed_unchecked(bytes: &Self::Uncompressed) -> CtOption<Self> {
+        match EncodedPoint::from_bytes(bytes) {
+            Ok(encoded) if !encoded.is_compressed() => Self::from_encoded_point(&encoded),
+            _ => CtOption::new(Self::IDENTITY, Choice::from(0)),
+        }
+    }
+
+    fn to_uncompressed(&self) -> Self::Uncompressed {
+        let mut out = Self::Uncompressed::default();
+        let encoded = self.to_encoded_point(false);
+        if !bool::from(self.is_identity()) {
+            out.copy_from_slice(encoded.as_bytes());
+        }
+        out
+    }
+}
+
+/// Decode exactly 64 hex characters into 32 bytes in a `const` context,
+/// panicking on invalid input.
+const fn decode_hex_32(hex: &str) -> [u8; 32] {
+    const fn nibble(b: u8) -> u8 {
+        match b {
+            b'0'..=b'9' => b - b'0',
+            b'a'..=b'f' => b - b'a' + 10,
+            b'A'..=b'F' => b - b'A' + 10,
+            _ => panic!("invalid hex character"),
+        }
+    }
+
+    let bytes = hex.as_bytes();
+    assert!(bytes.len() == 64, "expected 64 hex characters");
+
+    let mut out = [0u8; 32];
+    let mut i = 0;
+    while i < 32 {
+        out[i] = (nibble(bytes[2 * i]) << 4) | nibble(bytes[2 * i + 1]);
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod untagged_tests {
+    use super::AffinePoint;
+    use crate::ProjectivePoint;
+    use elliptic_curve::{group::Group, rand_core::OsRng, sec1::ToEncodedPoint};
+
+    #[test]
+    fn untagged_roundtrip_matches_sec1() {
+        let point = ProjectivePoint::random(&mut OsRng).to_affine();
+        let bytes = point.to_untagged_bytes();
+
+        // agrees with the SEC1 uncompressed coordinates
+        let sec1 = point.to_encoded_point(false);
+        assert_eq!(&bytes[..], &sec1.as_bytes()[1..]);
+
+        let parsed = AffinePoint::from_untagged_bytes(&bytes).unwrap();
+        assert_eq!(parsed, point);
+    }
+
+    #[test]
+    fn off_curve_forgery_rejected() {
+        let point = ProjectivePoint::random(&mut OsRng).to_affine();
+        let mut bytes = point.to_untagged_bytes();
+        bytes[63] ^= 1;
+        assert!(bool::from(
+            AffinePoint::from_untagged_bytes(&bytes).is_none()
+        ));
+    }
+}
+
+#[cfg(test)]
+mod const_hex_tests {
+    use super::AffinePoint;
+    use crate::Scalar;
+
+    static VENDOR_KEY: AffinePoint = AffinePoint::from_hex_unchecked(
+        "79be667ef9dcbbac55a06295ce870b07029bfcdb2dce28d959f2815b16f81798",
+        "483ada7726a3c4655da4fbfc0e1108a8fd17b448a68554199c47d08ffb10d4b8",
+    );
+
+    const PINNED_SCALAR: Scalar = Scalar::from_hex_unchecked(
+        "fffffffffffffffffffffffffffffffebaaedce6af48a03bbfd25e8cd0364140",
+    );
+
+    #[test]
+    fn pinned_constants_validate() {
+        assert_eq!(VENDOR_KEY, AffinePoint::GENERATOR);
+        assert!(bool::from(VENDOR_KEY.is_on_curve()));
+        assert_eq!(PINNED_SCALAR, -Scalar::ONE);
+    }
+
+    #[test]
+    fn off_curve_constant_fails_validation() {
+        let off_curve = AffinePoint::from_hex_unchecked(
+            "0000000000000000000000000000000000000000000000000000000000000001",
+            "0000000000000000000000000000000000000000000000000000000000000001",
+        );
+        assert!(!bool::from(off_curve.is_on_curve()));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::AffinePoint;
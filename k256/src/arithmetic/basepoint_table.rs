@@ -0,0 +1,173 @@
+//! Constant-time fixed-base multiplication tables with configurable
+//! windows.
+//!
+//! [`GeneratorTable`] trades flash/RAM for speed with a window size chosen
+//! at the type level:
+//!
+//! | `W` | table entries | approximate size (projective points) |
+//! |-----|---------------|--------------------------------------|
+//! | 4   | 65 × 8        | ~61 KiB                              |
+//! | 6   | 44 × 32       | ~165 KiB                             |
+//! | 8   | 33 × 128      | ~495 KiB                             |
+//!
+//! Lookups scan every table entry with `ConditionallySelectable` moves, so
+//! multiplication is constant time in the scalar - which also means larger
+//! windows pay a proportionally larger scan cost per lookup: `W = 4`/`6`
+//! are usually the speed sweet spot, while `W = 8` mainly trades more
+//! memory for fewer point additions (useful when additions are expensive
+//! relative to memory reads). Tables are built at runtime (point addition
+//! is not const-evaluable); embed them in a `static` via
+//! `once_cell::sync::Lazy` or equivalent.
+
+use super::{ProjectivePoint, Scalar};
+use alloc::vec::Vec;
+use elliptic_curve::{
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    PrimeField,
+};
+
+/// A precomputed table of generator multiples for window size `W`
+/// (supported: 4, 6, 8).
+#[derive(Clone)]
+pub struct GeneratorTable<const W: usize> {
+    /// `windows[i][j] = (j + 1) * 2^(W*i) * G`
+    windows: Vec<Vec<ProjectivePoint>>,
+}
+
+impl<const W: usize> GeneratorTable<W> {
+    /// Number of signed digits (one extra for the final carry).
+    const DIGITS: usize = (256 + W - 1) / W + 1;
+
+    /// Entries per window: `2^(W-1)` (signed digits cover `±2^(W-1)`).
+    const ENTRIES: usize = 1 << (W - 1);
+
+    /// Build the table. `W` must be 4, 6, or 8.
+    pub fn new() -> Self {
+        assert!(W == 4 || W == 6 || W == 8, "unsupported window size");
+
+        let mut windows = Vec::with_capacity(Self::DIGITS);
+        let mut base = ProjectivePoint::GENERATOR;
+
+        for _ in 0..Self::DIGITS {
+            let mut entries = Vec::with_capacity(Self::ENTRIES);
+            entries.push(base);
+            for j in 1..Self::ENTRIES {
+                let prev = entries[j - 1];
+                entries.push(prev + base);
+            }
+            windows.push(entries);
+
+            for _ in 0..W {
+                base = base.double();
+            }
+        }
+
+        Self { windows }
+    }
+
+    /// Compute `k * G` in constant time.
+    pub fn mul_by_generator(&self, k: &Scalar) -> ProjectivePoint {
+        let digits = signed_digits::<W>(k);
+
+        let mut acc = ProjectivePoint::IDENTITY;
+        for (window, &digit) in self.windows.iter().zip(digits.iter()) {
+            acc += select_signed(window, digit);
+        }
+        acc
+    }
+}
+
+impl<const W: usize> Default for GeneratorTable<W> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Constant-time lookup of `digit * base` from the precomputed window,
+/// where `-2^(W-1) <= digit <= 2^(W-1)`.
+fn select_signed(entries: &[ProjectivePoint], digit: i16) -> ProjectivePoint {
+    let sign_mask = digit >> 15;
+    let magnitude = ((digit + sign_mask) ^ sign_mask) as u16;
+
+    let mut result = ProjectivePoint::IDENTITY;
+    for (j, entry) in entries.iter().enumerate() {
+        let matches = magnitude.ct_eq(&(j as u16 + 1));
+        result.conditional_assign(entry, matches);
+    }
+
+    let negate = Choice::from((sign_mask & 1) as u8);
+    result.conditional_assign(&-result, negate);
+    result
+}
+
+/// Recentered signed base-`2^W` digits of the scalar, little-endian, with
+/// one carry digit: `k == sum(digit[i] * 2^(W*i))`,
+/// `-2^(W-1) <= digit[i] <= 2^(W-1)`.
+fn signed_digits<const W: usize>(k: &Scalar) -> Vec<i16> {
+    let bytes = k.to_repr();
+    let digits_len = (256 + W - 1) / W + 1;
+
+    let bit = |pos: usize| -> u16 {
+        if pos >= 256 {
+            0
+        } else {
+            ((bytes[31 - pos / 8] >> (pos % 8)) & 1) as u16
+        }
+    };
+
+    let mut digits = Vec::with_capacity(digits_len);
+    let mut carry = 0i16;
+    let half = 1i16 << (W - 1);
+    let full = 1i16 << W;
+
+    for i in 0..digits_len {
+        let mut raw = carry;
+        for b in 0..W {
+            raw += (bit(i * W + b) as i16) << b;
+        }
+
+        // recenter to (-2^(W-1), 2^(W-1)], branchless so the recoding is
+        // constant time in the scalar
+        let c = (raw + half - 1) >> W;
+        digits.push(raw - (c * full));
+        carry = c;
+    }
+
+    digits
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::GeneratorTable;
+    use crate::{ProjectivePoint, Scalar};
+    use elliptic_curve::{rand_core::OsRng, Field};
+
+    fn check<const W: usize>(table: &GeneratorTable<W>, k: &Scalar) {
+        assert_eq!(
+            table.mul_by_generator(k),
+            ProjectivePoint::GENERATOR * k,
+            "window {W} mismatch"
+        );
+    }
+
+    #[test]
+    fn all_windows_agree_with_generic_mul() {
+        let t4 = GeneratorTable::<4>::new();
+        let t6 = GeneratorTable::<6>::new();
+        let t8 = GeneratorTable::<8>::new();
+
+        for k in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+            check(&t4, &k);
+            check(&t6, &k);
+            check(&t8, &k);
+        }
+
+        for _ in 0..1000 {
+            let k = Scalar::random(&mut OsRng);
+            check(&t4, &k);
+            check(&t6, &k);
+            check(&t8, &k);
+        }
+    }
+}
@@ -132,6 +132,52 @@ impl FieldElement {
         self.0.normalizes_to_zero()
     }
 
+    /// Assert that the magnitude of this field element is at most
+    /// `magnitude`.
+    ///
+    /// k256 uses lazy reduction: additions increase an element's
+    /// *magnitude* (an upper bound on how far it may exceed the modulus)
+    /// without reducing it, and multiplication/squaring require both
+    /// inputs to have magnitude at most 8. Summing `n` magnitude-1
+    /// elements yields magnitude `n`, so long chains of additions must be
+    /// punctuated with [`FieldElement::normalize_weak`] (magnitude back to
+    /// 1) or [`FieldElement::normalize`] (magnitude 1 and fully reduced):
+    ///
+    /// ```
+    /// # #[cfg(feature = "expose-field")]
+    /// # {
+    /// use k256::FieldElement;
+    ///
+    /// let one = FieldElement::ONE;
+    ///
+    /// // sum eight magnitude-1 elements: magnitude grows to 8
+    /// let mut sum = one;
+    /// for _ in 0..7 {
+    ///     sum = sum + &one;
+    /// }
+    /// sum.assert_magnitude(8);
+    ///
+    /// // normalize before further multiplications
+    /// let sum = sum.normalize_weak();
+    /// sum.assert_magnitude(1);
+    /// assert_eq!(sum.normalize(), FieldElement::from(8u64));
+    /// # }
+    /// ```
+    ///
+    /// In debug builds this checks against the actual tracked magnitude;
+    /// release builds do not track magnitudes and this is a no-op.
+    pub fn assert_magnitude(&self, magnitude: u32) {
+        #[cfg(debug_assertions)]
+        debug_assert!(
+            self.0.magnitude() <= magnitude,
+            "field element magnitude {} exceeds asserted bound {}",
+            self.0.magnitude(),
+            magnitude
+        );
+        #[cfg(not(debug_assertions))]
+        let _ = magnitude;
+    }
+
     /// Multiplies by a single-limb integer.
     /// Multiplies the magnitude by the same value.
     pub fn mul_single(&self, rhs: u32) -> Self {
@@ -240,6 +286,7 @@ impl FieldElement {
         CtOption::new(res, is_root)
     }
 
+    /// Returns the field modulus as a `BigUint`.
     #[cfg(test)]
     pub fn modulus_as_biguint() -> BigUint {
         Self::ONE.negate(1).to_biguint().unwrap() + 1.to_biguint().unwrap()
@@ -269,12 +316,10 @@ impl Field for FieldElement {
         }
     }
 
-    #[must_use]
     fn square(&self) -> Self {
         self.square()
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         self.double()
     }
@@ -485,7 +530,7 @@ impl Neg for &FieldElement {
 
 impl Sum for FieldElement {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Add::add).unwrap_or(Self::ZERO)
+        iter.reduce(Add::add).unwrap_or(Self::ZERO)
     }
 }
 
@@ -498,7 +543,7 @@ impl<'a> Sum<&'a FieldElement> for FieldElement {
 
 impl Product for FieldElement {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Mul::mul).unwrap_or(Self::ONE)
+        iter.reduce(Mul::mul).unwrap_or(Self::ONE)
     }
 }
 
@@ -560,7 +605,7 @@ mod tests {
         // ROOT_OF_UNITY^{2^s} mod m == 1
         assert_eq!(
             FieldElement::ROOT_OF_UNITY
-                .pow_vartime(&[1u64 << FieldElement::S, 0, 0, 0])
+                .pow_vartime([1u64 << FieldElement::S, 0, 0, 0])
                 .normalize(),
             FieldElement::ONE
         );
@@ -568,7 +613,7 @@ mod tests {
         // MULTIPLICATIVE_GENERATOR^{t} mod m == ROOT_OF_UNITY
         assert_eq!(
             FieldElement::MULTIPLICATIVE_GENERATOR
-                .pow_vartime(&T)
+                .pow_vartime(T)
                 .normalize(),
             FieldElement::ROOT_OF_UNITY
         )
@@ -586,7 +631,7 @@ mod tests {
     fn delta_constant() {
         // DELTA^{t} mod m == 1
         assert_eq!(
-            FieldElement::DELTA.pow_vartime(&T).normalize(),
+            FieldElement::DELTA.pow_vartime(T).normalize(),
             FieldElement::ONE
         );
     }
@@ -22,6 +22,11 @@ pub struct FieldElementImpl {
 }
 
 impl FieldElementImpl {
+    /// The tracked magnitude of this field element.
+    pub const fn magnitude(&self) -> u32 {
+        self.magnitude
+    }
+
     /// Zero element.
     pub const ZERO: Self = Self {
         value: FieldElementUnsafeImpl::ZERO,
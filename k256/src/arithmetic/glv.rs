@@ -0,0 +1,120 @@
+//! Public API for the secp256k1 GLV endomorphism.
+//!
+//! secp256k1 has an efficiently computable endomorphism
+//! `lambda * (x, y) = (beta * x, y)` where `lambda^3 = 1 mod n` and
+//! `beta^3 = 1 mod p`:
+//!
+//! - `lambda = 0x5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72`
+//! - `beta   = 0x7ae96a2b657c07106e64479eac3434e99cf0497512f58995c1396c28719501ee`
+//!
+//! Splitting a scalar `k` into half-width components `k = k1 + k2*lambda`
+//! lets a multiscalar multiplication trade one 256-bit scalar for two
+//! 128-bit ones, roughly halving the number of doublings.
+
+use super::mul::decompose_scalar;
+use crate::{AffinePoint, Scalar};
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeLess};
+
+/// One component of a GLV-decomposed scalar: a magnitude of at most 128
+/// bits together with a sign.
+///
+/// The represented value is `magnitude` when `is_negative` is false and
+/// `-magnitude (mod n)` otherwise.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct SignedHalfScalar {
+    /// Absolute value, strictly below 2^128.
+    pub magnitude: Scalar,
+
+    /// Whether the represented value is the negation of `magnitude`.
+    pub is_negative: bool,
+}
+
+impl SignedHalfScalar {
+    /// The scalar value this represents, i.e. `±magnitude mod n`.
+    pub fn to_scalar(self) -> Scalar {
+        Scalar::conditional_select(
+            &self.magnitude,
+            &-self.magnitude,
+            Choice::from(u8::from(self.is_negative)),
+        )
+    }
+
+    /// Normalize a full-width scalar known to be `±small` into
+    /// magnitude/sign form.
+    fn new(scalar: Scalar) -> Self {
+        // 2^128
+        let two_pow_128 = elliptic_curve::bigint::U256::ONE.shl_vartime(128);
+        let is_negative = !scalar.0.ct_lt(&two_pow_128);
+
+        Self {
+            magnitude: Scalar::conditional_select(&scalar, &-scalar, is_negative),
+            is_negative: is_negative.into(),
+        }
+    }
+}
+
+impl Scalar {
+    /// Decompose this scalar into `(k1, k2)` such that
+    /// `self = k1 + k2 * lambda (mod n)` with `|k1|, |k2| < 2^128`.
+    ///
+    /// Together with [`AffinePoint::endomorphism`] this enables
+    /// endomorphism-accelerated multiscalar multiplication:
+    /// `k * P == k1 * P + k2 * endomorphism(P)`.
+    pub fn split_glv(&self) -> (SignedHalfScalar, SignedHalfScalar) {
+        let (r1, r2) = decompose_scalar(self);
+        (SignedHalfScalar::new(r1), SignedHalfScalar::new(r2))
+    }
+}
+
+impl AffinePoint {
+    /// Apply the secp256k1 endomorphism: `lambda * (x, y) = (beta * x, y)`.
+    pub fn endomorphism(&self) -> Self {
+        crate::ProjectivePoint::from(*self)
+            .endomorphism()
+            .to_affine()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AffinePoint, ProjectivePoint, Scalar};
+    use elliptic_curve::{rand_core::OsRng, Field, PrimeField};
+    use hex_literal::hex;
+
+    fn check(k: Scalar) {
+        let (k1, k2) = k.split_glv();
+
+        // magnitudes fit in 128 bits
+        assert!(k1.magnitude.to_bytes()[..16].iter().all(|b| *b == 0));
+        assert!(k2.magnitude.to_bytes()[..16].iter().all(|b| *b == 0));
+
+        // k == k1 + k2 * lambda
+        let lambda = Scalar::from_repr(
+            hex!("5363ad4cc05c30e0a5261c028812645a122e22ea20816678df02967c1b23bd72").into(),
+        )
+        .unwrap();
+        assert_eq!(
+            k1.to_scalar() + k2.to_scalar() * lambda,
+            k,
+            "decomposition mismatch"
+        );
+
+        // k * P == k1 * P + k2 * endo(P)
+        let p = ProjectivePoint::GENERATOR * Scalar::random(&mut OsRng);
+        let endo = AffinePoint::endomorphism(&p.to_affine());
+        let recombined = p * k1.to_scalar() + ProjectivePoint::from(endo) * k2.to_scalar();
+        assert_eq!(p * k, recombined);
+    }
+
+    #[test]
+    fn split_glv_roundtrips() {
+        check(Scalar::ZERO);
+        check(Scalar::ONE);
+        check(-Scalar::ONE); // n - 1
+        check(-Scalar::from(2u64)); // n - 2
+
+        for _ in 0..16 {
+            check(Scalar::random(&mut OsRng));
+        }
+    }
+}
@@ -3,9 +3,10 @@ use elliptic_curve::consts::{U4, U48};
 use elliptic_curve::generic_array::GenericArray;
 use elliptic_curve::group::cofactor::CofactorGroup;
 use elliptic_curve::hash2curve::{
-    FromOkm, GroupDigest, Isogeny, IsogenyCoefficients, MapToCurve, OsswuMap, OsswuMapParams, Sgn0,
+    hash_to_field, ExpandMsg, FromOkm, GroupDigest, Isogeny, IsogenyCoefficients, MapToCurve,
+    OsswuMap, OsswuMapParams, Sgn0,
 };
-use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+use elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
 use elliptic_curve::Field;
 
 use crate::{AffinePoint, ProjectivePoint, Scalar, Secp256k1};
@@ -14,6 +15,53 @@ use super::FieldElement;
 
 impl GroupDigest for Secp256k1 {
     type FieldElement = FieldElement;
+
+    fn hash_from_bytes<'a, X: ExpandMsg<'a>>(
+        msgs: &[&[u8]],
+        dsts: &'a [&'a [u8]],
+    ) -> elliptic_curve::Result<ProjectivePoint> {
+        validate_dst(dsts)?;
+
+        let mut u = [FieldElement::default(), FieldElement::default()];
+        hash_to_field::<X, _>(msgs, dsts, &mut u)?;
+        let q0 = u[0].map_to_curve();
+        let q1 = u[1].map_to_curve();
+        Ok(CofactorGroup::clear_cofactor(&q0) + CofactorGroup::clear_cofactor(&q1))
+    }
+
+    fn encode_from_bytes<'a, X: ExpandMsg<'a>>(
+        msgs: &[&[u8]],
+        dsts: &'a [&'a [u8]],
+    ) -> elliptic_curve::Result<ProjectivePoint> {
+        validate_dst(dsts)?;
+
+        let mut u = [FieldElement::default()];
+        hash_to_field::<X, _>(msgs, dsts, &mut u)?;
+        Ok(CofactorGroup::clear_cofactor(&u[0].map_to_curve()))
+    }
+
+    fn hash_to_scalar<'a, X: ExpandMsg<'a>>(
+        msgs: &[&[u8]],
+        dsts: &'a [&'a [u8]],
+    ) -> elliptic_curve::Result<Scalar> {
+        validate_dst(dsts)?;
+
+        let mut u = [Scalar::default()];
+        hash_to_field::<X, _>(msgs, dsts, &mut u)?;
+        Ok(u[0])
+    }
+}
+
+/// RFC 9380 requires a non-empty domain separation tag; an empty DST
+/// destroys domain separation entirely, so reject it with an error rather
+/// than producing output. (DSTs longer than 255 bytes are handled by the
+/// `H2C-OVERSIZE-DST-` hashing fallback in the expander itself.)
+fn validate_dst(dsts: &[&[u8]]) -> elliptic_curve::Result<()> {
+    if dsts.iter().map(|part| part.len()).sum::<usize>() == 0 {
+        return Err(elliptic_curve::Error);
+    }
+
+    Ok(())
 }
 
 impl FromOkm for FieldElement {
@@ -144,6 +192,37 @@ impl MapToCurve for FieldElement {
     }
 }
 
+/// Map a field element to a point on the 3-isogenous curve `E'`
+/// (`y^2 = x^3 + A'x + B'` with the constants from
+/// `<FieldElement as OsswuMap>::PARAMS`) using the simplified SWU map.
+///
+/// # ⚠️ Hazmat
+///
+/// This is an intermediate of the `secp256k1_XMD:SHA-256_SSWU_RO_` suite,
+/// exposed for optimizations like hashing with precomputed `u` values and
+/// for testing against the RFC 9380 intermediate vectors. The result is
+/// *not* a secp256k1 point until [`iso_map`] is applied.
+pub fn map_to_curve_simple_swu(u: &FieldElement) -> (FieldElement, FieldElement) {
+    u.osswu()
+}
+
+/// Apply the 3-isogeny from `E'` to secp256k1, completing the map started
+/// by [`map_to_curve_simple_swu`].
+///
+/// # ⚠️ Hazmat
+///
+/// The input must be a point produced by the simplified SWU map on `E'`;
+/// feeding arbitrary coordinates produces garbage.
+pub fn iso_map(x: FieldElement, y: FieldElement) -> AffinePoint {
+    let (qx, qy) = FieldElement::isogeny(x, y);
+
+    AffinePoint {
+        x: qx,
+        y: qy,
+        infinity: 0,
+    }
+}
+
 impl FromOkm for Scalar {
     type Length = U48;
 
@@ -256,22 +335,6 @@ impl Isogeny for FieldElement {
     };
 }
 
-impl CofactorGroup for ProjectivePoint {
-    type Subgroup = ProjectivePoint;
-
-    fn clear_cofactor(&self) -> Self::Subgroup {
-        *self
-    }
-
-    fn into_subgroup(self) -> CtOption<Self::Subgroup> {
-        CtOption::new(self, 1.into())
-    }
-
-    fn is_torsion_free(&self) -> Choice {
-        1.into()
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use crate::{FieldElement, Scalar, Secp256k1, U256};
@@ -283,7 +346,6 @@ mod tests {
         hash2curve::{FromOkm, GroupDigest, MapToCurve},
         Curve,
     };
-    use hex_literal::hex;
     use proptest::{num::u64::ANY, prelude::ProptestConfig, proptest};
 
     #[test]
@@ -400,6 +462,57 @@ mod tests {
         }
     }
 
+    #[test]
+    fn public_swu_and_iso_map_compose() {
+        use super::{iso_map, map_to_curve_simple_swu};
+        use elliptic_curve::hash2curve::MapToCurve;
+        use hex_literal::hex;
+
+        // u value from the RFC 9380 J.8.1 vector for msg = "abc"
+        let u0 = FieldElement::from_bytes(
+            &hex!("128aab5d3679a1f7601e3bdf94ced1f43e491f544767e18a4873f397b08a2b61").into(),
+        )
+        .unwrap();
+
+        // the composed public entry points must agree with map_to_curve
+        let (ex, ey) = map_to_curve_simple_swu(&u0);
+        let q = iso_map(ex, ey);
+        let expected = MapToCurve::map_to_curve(&u0);
+        assert_eq!(crate::ProjectivePoint::from(q), expected);
+
+        // and the J.8.1 intermediate q0 for "abc"
+        assert_eq!(
+            q.x.normalize().to_bytes().as_slice(),
+            &hex!("07dd9432d426845fb19857d1b3a91722436604ccbbbadad8523b8fc38a5322d7")
+        );
+    }
+
+    #[test]
+    fn dst_validation() {
+        use elliptic_curve::hash2curve::ExpandMsgXmd;
+        use sha2::Sha256;
+
+        // empty DSTs (either form) error instead of silently hashing
+        assert!(Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"msg"], &[]).is_err());
+        assert!(Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"msg"], &[b""]).is_err());
+        assert!(Secp256k1::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[b"msg"], &[b""]).is_err());
+        assert!(Secp256k1::encode_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"msg"], &[b""]).is_err());
+
+        // oversize (> 255 byte) DSTs go through the H2C-OVERSIZE-DST path
+        // and still produce output, distinct from a truncated variant
+        let oversize = [0x44u8; 300];
+        let p1 =
+            Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"msg"], &[&oversize]).unwrap();
+        let p2 = Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[b"msg"], &[&oversize[..255]])
+            .unwrap();
+        assert_ne!(p1, p2);
+
+        // hash_to_scalar is public and deterministic
+        let s1 = Secp256k1::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[b"msg"], &[b"dst"]).unwrap();
+        let s2 = Secp256k1::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[b"msg"], &[b"dst"]).unwrap();
+        assert_eq!(s1, s2);
+    }
+
     #[test]
     fn from_okm_fuzz() {
         let mut wide_order = GenericArray::default();
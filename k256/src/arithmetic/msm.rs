@@ -0,0 +1,186 @@
+//! Variable-time multiscalar multiplication (Pippenger's bucket method).
+
+use super::{glv::SignedHalfScalar, ProjectivePoint, Scalar};
+use alloc::vec;
+use alloc::vec::Vec;
+
+/// Compute `sum(scalars[i] * points[i])` using Pippenger's bucket method
+/// with GLV endomorphism splitting.
+///
+/// Each 256-bit term is decomposed into two signed 128-bit half-terms via
+/// the secp256k1 endomorphism, then all half-terms are processed with a
+/// window size chosen from the problem size.
+///
+/// ⚠️ This operation is **variable time** and must only be used with public
+/// scalars (e.g. signature verification or commitment aggregation).
+///
+/// # Panics
+///
+/// Panics if `points` and `scalars` have different lengths.
+pub fn multiscalar_mul(points: &[ProjectivePoint], scalars: &[Scalar]) -> ProjectivePoint {
+    assert_eq!(
+        points.len(),
+        scalars.len(),
+        "points/scalars length mismatch"
+    );
+
+    if points.is_empty() {
+        return ProjectivePoint::IDENTITY;
+    }
+
+    // Split every term in two half-width terms via the endomorphism,
+    // folding the sign into the point.
+    let mut half_points = Vec::with_capacity(2 * points.len());
+    let mut half_scalars = Vec::with_capacity(2 * points.len());
+
+    for (point, scalar) in points.iter().zip(scalars) {
+        let (k1, k2) = scalar.split_glv();
+        let endo = point.endomorphism();
+
+        half_points.push(signed_point(point, &k1));
+        half_scalars.push(half_limbs(&k1));
+        half_points.push(signed_point(&endo, &k2));
+        half_scalars.push(half_limbs(&k2));
+    }
+
+    pippenger(&half_points, &half_scalars, 128)
+}
+
+/// Negate `point` when the half-scalar is negative.
+fn signed_point(point: &ProjectivePoint, half: &SignedHalfScalar) -> ProjectivePoint {
+    if half.is_negative {
+        -*point
+    } else {
+        *point
+    }
+}
+
+/// Extract the (little-endian) 128-bit magnitude as two u64 limbs.
+fn half_limbs(half: &SignedHalfScalar) -> [u64; 2] {
+    let bytes = half.magnitude.to_bytes();
+    let mut lo = [0u8; 8];
+    let mut hi = [0u8; 8];
+    lo.copy_from_slice(&bytes[24..]);
+    hi.copy_from_slice(&bytes[16..24]);
+    [u64::from_be_bytes(lo), u64::from_be_bytes(hi)]
+}
+
+/// Window size heuristic for Pippenger's algorithm.
+fn window_size(n: usize) -> usize {
+    match n {
+        0..=11 => 3,
+        12..=47 => 4,
+        48..=139 => 5,
+        140..=409 => 6,
+        410..=1139 => 7,
+        1140..=2986 => 8,
+        2987..=8191 => 9,
+        _ => 11,
+    }
+}
+
+/// Pippenger's bucket method over little-endian limb scalars of the given
+/// bit length.
+fn pippenger(points: &[ProjectivePoint], scalars: &[[u64; 2]], bits: usize) -> ProjectivePoint {
+    let w = window_size(points.len());
+    let windows = (bits + w - 1) / w;
+    let bucket_count = (1usize << w) - 1;
+
+    let mut result = ProjectivePoint::IDENTITY;
+
+    for window in (0..windows).rev() {
+        for _ in 0..w {
+            result = result.double();
+        }
+
+        let mut buckets = vec![ProjectivePoint::IDENTITY; bucket_count];
+        let bit_offset = window * w;
+
+        for (point, scalar) in points.iter().zip(scalars) {
+            let digit = extract_bits(scalar, bit_offset, w);
+            if digit != 0 {
+                buckets[digit - 1] += point;
+            }
+        }
+
+        // running-sum accumulation: sum(i * bucket[i])
+        let mut running = ProjectivePoint::IDENTITY;
+        for bucket in buckets.iter().rev() {
+            running += bucket;
+            result += running;
+        }
+    }
+
+    result
+}
+
+/// Extract `width` bits starting at `offset` from little-endian limbs.
+fn extract_bits(limbs: &[u64; 2], offset: usize, width: usize) -> usize {
+    let limb = offset / 64;
+    let shift = offset % 64;
+
+    if limb >= 2 {
+        return 0;
+    }
+
+    let mut value = limbs[limb] >> shift;
+    if shift + width > 64 && limb + 1 < 2 {
+        value |= limbs[limb + 1] << (64 - shift);
+    }
+
+    (value as usize) & ((1 << width) - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::multiscalar_mul;
+    use crate::{ProjectivePoint, Scalar};
+    use alloc::vec::Vec;
+    use elliptic_curve::{rand_core::OsRng, Field, Group};
+
+    fn naive(points: &[ProjectivePoint], scalars: &[Scalar]) -> ProjectivePoint {
+        points
+            .iter()
+            .zip(scalars)
+            .fold(ProjectivePoint::IDENTITY, |acc, (p, k)| acc + *p * k)
+    }
+
+    #[test]
+    fn agrees_with_naive_summation() {
+        for n in [1usize, 2, 63, 64, 1000] {
+            let points: Vec<_> = (0..n)
+                .map(|_| ProjectivePoint::random(&mut OsRng))
+                .collect();
+            let scalars: Vec<_> = (0..n).map(|_| Scalar::random(&mut OsRng)).collect();
+
+            assert_eq!(
+                multiscalar_mul(&points, &scalars),
+                naive(&points, &scalars),
+                "mismatch at size {n}"
+            );
+        }
+    }
+
+    #[test]
+    fn edge_scalars() {
+        let points = [
+            ProjectivePoint::GENERATOR,
+            ProjectivePoint::random(&mut OsRng),
+            ProjectivePoint::random(&mut OsRng),
+            ProjectivePoint::IDENTITY,
+        ];
+        let scalars = [
+            Scalar::ZERO,
+            Scalar::ONE,
+            -Scalar::ONE,
+            Scalar::random(&mut OsRng),
+        ];
+
+        assert_eq!(multiscalar_mul(&points, &scalars), naive(&points, &scalars));
+    }
+
+    #[test]
+    fn empty_is_identity() {
+        assert_eq!(multiscalar_mul(&[], &[]), ProjectivePoint::IDENTITY);
+    }
+}
@@ -227,7 +227,7 @@ const G2: Scalar = Scalar::from_bytes_unchecked(&[
  */
 
 /// Find r1 and r2 given k, such that r1 + r2 * lambda == k mod n.
-fn decompose_scalar(k: &Scalar) -> (Scalar, Scalar) {
+pub(crate) fn decompose_scalar(k: &Scalar) -> (Scalar, Scalar) {
     // these _vartime calls are constant time since the shift amount is constant
     let c1 = WideScalar::mul_shift_vartime(k, &G1, 384) * MINUS_B1;
     let c2 = WideScalar::mul_shift_vartime(k, &G2, 384) * MINUS_B2;
@@ -467,7 +467,7 @@ mod tests {
         let k = Scalar::random(&mut OsRng);
         let l = Scalar::random(&mut OsRng);
 
-        let reference = &x * &k + &y * &l;
+        let reference = x * k + y * l;
         let test = ProjectivePoint::lincomb(&x, &k, &y, &l);
         assert_eq!(reference, test);
     }
@@ -475,7 +475,7 @@ mod tests {
     #[test]
     fn test_mul_by_generator() {
         let k = Scalar::random(&mut OsRng);
-        let reference = &ProjectivePoint::GENERATOR * &k;
+        let reference = ProjectivePoint::GENERATOR * k;
         let test = ProjectivePoint::mul_by_generator(&k);
         assert_eq!(reference, test);
     }
@@ -488,7 +488,7 @@ mod tests {
         let k = Scalar::random(&mut OsRng);
         let l = Scalar::random(&mut OsRng);
 
-        let reference = &x * &k + &y * &l;
+        let reference = x * k + y * l;
         let points_and_scalars = vec![(x, k), (y, l)];
 
         let test = ProjectivePoint::lincomb_ext(points_and_scalars.as_slice());
@@ -399,6 +399,31 @@ impl PartialEq<ProjectivePoint> for AffinePoint {
 
 impl Eq for ProjectivePoint {}
 
+/// secp256k1 has cofactor 1, so the cofactor-clearing operations are
+/// no-ops and every point is torsion free.
+impl elliptic_curve::group::cofactor::CofactorGroup for ProjectivePoint {
+    type Subgroup = ProjectivePoint;
+
+    fn clear_cofactor(&self) -> Self::Subgroup {
+        *self
+    }
+
+    fn into_subgroup(self) -> CtOption<Self> {
+        CtOption::new(self, 1.into())
+    }
+
+    fn is_torsion_free(&self) -> Choice {
+        1.into()
+    }
+}
+
+// NOTE: `group::WnafGroup` is deliberately not implemented. The `group`
+// crate's `Wnaf` machinery interprets `Scalar::to_repr()` as little-endian
+// limbs, while k256's repr is the big-endian SEC1 form, so a conforming
+// impl would make `Wnaf` silently compute incorrect results. Use
+// `k256::WnafTable` (or `ProjectivePoint::mul_vartime`) for wNAF
+// multiplication instead.
+
 impl Group for ProjectivePoint {
     type Scalar = Scalar;
 
@@ -418,7 +443,6 @@ impl Group for ProjectivePoint {
         self.z.normalizes_to_zero()
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         Self::double(self)
     }
@@ -635,7 +659,7 @@ impl Neg for ProjectivePoint {
     }
 }
 
-impl<'a> Neg for &'a ProjectivePoint {
+impl Neg for &ProjectivePoint {
     type Output = ProjectivePoint;
 
     fn neg(self) -> ProjectivePoint {
@@ -671,6 +695,41 @@ impl TryFrom<&ProjectivePoint> for PublicKey {
     }
 }
 
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod group_traits_tests {
+    use super::ProjectivePoint;
+    use elliptic_curve::{group::Group, rand_core::OsRng};
+
+    #[test]
+    fn uncompressed_encoding_roundtrip() {
+        use crate::AffinePoint;
+        use elliptic_curve::group::UncompressedEncoding;
+
+        let point = ProjectivePoint::random(&mut OsRng).to_affine();
+        let bytes = point.to_uncompressed();
+        assert_eq!(bytes.len(), 65);
+        assert_eq!(bytes[0], 0x04);
+        assert_eq!(AffinePoint::from_uncompressed(&bytes).unwrap(), point);
+
+        // compressed input via the uncompressed entry point is rejected
+        let mut compressed = bytes;
+        compressed[0] = 0x02;
+        assert!(bool::from(
+            AffinePoint::from_uncompressed(&compressed).is_none()
+        ));
+    }
+
+    #[test]
+    fn cofactor_group_is_unconditional() {
+        use elliptic_curve::group::cofactor::CofactorGroup;
+
+        let point = ProjectivePoint::random(&mut OsRng);
+        assert_eq!(point.clear_cofactor(), point);
+        assert!(bool::from(point.is_torsion_free()));
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::{AffinePoint, ProjectivePoint};
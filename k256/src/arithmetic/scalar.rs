@@ -13,7 +13,7 @@ use core::{
 };
 use elliptic_curve::{
     bigint::{prelude::*, Limb, Word, U256, U512},
-    ff::{self, Field, PrimeField},
+    ff::{self, Field, FromUniformBytes, PrimeField},
     ops::{Invert, Reduce, ReduceNonZero},
     rand_core::{CryptoRngCore, RngCore},
     scalar::{FromUintUnchecked, IsHigh},
@@ -175,6 +175,55 @@ impl Scalar {
         CtOption::new(res, !self.is_zero())
     }
 
+    /// Parse a scalar from big-endian hex at compile time, for embedding
+    /// pinned scalars as constants.
+    ///
+    /// Panics (at compile time in `const`/`static` contexts) if the string
+    /// is not 64 hex characters or encodes a value >= the group order.
+    pub const fn from_hex_unchecked(hex: &str) -> Self {
+        let uint = U256::from_be_hex(hex);
+
+        // constant context range check against the group order
+        let scalar_words = uint.as_words();
+        let order_words = Secp256k1::ORDER.as_words();
+        let mut borrow = 0u128;
+        let mut i = 0;
+        while i < scalar_words.len() {
+            let diff = (scalar_words[i] as u128)
+                .wrapping_sub(order_words[i] as u128)
+                .wrapping_sub(borrow);
+            borrow = (diff >> 127) & 1;
+            i += 1;
+        }
+        assert!(borrow == 1, "scalar must be less than the group order");
+
+        Self(uint)
+    }
+
+    /// Reduce 64 uniformly random bytes (interpreted as a big-endian
+    /// 512-bit integer) modulo the group order, producing an unbiased
+    /// scalar.
+    ///
+    /// This performs a full 512-to-256-bit reduction (the same wide
+    /// reduction as [`Reduce<U512>`]), not two chained narrow reductions.
+    pub fn from_bytes_mod_order_wide(bytes: &[u8; 64]) -> Self {
+        <Self as Reduce<U512>>::reduce(U512::from_be_slice(bytes))
+    }
+
+    /// Fast variable-time inversion using Stein's algorithm.
+    ///
+    /// Used by signature verification, which operates on public values; see
+    /// [`Invert::invert_vartime`] for the trait form.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with (unblinded) secret scalars, as
+    /// its variable-time operation can potentially leak secrets through
+    /// sidechannels. Signing uses the constant-time [`Scalar::invert`].
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        <Self as Invert>::invert_vartime(self)
+    }
+
     /// Returns the scalar modulus as a `BigUint` object.
     #[cfg(test)]
     pub fn modulus_as_biguint() -> BigUint {
@@ -237,12 +286,10 @@ impl Field for Scalar {
         Self::generate_vartime(&mut rng)
     }
 
-    #[must_use]
     fn square(&self) -> Self {
         Scalar::square(self)
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         self.add(self)
     }
@@ -674,6 +721,12 @@ impl Reduce<U256> for Scalar {
     }
 }
 
+impl FromUniformBytes<64> for Scalar {
+    fn from_uniform_bytes(bytes: &[u8; 64]) -> Self {
+        Self::from_bytes_mod_order_wide(bytes)
+    }
+}
+
 impl Reduce<U512> for Scalar {
     type Bytes = WideBytes;
 
@@ -713,7 +766,7 @@ impl ReduceNonZero<U512> for Scalar {
 
 impl Sum for Scalar {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Add::add).unwrap_or(Self::ZERO)
+        iter.reduce(Add::add).unwrap_or(Self::ZERO)
     }
 }
 
@@ -725,7 +778,7 @@ impl<'a> Sum<&'a Scalar> for Scalar {
 
 impl Product for Scalar {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Mul::mul).unwrap_or(Self::ONE)
+        iter.reduce(Mul::mul).unwrap_or(Self::ONE)
     }
 }
 
@@ -797,7 +850,7 @@ mod tests {
         bigint::{ArrayEncoding, U256, U512},
         ff::{Field, PrimeField},
         generic_array::GenericArray,
-        ops::{Invert, Reduce},
+        ops::Reduce,
         scalar::IsHigh,
     };
     use num_bigint::{BigUint, ToBigUint};
@@ -846,13 +899,13 @@ mod tests {
     fn root_of_unity_constant() {
         // ROOT_OF_UNITY^{2^s} mod m == 1
         assert_eq!(
-            Scalar::ROOT_OF_UNITY.pow_vartime(&[1u64 << Scalar::S, 0, 0, 0]),
+            Scalar::ROOT_OF_UNITY.pow_vartime([1u64 << Scalar::S, 0, 0, 0]),
             Scalar::ONE
         );
 
         // MULTIPLICATIVE_GENERATOR^{t} mod m == ROOT_OF_UNITY
         assert_eq!(
-            Scalar::MULTIPLICATIVE_GENERATOR.pow_vartime(&T),
+            Scalar::MULTIPLICATIVE_GENERATOR.pow_vartime(T),
             Scalar::ROOT_OF_UNITY
         )
     }
@@ -868,7 +921,7 @@ mod tests {
     #[test]
     fn delta_constant() {
         // DELTA^{t} mod m == 1
-        assert_eq!(Scalar::DELTA.pow_vartime(&T), Scalar::ONE);
+        assert_eq!(Scalar::DELTA.pow_vartime(T), Scalar::ONE);
     }
 
     #[test]
@@ -930,6 +983,77 @@ mod tests {
         );
     }
 
+    #[test]
+    fn from_bytes_mod_order_wide_kats() {
+        use hex_literal::hex;
+
+        // n reduces to zero
+        let mut wide = [0u8; 64];
+        wide[32..].copy_from_slice(&ORDER.to_be_byte_array());
+        assert_eq!(Scalar::from_bytes_mod_order_wide(&wide), Scalar::ZERO);
+
+        // 2n reduces to zero
+        let wide: [u8; 64] = hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001
+             fffffffffffffffffffffffffffffffd755db9cd5e9140777fa4bd19a06c8282"
+        );
+        assert_eq!(Scalar::from_bytes_mod_order_wide(&wide), Scalar::ZERO);
+
+        // 2^256
+        let mut wide = [0u8; 64];
+        wide[31] = 1;
+        assert_eq!(
+            Scalar::from_bytes_mod_order_wide(&wide),
+            Scalar::from_repr(
+                hex!("000000000000000000000000000000014551231950b75fc4402da1732fc9bebf").into()
+            )
+            .unwrap()
+        );
+
+        // 2^512 - 1
+        let fe = Scalar::from_bytes_mod_order_wide(&[0xff; 64]);
+        assert_eq!(
+            fe,
+            Scalar::from_repr(
+                hex!("9d671cd581c69bc5e697f5e45bcd07c6741496c20e7cf878896cf21467d7d13f").into()
+            )
+            .unwrap()
+        );
+
+        // FromUniformBytes agrees
+        use elliptic_curve::ff::FromUniformBytes;
+        assert_eq!(Scalar::from_uniform_bytes(&[0xff; 64]), fe);
+    }
+
+    #[test]
+    fn from_bytes_mod_order_wide_statistical_smoke() {
+        use elliptic_curve::rand_core::{OsRng, RngCore};
+
+        // top-byte distribution of reduced outputs should not be skewed
+        // toward small values (as a naive truncation would be)
+        let mut high_top_byte = 0usize;
+        for _ in 0..256 {
+            let mut wide = [0u8; 64];
+            OsRng.fill_bytes(&mut wide);
+            if Scalar::from_bytes_mod_order_wide(&wide).to_bytes()[0] >= 0x80 {
+                high_top_byte += 1;
+            }
+        }
+
+        // expect roughly half; allow a wide margin
+        assert!((64..=192).contains(&high_top_byte));
+    }
+
+    #[test]
+    fn invert_vartime_agrees_with_invert() {
+        use elliptic_curve::rand_core::OsRng;
+
+        for _ in 0..32 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(scalar.invert_vartime().unwrap(), scalar.invert().unwrap());
+        }
+    }
+
     /// Basic tests that `invert_vartime` works.
     #[test]
     fn invert_vartime() {
@@ -1009,7 +1133,7 @@ mod tests {
 
         let a = Scalar::from(&t - &one);
         let b = Scalar::from(&t);
-        let res = &a + &b;
+        let res = a + b;
 
         let m = Scalar::modulus_as_biguint();
         let res_ref = Scalar::from((&t + &t - &one) % &m);
@@ -1022,7 +1146,7 @@ mod tests {
         use elliptic_curve::rand_core::OsRng;
         let a = Scalar::generate_biased(&mut OsRng);
         // just to make sure `a` is not optimized out by the compiler
-        assert_eq!((a - &a).is_zero().unwrap_u8(), 1);
+        assert_eq!((a - a).is_zero().unwrap_u8(), 1);
     }
 
     #[test]
@@ -1030,7 +1154,7 @@ mod tests {
         use elliptic_curve::rand_core::OsRng;
         let a = Scalar::generate_vartime(&mut OsRng);
         // just to make sure `a` is not optimized out by the compiler
-        assert_eq!((a - &a).is_zero().unwrap_u8(), 1);
+        assert_eq!((a - a).is_zero().unwrap_u8(), 1);
     }
 
     #[test]
@@ -0,0 +1,235 @@
+//! Variable-time wNAF scalar multiplication with runtime-selectable
+//! windows.
+
+use super::{glv::SignedHalfScalar, ProjectivePoint, Scalar};
+use alloc::vec::Vec;
+use elliptic_curve::PrimeField;
+
+/// Smallest supported window size.
+pub const MIN_WINDOW: usize = 4;
+
+/// Largest supported window size.
+pub const MAX_WINDOW: usize = 8;
+
+/// A reusable table of odd multiples `[P, 3P, 5P, ..., (2^(w-1) - 1)P]` of
+/// a fixed base point, enabling repeated variable-time multiplications of
+/// the same base (e.g. verifying many signatures under one public key).
+#[derive(Clone)]
+pub struct WnafTable {
+    /// Odd multiples of the base point.
+    multiples: Vec<ProjectivePoint>,
+
+    /// The window size this table was built for.
+    window: usize,
+}
+
+impl WnafTable {
+    /// Precompute the odd multiples of `base` for the given window size
+    /// (clamped to `MIN_WINDOW..=MAX_WINDOW`).
+    pub fn new(base: &ProjectivePoint, window: usize) -> Self {
+        let window = window.clamp(MIN_WINDOW, MAX_WINDOW);
+        let count = 1 << (window - 2);
+
+        let mut multiples = Vec::with_capacity(count);
+        multiples.push(*base);
+        let double = base.double();
+        for i in 1..count {
+            multiples.push(multiples[i - 1] + double);
+        }
+
+        Self { multiples, window }
+    }
+
+    /// The window size this table uses.
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Compute `k * B` in variable time, where `B` is the table's base.
+    ///
+    /// The scalar is GLV-split into two signed half-width components, so
+    /// only ~128 doublings are required; the endomorphism is applied to the
+    /// table entries on the fly (a single field multiplication each).
+    ///
+    /// ⚠️ Variable time in the scalar: do not use with secret scalars.
+    pub fn mul_vartime(&self, k: &Scalar) -> ProjectivePoint {
+        let (k1, k2) = k.split_glv();
+        let digits1 = half_wnaf_digits(&k1, self.window);
+        let digits2 = half_wnaf_digits(&k2, self.window);
+
+        let mut acc = ProjectivePoint::IDENTITY;
+        for i in (0..digits1.len().max(digits2.len())).rev() {
+            acc = acc.double();
+
+            if let Some(&digit) = digits1.get(i) {
+                if digit > 0 {
+                    acc += &self.multiples[(digit as usize - 1) / 2];
+                } else if digit < 0 {
+                    acc -= &self.multiples[((-digit) as usize - 1) / 2];
+                }
+            }
+
+            if let Some(&digit) = digits2.get(i) {
+                if digit > 0 {
+                    acc += &self.multiples[(digit as usize - 1) / 2].endomorphism();
+                } else if digit < 0 {
+                    acc -= &self.multiples[((-digit) as usize - 1) / 2].endomorphism();
+                }
+            }
+        }
+
+        acc
+    }
+}
+
+/// wNAF digits of a signed half-scalar, with the sign folded into the
+/// digits.
+fn half_wnaf_digits(half: &SignedHalfScalar, window: usize) -> Vec<i8> {
+    let mut digits = wnaf_digits(&half.magnitude, window);
+    if half.is_negative {
+        for digit in digits.iter_mut() {
+            *digit = -*digit;
+        }
+    }
+    digits
+}
+
+impl ProjectivePoint {
+    /// Variable-time scalar multiplication using a width-5 wNAF.
+    ///
+    /// ⚠️ Variable time in the scalar: do not use with secret scalars.
+    /// For repeated multiplications of the same base, build a [`WnafTable`]
+    /// once and reuse it.
+    pub fn mul_vartime(&self, k: &Scalar) -> ProjectivePoint {
+        WnafTable::new(self, 5).mul_vartime(k)
+    }
+}
+
+/// Compute the wNAF digit representation of a scalar: digits in
+/// `{0, ±1, ±3, ..., ±(2^(w-1) - 1)}` such that `k == sum(digit[i] * 2^i)`
+/// with at most one non-zero digit per window.
+fn wnaf_digits(k: &Scalar, window: usize) -> Vec<i8> {
+    // little-endian limbs with one spare limb of headroom
+    let bytes = k.to_repr();
+    let mut limbs = [0u64; 5];
+    for i in 0..4 {
+        let mut buf = [0u8; 8];
+        buf.copy_from_slice(&bytes[32 - 8 * (i + 1)..32 - 8 * i]);
+        limbs[i] = u64::from_be_bytes(buf);
+    }
+
+    let is_zero = |l: &[u64; 5]| l.iter().all(|w| *w == 0);
+    let shr1 = |l: &mut [u64; 5]| {
+        for i in 0..5 {
+            l[i] >>= 1;
+            if i + 1 < 5 {
+                l[i] |= l[i + 1] << 63;
+            }
+        }
+    };
+
+    let full = 1i64 << window;
+    let half = 1i64 << (window - 1);
+    let mask = (1u64 << window) - 1;
+
+    let mut digits = Vec::with_capacity(257);
+
+    while !is_zero(&limbs) {
+        if limbs[0] & 1 == 1 {
+            let mut digit = (limbs[0] & mask) as i64;
+            if digit >= half {
+                digit -= full;
+            }
+
+            // k -= digit
+            if digit >= 0 {
+                let mut borrow = digit as u64;
+                for limb in limbs.iter_mut() {
+                    let (value, underflow) = limb.overflowing_sub(borrow);
+                    *limb = value;
+                    borrow = u64::from(underflow);
+                    if borrow == 0 {
+                        break;
+                    }
+                }
+            } else {
+                let mut carry = (-digit) as u64;
+                for limb in limbs.iter_mut() {
+                    let (value, overflow) = limb.overflowing_add(carry);
+                    *limb = value;
+                    carry = u64::from(overflow);
+                    if carry == 0 {
+                        break;
+                    }
+                }
+            }
+
+            digits.push(digit as i8);
+        } else {
+            digits.push(0);
+        }
+
+        shr1(&mut limbs);
+    }
+
+    digits
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{WnafTable, MAX_WINDOW, MIN_WINDOW};
+    use crate::{ProjectivePoint, Scalar};
+    use elliptic_curve::{rand_core::OsRng, Field, Group};
+
+    #[test]
+    fn mul_vartime_matches_constant_time() {
+        for _ in 0..8 {
+            let p = ProjectivePoint::random(&mut OsRng);
+            let k = Scalar::random(&mut OsRng);
+            assert_eq!(p.mul_vartime(&k), p * k);
+        }
+    }
+
+    #[test]
+    fn all_window_sizes_agree() {
+        let p = ProjectivePoint::random(&mut OsRng);
+        let k = Scalar::random(&mut OsRng);
+        let expected = p * k;
+
+        for window in MIN_WINDOW..=MAX_WINDOW {
+            let table = WnafTable::new(&p, window);
+            assert_eq!(table.window(), window);
+            assert_eq!(table.mul_vartime(&k), expected, "window {window}");
+        }
+    }
+
+    #[test]
+    fn table_reuse_and_edge_scalars() {
+        let p = ProjectivePoint::random(&mut OsRng);
+        let table = WnafTable::new(&p, 6);
+
+        for k in [
+            Scalar::ZERO,
+            Scalar::ONE,
+            Scalar::from(2u64),
+            -Scalar::ONE,
+            -Scalar::from(2u64),
+            Scalar::random(&mut OsRng),
+        ] {
+            assert_eq!(table.mul_vartime(&k), p * k);
+        }
+
+        assert_eq!(
+            WnafTable::new(&ProjectivePoint::IDENTITY, 5).mul_vartime(&Scalar::ONE),
+            ProjectivePoint::IDENTITY
+        );
+    }
+
+    #[test]
+    fn window_clamping() {
+        let p = ProjectivePoint::GENERATOR;
+        assert_eq!(WnafTable::new(&p, 1).window(), MIN_WINDOW);
+        assert_eq!(WnafTable::new(&p, 64).window(), MAX_WINDOW);
+    }
+}
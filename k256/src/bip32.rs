@@ -0,0 +1,245 @@
+//! BIP32 hierarchical key derivation math for secp256k1.
+//!
+//! Implements the key-material side of [BIP32]: master key generation from
+//! a seed, hardened and non-hardened `CKDpriv`, non-hardened `CKDpub`, and
+//! plain additive child tweaks via the [`DeriveChild`] extension trait.
+//! Extended-key serialization (xprv/xpub encoding, fingerprints, depth) is
+//! out of scope; only the cryptographic derivation lives here.
+//!
+//! [BIP32]: https://github.com/bitcoin/bips/blob/master/bip-0032.mediawiki
+
+use crate::{
+    ecdsa::{SigningKey, VerifyingKey},
+    NonZeroScalar, ProjectivePoint, Scalar, U256,
+};
+use elliptic_curve::{group::prime::PrimeCurveAffine, ops::Reduce, sec1::ToEncodedPoint};
+use hmac::{Hmac, Mac};
+use sha2::Sha512;
+use signature::{Error, Result};
+
+/// A BIP32 chain code.
+pub type ChainCode = [u8; 32];
+
+/// First hardened child index.
+pub const HARDENED_OFFSET: u32 = 0x8000_0000;
+
+/// Additive child derivation with an explicit tweak.
+pub trait DeriveChild: Sized {
+    /// Derive the child key for the given additive tweak, failing if the
+    /// result is zero (private keys) or the identity (public keys).
+    fn derive_child(&self, tweak: &Scalar) -> Result<Self>;
+}
+
+impl DeriveChild for SigningKey {
+    fn derive_child(&self, tweak: &Scalar) -> Result<Self> {
+        let child = *self.as_nonzero_scalar().as_ref() + tweak;
+        let child =
+            Option::<NonZeroScalar>::from(NonZeroScalar::new(child)).ok_or_else(Error::new)?;
+        Ok(SigningKey::from(child))
+    }
+}
+
+impl DeriveChild for VerifyingKey {
+    fn derive_child(&self, tweak: &Scalar) -> Result<Self> {
+        let child = ProjectivePoint::from(*self.as_affine()) + ProjectivePoint::GENERATOR * tweak;
+        let child = child.to_affine();
+
+        if bool::from(child.is_identity()) {
+            return Err(Error::new());
+        }
+
+        VerifyingKey::from_affine(child).map_err(|_| Error::new())
+    }
+}
+
+/// Derive the BIP32 master key and chain code from a seed:
+/// `HMAC-SHA512("Bitcoin seed", seed)`.
+pub fn master_from_seed(seed: &[u8]) -> Result<(SigningKey, ChainCode)> {
+    let i = hmac_sha512(b"Bitcoin seed", seed);
+    split_i(&i)
+}
+
+/// BIP32 `CKDpriv`: derive the child private key and chain code for the
+/// given index (hardened when `index >= HARDENED_OFFSET`).
+pub fn ckd_priv(
+    parent: &SigningKey,
+    chain_code: &ChainCode,
+    index: u32,
+) -> Result<(SigningKey, ChainCode)> {
+    let mut data = [0u8; 37];
+    if index >= HARDENED_OFFSET {
+        // 0x00 || ser256(k_par) || ser32(i)
+        data[1..33].copy_from_slice(&parent.to_bytes());
+    } else {
+        // serP(K_par) || ser32(i)
+        data[..33].copy_from_slice(
+            parent
+                .verifying_key()
+                .as_affine()
+                .to_encoded_point(true)
+                .as_bytes(),
+        );
+    }
+    data[33..].copy_from_slice(&index.to_be_bytes());
+
+    let i = hmac_sha512(chain_code, &data);
+    let (tweak, child_chain_code) = split_tweak(&i)?;
+
+    let child = parent.derive_child(&tweak)?;
+    Ok((child, child_chain_code))
+}
+
+/// BIP32 `CKDpub`: derive the child public key and chain code for a
+/// non-hardened index.
+///
+/// Hardened indices cannot be derived from public keys and are rejected.
+pub fn ckd_pub(
+    parent: &VerifyingKey,
+    chain_code: &ChainCode,
+    index: u32,
+) -> Result<(VerifyingKey, ChainCode)> {
+    if index >= HARDENED_OFFSET {
+        return Err(Error::new());
+    }
+
+    let mut data = [0u8; 37];
+    data[..33].copy_from_slice(parent.as_affine().to_encoded_point(true).as_bytes());
+    data[33..].copy_from_slice(&index.to_be_bytes());
+
+    let i = hmac_sha512(chain_code, &data);
+    let (tweak, child_chain_code) = split_tweak(&i)?;
+
+    let child = parent.derive_child(&tweak)?;
+    Ok((child, child_chain_code))
+}
+
+fn hmac_sha512(key: &[u8], data: &[u8]) -> [u8; 64] {
+    // HMAC accepts keys of any length
+    #[allow(clippy::unwrap_used)]
+    let mut mac = Hmac::<Sha512>::new_from_slice(key).unwrap();
+    mac.update(data);
+    mac.finalize().into_bytes().into()
+}
+
+/// Split `I` into a master key and chain code, rejecting the (negligible)
+/// invalid cases per BIP32.
+fn split_i(i: &[u8; 64]) -> Result<(SigningKey, ChainCode)> {
+    let key = NonZeroScalar::try_from(&i[..32]).map_err(|_| Error::new())?;
+    let mut chain_code = ChainCode::default();
+    chain_code.copy_from_slice(&i[32..]);
+    Ok((SigningKey::from(key), chain_code))
+}
+
+/// Split `I` into a tweak scalar and chain code, rejecting `IL >= n`.
+fn split_tweak(i: &[u8; 64]) -> Result<(Scalar, ChainCode)> {
+    use elliptic_curve::bigint::Encoding;
+
+    // BIP32 requires IL < n (resample at the caller on failure)
+    let il = U256::from_be_bytes(i[..32].try_into().map_err(|_| Error::new())?);
+    if il >= crate::ORDER {
+        return Err(Error::new());
+    }
+
+    let tweak = <Scalar as Reduce<U256>>::reduce_bytes(crate::FieldBytes::from_slice(&i[..32]));
+    let mut chain_code = ChainCode::default();
+    chain_code.copy_from_slice(&i[32..]);
+    Ok((tweak, chain_code))
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{ckd_priv, ckd_pub, master_from_seed, DeriveChild, HARDENED_OFFSET};
+    use crate::Scalar;
+    use hex_literal::hex;
+
+    /// BIP32 test vector 1 (seed `000102030405060708090a0b0c0d0e0f`):
+    /// key material for m, m/0H and m/0H/1.
+    #[test]
+    fn vector_1() {
+        let seed = hex!("000102030405060708090a0b0c0d0e0f");
+        let (master, cc) = master_from_seed(&seed).unwrap();
+        assert_eq!(
+            master.to_bytes().as_slice(),
+            &hex!("e8f32e723decf4051aefac8e2c93c9c5b214313817cdb01a1494b917c8436b35")
+        );
+        assert_eq!(
+            cc,
+            hex!("873dff81c02f525623fd1fe5167eac3a55a049de3d314bb42ee227ffed37d508")
+        );
+
+        let (child, cc) = ckd_priv(&master, &cc, HARDENED_OFFSET).unwrap();
+        assert_eq!(
+            child.to_bytes().as_slice(),
+            &hex!("edb2e14f9ee77d26dd93b4ecede8d16ed408ce149b6cd80b0715a2d911a0afea")
+        );
+        assert_eq!(
+            cc,
+            hex!("47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141")
+        );
+
+        let (grandchild, cc) = ckd_priv(&child, &cc, 1).unwrap();
+        assert_eq!(
+            grandchild.to_bytes().as_slice(),
+            &hex!("3c6cb8d0f6a264c91ea8b5030fadaa8e538b020f0a387421a12de9319dc93368")
+        );
+        assert_eq!(
+            cc,
+            hex!("2a7857631386ba23dacac34180dd1983734e444fdbf774041578e9b6adb37c19")
+        );
+
+        // non-hardened CKDpub agrees with CKDpriv on the public side
+        let cc1 = hex!("47fdacbd0f1097043b78c63c20c34ef4ed9a111d980047ad16282c7ae6236141");
+        let (pub_child, pub_cc) = ckd_pub(child.verifying_key(), &cc1, 1).unwrap();
+        assert_eq!(&pub_child, grandchild.verifying_key());
+        assert_eq!(pub_cc, cc);
+    }
+
+    /// BIP32 test vector 2 key material for m and m/0.
+    #[test]
+    fn vector_2() {
+        let seed = hex!(
+            "fffcf9f6f3f0edeae7e4e1dedbd8d5d2cfccc9c6c3c0bdbab7b4b1aeaba8a5a2
+             9f9c999693908d8a8784817e7b7875726f6c696663605d5a5754514e4b484542"
+        );
+        let (master, cc) = master_from_seed(&seed).unwrap();
+        assert_eq!(
+            master.to_bytes().as_slice(),
+            &hex!("4b03d6fc340455b363f51020ad3ecca4f0850280cf436c70c727923f6db46c3e")
+        );
+        assert_eq!(
+            cc,
+            hex!("60499f801b896d83179a4374aeb7822aaeaceaa0db1f85ee3e904c4defbd9689")
+        );
+
+        let (child, cc) = ckd_priv(&master, &cc, 0).unwrap();
+        assert_eq!(
+            child.to_bytes().as_slice(),
+            &hex!("abe74a98f6c7eabee0428f53798f0ab8aa1bd37873999041703c742f15ac7e1e")
+        );
+        assert_eq!(
+            cc,
+            hex!("f0909affaa7ee7abe5dd4e100598d4dc53cd709d5a5c2cac40e7412f232f7c9c")
+        );
+    }
+
+    #[test]
+    fn hardened_ckd_pub_rejected() {
+        let (master, cc) = master_from_seed(&[0x42u8; 16]).unwrap();
+        assert!(ckd_pub(master.verifying_key(), &cc, HARDENED_OFFSET).is_err());
+    }
+
+    #[test]
+    fn derive_child_zero_rejected() {
+        let (master, _) = master_from_seed(&[0x01u8; 16]).unwrap();
+        let neg = -*master.as_nonzero_scalar().as_ref();
+        assert!(master.derive_child(&neg).is_err());
+        assert!(master.verifying_key().derive_child(&neg).is_err());
+
+        // a benign tweak works and agrees across private/public derivation
+        let tweak = Scalar::from(1234567u64);
+        let sk_child = master.derive_child(&tweak).unwrap();
+        let vk_child = master.verifying_key().derive_child(&tweak).unwrap();
+        assert_eq!(sk_child.verifying_key(), &vk_child);
+    }
+}
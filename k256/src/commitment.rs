@@ -0,0 +1,191 @@
+//! Pedersen commitments over secp256k1.
+//!
+//! A Pedersen commitment to a value `v` with blinding factor `r` is the
+//! curve point `C = v*H + r*G`, where `H` is a "nothing up my sleeve"
+//! second generator with unknown discrete logarithm relative to `G`. The
+//! default [`PedersenGens`] derives `H` exactly as Bitcoin's
+//! Elements/secp256k1-zkp does: the SHA-256 hash of the uncompressed SEC1
+//! encoding of `G`, lifted to the curve point with even Y.
+//!
+//! Commitments are homomorphic: `commit(v1, r1) + commit(v2, r2)` is a
+//! commitment to `v1 + v2` with blinding `r1 + r2`.
+
+use crate::{AffinePoint, EncodedPoint, ProjectivePoint, Scalar};
+use core::ops::{Add, Sub};
+use elliptic_curve::{
+    group::{prime::PrimeCurveAffine, GroupEncoding},
+    ops::LinearCombination,
+    point::DecompactPoint,
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Error, FieldBytes, Result,
+};
+use sha2::{Digest, Sha256};
+
+/// Generator points for Pedersen commitments.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PedersenGens {
+    /// Generator for the blinding factor (the curve generator `G`).
+    pub blinding_generator: ProjectivePoint,
+
+    /// Generator for the committed value (`H`).
+    pub value_generator: ProjectivePoint,
+}
+
+impl Default for PedersenGens {
+    fn default() -> Self {
+        Self {
+            blinding_generator: ProjectivePoint::GENERATOR,
+            value_generator: nums_generator(),
+        }
+    }
+}
+
+impl PedersenGens {
+    /// Commit to `value` with the given `blinding` factor:
+    /// `C = value*H + blinding*G`.
+    pub fn commit(&self, value: &Scalar, blinding: &Scalar) -> PedersenCommitment {
+        PedersenCommitment(ProjectivePoint::lincomb(
+            &self.value_generator,
+            value,
+            &self.blinding_generator,
+            blinding,
+        ))
+    }
+
+    /// Verify that `commitment` opens to `value` with `blinding`.
+    pub fn verify_opening(
+        &self,
+        commitment: &PedersenCommitment,
+        value: &Scalar,
+        blinding: &Scalar,
+    ) -> bool {
+        self.commit(value, blinding) == *commitment
+    }
+
+    /// Re-blind a commitment: returns a commitment to the same value whose
+    /// blinding factor is increased by `delta`, without knowledge of the
+    /// original opening ("switch" operation).
+    pub fn switch(&self, commitment: &PedersenCommitment, delta: &Scalar) -> PedersenCommitment {
+        PedersenCommitment(commitment.0 + self.blinding_generator * delta)
+    }
+}
+
+/// A Pedersen commitment: a point on the secp256k1 curve.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PedersenCommitment(ProjectivePoint);
+
+impl PedersenCommitment {
+    /// Serialize as a 33-byte compressed SEC1 point.
+    pub fn to_bytes(&self) -> [u8; 33] {
+        let mut out = [0u8; 33];
+        out.copy_from_slice(self.0.to_bytes().as_ref());
+        out
+    }
+
+    /// Parse a commitment from its 33-byte compressed encoding.
+    pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self> {
+        let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| Error)?;
+        if !encoded.is_compressed() {
+            return Err(Error);
+        }
+
+        let affine =
+            Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded)).ok_or(Error)?;
+        Ok(Self(ProjectivePoint::from(affine)))
+    }
+
+    /// Borrow the underlying curve point.
+    pub fn as_point(&self) -> &ProjectivePoint {
+        &self.0
+    }
+}
+
+impl Add for PedersenCommitment {
+    type Output = PedersenCommitment;
+
+    fn add(self, rhs: Self) -> Self {
+        Self(self.0 + rhs.0)
+    }
+}
+
+impl Sub for PedersenCommitment {
+    type Output = PedersenCommitment;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self(self.0 - rhs.0)
+    }
+}
+
+/// Derive the standard NUMS second generator `H`: the SHA-256 hash of the
+/// uncompressed encoding of `G`, lifted to the curve point with even Y.
+///
+/// This matches the `H` used by Elements/secp256k1-zkp.
+fn nums_generator() -> ProjectivePoint {
+    let digest = Sha256::digest(AffinePoint::GENERATOR.to_encoded_point(false).as_bytes());
+
+    // The hash of G's encoding happens to be a valid x-coordinate; this
+    // unwrap is exercised by the `nums_generator_matches_constant` test.
+    #[allow(clippy::unwrap_used)]
+    AffinePoint::decompact(FieldBytes::<crate::Secp256k1>::from_slice(&digest))
+        .unwrap()
+        .to_curve()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{PedersenCommitment, PedersenGens};
+    use crate::Scalar;
+    use elliptic_curve::{rand_core::OsRng, Field};
+    use hex_literal::hex;
+
+    #[test]
+    fn nums_generator_matches_constant() {
+        // the H point used by Elements/secp256k1-zkp
+        let expected = hex!("0250929b74c1a04954b78b4b6035e97a5e078a5a0f28ec96d547bfee9ace803ac0");
+        let gens = PedersenGens::default();
+        let commitment = PedersenCommitment(gens.value_generator);
+        assert_eq!(commitment.to_bytes(), expected);
+    }
+
+    #[test]
+    fn commit_opens_and_roundtrips() {
+        let gens = PedersenGens::default();
+        let value = Scalar::from(1000u64);
+        let blinding = Scalar::random(&mut OsRng);
+
+        let commitment = gens.commit(&value, &blinding);
+        assert!(gens.verify_opening(&commitment, &value, &blinding));
+        assert!(!gens.verify_opening(&commitment, &Scalar::from(1001u64), &blinding));
+        assert!(!gens.verify_opening(&commitment, &value, &Scalar::ZERO));
+
+        let bytes = commitment.to_bytes();
+        assert_eq!(PedersenCommitment::from_bytes(&bytes).unwrap(), commitment);
+    }
+
+    #[test]
+    fn homomorphism() {
+        let gens = PedersenGens::default();
+        let (v1, v2) = (Scalar::from(3u64), Scalar::from(39u64));
+        let (r1, r2) = (Scalar::random(&mut OsRng), Scalar::random(&mut OsRng));
+
+        let c1 = gens.commit(&v1, &r1);
+        let c2 = gens.commit(&v2, &r2);
+
+        assert_eq!(c1 + c2, gens.commit(&(v1 + v2), &(r1 + r2)));
+        assert_eq!(c2 - c1, gens.commit(&(v2 - v1), &(r2 - r1)));
+    }
+
+    #[test]
+    fn switch_reblinds() {
+        let gens = PedersenGens::default();
+        let value = Scalar::from(77u64);
+        let blinding = Scalar::random(&mut OsRng);
+        let delta = Scalar::random(&mut OsRng);
+
+        let commitment = gens.commit(&value, &blinding);
+        let switched = gens.switch(&commitment, &delta);
+
+        assert!(gens.verify_opening(&switched, &value, &(blinding + delta)));
+        assert_ne!(commitment, switched);
+    }
+}
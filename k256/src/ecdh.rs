@@ -38,7 +38,9 @@
 
 pub use elliptic_curve::ecdh::diffie_hellman;
 
-use crate::{AffinePoint, Secp256k1};
+use crate::{AffinePoint, NonZeroScalar, ProjectivePoint, PublicKey, Secp256k1};
+use digest::{core_api::BlockSizeUser, Digest};
+use elliptic_curve::{group::prime::PrimeCurveAffine, Error, Result};
 
 /// secp256k1 Ephemeral Diffie-Hellman Secret.
 pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<Secp256k1>;
@@ -51,3 +53,93 @@ impl From<&AffinePoint> for SharedSecret {
         affine.x.to_bytes().into()
     }
 }
+
+/// Checked static Diffie-Hellman: validates the peer key and rejects a
+/// point-at-infinity result instead of silently encoding it.
+///
+/// The peer key must arrive as a [`PublicKey`], whose decoding already
+/// enforces the on-curve and non-identity checks; since secp256k1 has
+/// cofactor 1 and the secret scalar is non-zero, an identity product is
+/// mathematically unreachable for such inputs, but this entry point keeps
+/// the check in code as defense in depth.
+pub fn diffie_hellman_checked(
+    secret_key: &NonZeroScalar,
+    public_key: &PublicKey,
+) -> Result<SharedSecret> {
+    let shared_point =
+        (ProjectivePoint::from(public_key.as_affine()) * secret_key.as_ref()).to_affine();
+
+    if bool::from(shared_point.is_identity()) {
+        return Err(Error);
+    }
+
+    Ok(SharedSecret::from(&shared_point))
+}
+
+/// One-call HKDF convenience over a [`SharedSecret`].
+///
+/// Prefer this (or [`SharedSecret::extract`]) over `raw_secret_bytes`: the
+/// raw x-coordinate is not a uniformly random byte string and must go
+/// through a KDF before use as symmetric key material.
+pub trait SharedSecretExt {
+    /// Derive `okm.len()` bytes of output key material via
+    /// HKDF-extract-and-expand over the shared secret.
+    fn hkdf<D>(&self, salt: Option<&[u8]>, info: &[u8], okm: &mut [u8]) -> Result<()>
+    where
+        D: BlockSizeUser + Clone + Digest;
+}
+
+impl SharedSecretExt for SharedSecret {
+    fn hkdf<D>(&self, salt: Option<&[u8]>, info: &[u8], okm: &mut [u8]) -> Result<()>
+    where
+        D: BlockSizeUser + Clone + Digest,
+    {
+        self.extract::<D>(salt).expand(info, okm).map_err(|_| Error)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{diffie_hellman_checked, SharedSecretExt};
+    use crate::{NonZeroScalar, PublicKey};
+    use elliptic_curve::rand_core::OsRng;
+    use sha2::Sha256;
+
+    #[test]
+    fn checked_dh_and_hkdf() {
+        let a = NonZeroScalar::random(&mut OsRng);
+        let b = NonZeroScalar::random(&mut OsRng);
+        let (pa, pb) = (
+            PublicKey::from_secret_scalar(&a),
+            PublicKey::from_secret_scalar(&b),
+        );
+
+        let s1 = diffie_hellman_checked(&a, &pb).unwrap();
+        let s2 = diffie_hellman_checked(&b, &pa).unwrap();
+        assert_eq!(s1.raw_secret_bytes(), s2.raw_secret_bytes());
+
+        let mut k1 = [0u8; 32];
+        let mut k2 = [0u8; 32];
+        s1.hkdf::<Sha256>(Some(b"salt"), b"info", &mut k1).unwrap();
+        s2.hkdf::<Sha256>(Some(b"salt"), b"info", &mut k2).unwrap();
+        assert_eq!(k1, k2);
+
+        // differing info separates keys
+        let mut k3 = [0u8; 32];
+        s1.hkdf::<Sha256>(Some(b"salt"), b"other", &mut k3).unwrap();
+        assert_ne!(k1, k3);
+
+        // oversized okm request fails cleanly
+        let mut too_long = [0u8; 256 * 32 + 1];
+        assert!(s1.hkdf::<Sha256>(None, b"", &mut too_long).is_err());
+    }
+
+    #[test]
+    fn identity_peer_unrepresentable() {
+        // the identity cannot be decoded as a PublicKey, so the crafted
+        // low-order-style inputs of cofactor > 1 curves cannot reach
+        // diffie_hellman_checked on secp256k1
+        assert!(PublicKey::from_sec1_bytes(&[0x00]).is_err());
+    }
+}
@@ -142,6 +142,21 @@
 //! # }
 //! ```
 
+#[cfg(all(feature = "ecdsa", feature = "adaptor"))]
+pub mod adaptor;
+
+#[cfg(all(feature = "ecdsa", feature = "anti-exfil"))]
+pub mod anti_exfil;
+
+#[cfg(feature = "ethereum")]
+pub mod ethereum;
+
+#[cfg(all(feature = "ecdsa", feature = "alloc", feature = "sha256"))]
+mod precomputed;
+
+#[cfg(all(feature = "ecdsa", feature = "alloc", feature = "sha256"))]
+pub use precomputed::VerifyingKeyWithTable;
+
 pub use ecdsa_core::{
     signature::{self, Error},
     RecoveryId,
@@ -208,6 +223,847 @@ impl VerifyPrimitive<Secp256k1> for AffinePoint {
     }
 }
 
+#[cfg(feature = "ecdsa")]
+use {elliptic_curve::rand_core::CryptoRngCore, signature::digest::Digest};
+
+/// Error returned by [`batch_verify`].
+#[cfg(all(feature = "ecdsa", feature = "alloc"))]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BatchVerifyError {
+    /// Index of the first invalid item, identified by the per-item fallback
+    /// scan after the batched equation failed.
+    pub invalid_index: Option<usize>,
+}
+
+/// Verify a batch of ECDSA signatures with a single variable-time
+/// multiscalar multiplication.
+///
+/// Each signature is checked via the inversion-free relation
+/// `z_i*G + r_i*P_i - s_i*R_i == O`, where `R_i` is the nonce point
+/// reconstructed from `r_i` and the signature's [`RecoveryId`]; the
+/// equations are combined with independent random 128-bit coefficients.
+/// Recovering `R_i` is why a recovery ID is required per item: ECDSA's
+/// x-only `r` loses the parity of the nonce point, and without it the
+/// relation cannot be linearized into one multiexponentiation.
+///
+/// This answers only "all valid / not all valid" for throughput-oriented
+/// workloads; when the batch fails, a sequential fallback identifies the
+/// first invalid index. The whole operation is variable time and must only
+/// be used with public inputs.
+#[cfg(all(feature = "ecdsa", feature = "alloc"))]
+pub fn batch_verify(
+    items: &[(VerifyingKey, [u8; 32], Signature, RecoveryId)],
+    rng: &mut impl CryptoRngCore,
+) -> Result<(), BatchVerifyError> {
+    use crate::{ProjectivePoint, U256};
+    use elliptic_curve::{
+        group::Group,
+        ops::{LinearCombinationExt, Reduce},
+        PrimeField,
+    };
+
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    let mut terms = alloc::vec::Vec::with_capacity(2 * items.len() + 1);
+    let mut z_sum = Scalar::ZERO;
+
+    for (i, (verifying_key, prehash, signature, recovery_id)) in items.iter().enumerate() {
+        let invalid = || BatchVerifyError {
+            invalid_index: Some(i),
+        };
+
+        // match the sequential verifier's low-S policy
+        if bool::from(signature.s().is_high()) {
+            return Err(invalid());
+        }
+
+        let big_r = recover_nonce_point(signature, recovery_id).ok_or_else(invalid)?;
+
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(prehash));
+        let r = <Scalar as Reduce<U256>>::reduce_bytes(&signature.r().to_bytes());
+        let s = <Scalar as Reduce<U256>>::reduce_bytes(&signature.s().to_bytes());
+
+        // a_0 = 1; later coefficients carry 128 bits of randomness
+        let a = if i == 0 {
+            Scalar::ONE
+        } else {
+            let mut bytes = [0u8; 32];
+            rng.fill_bytes(&mut bytes[16..]);
+            #[allow(clippy::unwrap_used)]
+            Option::<Scalar>::from(Scalar::from_repr(bytes.into())).unwrap()
+        };
+
+        z_sum += a * z;
+        terms.push((ProjectivePoint::from(*verifying_key.as_affine()), a * r));
+        terms.push((big_r, -(a * s)));
+    }
+
+    terms.push((ProjectivePoint::GENERATOR, z_sum));
+
+    if bool::from(ProjectivePoint::lincomb_ext(terms.as_slice()).is_identity()) {
+        return Ok(());
+    }
+
+    // slow path: identify the offender
+    use ecdsa_core::signature::hazmat::PrehashVerifier;
+    for (i, (verifying_key, prehash, signature, _)) in items.iter().enumerate() {
+        if verifying_key.verify_prehash(prehash, signature).is_err() {
+            return Err(BatchVerifyError {
+                invalid_index: Some(i),
+            });
+        }
+    }
+
+    Err(BatchVerifyError {
+        invalid_index: None,
+    })
+}
+
+/// Reconstruct the ECDSA nonce point `R` from a signature's `r` component
+/// and recovery ID.
+#[cfg(all(feature = "ecdsa", feature = "alloc"))]
+fn recover_nonce_point(
+    signature: &Signature,
+    recovery_id: &RecoveryId,
+) -> Option<crate::ProjectivePoint> {
+    use crate::{CompressedPoint, ProjectivePoint, U256};
+    use elliptic_curve::{bigint::ArrayEncoding, sec1::FromEncodedPoint, Curve};
+
+    let mut x = U256::from_be_byte_array(signature.r().to_bytes());
+    if recovery_id.is_x_reduced() {
+        x = x.wrapping_add(&Secp256k1::ORDER);
+    }
+
+    let mut compressed = CompressedPoint::default();
+    compressed[0] = if recovery_id.is_y_odd() { 0x03 } else { 0x02 };
+    compressed[1..].copy_from_slice(&x.to_be_byte_array());
+
+    let encoded = crate::EncodedPoint::from_bytes(compressed).ok()?;
+    Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+        .map(ProjectivePoint::from)
+}
+
+/// Additive secret sharing of signing keys for simple multi-party setups.
+#[cfg(all(feature = "ecdsa", feature = "alloc"))]
+pub trait AdditiveSharing: Sized {
+    /// The public counterpart used for share aggregation.
+    type Public;
+
+    /// Split into `n >= 1` additive shares which sum to this key.
+    ///
+    /// All but one share are uniformly random; arithmetic on the secret is
+    /// constant time, and shares are [`NonZeroScalar`]s which zeroize on
+    /// drop.
+    ///
+    /// [`NonZeroScalar`]: crate::NonZeroScalar
+    fn split_additive(
+        &self,
+        n: usize,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<alloc::vec::Vec<crate::NonZeroScalar>, Error>;
+
+    /// Recombine additive shares into the original key.
+    fn from_additive_shares(shares: &[crate::NonZeroScalar]) -> Result<Self, Error>;
+
+    /// Aggregate the public counterparts of the shares, rejecting an
+    /// identity sum.
+    fn from_public_shares(shares: &[Self::Public]) -> Result<Self::Public, Error>;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "alloc"))]
+impl AdditiveSharing for SigningKey {
+    type Public = VerifyingKey;
+
+    fn split_additive(
+        &self,
+        n: usize,
+        rng: &mut impl CryptoRngCore,
+    ) -> Result<alloc::vec::Vec<crate::NonZeroScalar>, Error> {
+        if n == 0 {
+            return Err(Error::new());
+        }
+
+        loop {
+            let mut shares = alloc::vec::Vec::with_capacity(n);
+            let mut sum = Scalar::ZERO;
+
+            for _ in 0..n - 1 {
+                let share = crate::NonZeroScalar::random(rng);
+                sum += share.as_ref();
+                shares.push(share);
+            }
+
+            let last = *self.as_nonzero_scalar().as_ref() - sum;
+            match Option::<crate::NonZeroScalar>::from(crate::NonZeroScalar::new(last)) {
+                Some(last) => {
+                    shares.push(last);
+                    return Ok(shares);
+                }
+                // negligible probability: resample the random shares
+                None => continue,
+            }
+        }
+    }
+
+    fn from_additive_shares(shares: &[crate::NonZeroScalar]) -> Result<Self, Error> {
+        if shares.is_empty() {
+            return Err(Error::new());
+        }
+
+        let mut sum = Scalar::ZERO;
+        for share in shares {
+            sum += share.as_ref();
+        }
+
+        let sum = Option::<crate::NonZeroScalar>::from(crate::NonZeroScalar::new(sum))
+            .ok_or_else(Error::new)?;
+        Ok(SigningKey::from(sum))
+    }
+
+    fn from_public_shares(shares: &[VerifyingKey]) -> Result<VerifyingKey, Error> {
+        use elliptic_curve::group::Group;
+
+        if shares.is_empty() {
+            return Err(Error::new());
+        }
+
+        let mut sum = crate::ProjectivePoint::IDENTITY;
+        for share in shares {
+            sum += crate::ProjectivePoint::from(*share.as_affine());
+        }
+
+        if bool::from(sum.is_identity()) {
+            return Err(Error::new());
+        }
+
+        VerifyingKey::from_affine(sum.to_affine()).map_err(|_| Error::new())
+    }
+}
+
+/// Randomized recoverable signing: RFC 6979 nonce derivation with RNG
+/// entropy mixed in as additional data, for fault-attack hardening.
+///
+/// The non-randomized [`SigningKey::sign_digest_recoverable`] and
+/// [`VerifyingKey::recover_from_digest`] are already generic over any
+/// 32-byte-output [`Digest`] (e.g. Keccak-256, SHA3-256), with no coupling
+/// to the curve's default digest.
+///
+/// [`Digest`]: signature::digest::Digest
+#[cfg(feature = "ecdsa")]
+pub trait RandomizedRecoverableSigner {
+    /// Sign a 32-byte prehash with RNG entropy mixed into the RFC 6979
+    /// nonce, returning the signature and recovery ID.
+    fn sign_prehash_recoverable_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<(Signature, RecoveryId), Error>;
+
+    /// Sign a digest with RNG entropy mixed into the RFC 6979 nonce.
+    fn sign_digest_recoverable_with_rng<D: Digest>(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg_digest: D,
+    ) -> Result<(Signature, RecoveryId), Error>;
+}
+
+#[cfg(feature = "ecdsa")]
+impl RandomizedRecoverableSigner for SigningKey {
+    fn sign_prehash_recoverable_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<(Signature, RecoveryId), Error> {
+        let z = hazmat::bits2field::<Secp256k1>(prehash)?;
+
+        let mut ad = [0u8; 32];
+        rng.fill_bytes(&mut ad);
+
+        let (signature, recovery_id) = self
+            .as_nonzero_scalar()
+            .as_ref()
+            .try_sign_prehashed_rfc6979::<sha2::Sha256>(&z, &ad)?;
+
+        Ok((signature, recovery_id.ok_or_else(Error::new)?))
+    }
+
+    fn sign_digest_recoverable_with_rng<D: Digest>(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        msg_digest: D,
+    ) -> Result<(Signature, RecoveryId), Error> {
+        self.sign_prehash_recoverable_with_rng(rng, &msg_digest.finalize())
+    }
+}
+
+/// Low-S-related signature queries.
+///
+/// ECDSA signatures are malleable: `(r, s)` and `(r, n - s)` are both
+/// valid. Bitcoin consensus requires the "low-S" normalized form, which is
+/// what k256 produces when signing and what the default verifier enforces.
+#[cfg(feature = "ecdsa")]
+pub trait SignatureNormalization {
+    /// Is the `s` component in the high half of the scalar range?
+    fn is_high(&self) -> bool;
+
+    /// Is this signature in the canonical low-S form?
+    fn is_normalized(&self) -> bool {
+        !self.is_high()
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl SignatureNormalization for Signature {
+    fn is_high(&self) -> bool {
+        IsHigh::is_high(&*self.s()).into()
+    }
+}
+
+/// Verification policy options for [`VerifyingOptions`].
+///
+/// The default k256 verifier is strict (rejects high-S); use
+/// `require_low_s: false` for ecosystems which accept either form.
+#[cfg(feature = "ecdsa")]
+#[derive(Copy, Clone, Debug)]
+pub struct VerifyingOptions {
+    /// Reject signatures whose `s` component is not low-S normalized
+    /// (the Bitcoin consensus rule). Defaults to `true`, matching
+    /// [`VerifyingKey`].
+    pub require_low_s: bool,
+}
+
+#[cfg(feature = "ecdsa")]
+impl Default for VerifyingOptions {
+    fn default() -> Self {
+        Self {
+            require_low_s: true,
+        }
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl VerifyingOptions {
+    /// Verify `signature` over `prehash` under `verifying_key` with this
+    /// policy.
+    ///
+    /// Zero or out-of-range `r`/`s` components are unrepresentable in
+    /// [`Signature`] and therefore always rejected at parse time.
+    pub fn verify_prehash(
+        &self,
+        verifying_key: &VerifyingKey,
+        prehash: &[u8],
+        signature: &Signature,
+    ) -> Result<(), Error> {
+        use ecdsa_core::signature::hazmat::PrehashVerifier;
+
+        if self.require_low_s {
+            verifying_key.verify_prehash(prehash, signature)
+        } else {
+            // lenient: accept either s form by normalizing first
+            let normalized = signature.normalize_s().unwrap_or(*signature);
+            verifying_key.verify_prehash(prehash, &normalized)
+        }
+    }
+}
+
+/// Strict (low-S enforcing) and lenient prehash verification.
+#[cfg(feature = "ecdsa")]
+pub trait VerifyPrehashStrictExt {
+    /// Verify, rejecting high-S signatures. This is the same behavior as
+    /// the default [`PrehashVerifier`] impl, provided under an explicit
+    /// name so call sites can state their policy.
+    ///
+    /// [`PrehashVerifier`]: ecdsa_core::signature::hazmat::PrehashVerifier
+    fn verify_prehash_strict(&self, prehash: &[u8], signature: &Signature) -> Result<(), Error>;
+
+    /// Verify, accepting both the low-S and high-S forms.
+    fn verify_prehash_lenient(&self, prehash: &[u8], signature: &Signature) -> Result<(), Error>;
+}
+
+#[cfg(feature = "ecdsa")]
+impl VerifyPrehashStrictExt for VerifyingKey {
+    fn verify_prehash_strict(&self, prehash: &[u8], signature: &Signature) -> Result<(), Error> {
+        VerifyingOptions::default().verify_prehash(self, prehash, signature)
+    }
+
+    fn verify_prehash_lenient(&self, prehash: &[u8], signature: &Signature) -> Result<(), Error> {
+        VerifyingOptions {
+            require_low_s: false,
+        }
+        .verify_prehash(self, prehash, signature)
+    }
+}
+
+/// An ECDSA/secp256k1 signature paired with its [`RecoveryId`].
+///
+/// Serializes as 65 bytes `r || s || recovery_id` in binary formats and as
+/// hex in human-readable formats (via `serdect`). Deserialization rejects
+/// recovery IDs >= 4 and non-canonical `r`/`s` components.
+#[cfg(feature = "ecdsa")]
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct RecoverableSignature {
+    signature: Signature,
+    recovery_id: RecoveryId,
+}
+
+#[cfg(feature = "ecdsa")]
+impl RecoverableSignature {
+    /// Byte length of the serialized form.
+    pub const BYTE_SIZE: usize = 65;
+
+    /// Create from a signature and recovery ID.
+    pub fn new(signature: Signature, recovery_id: RecoveryId) -> Self {
+        Self {
+            signature,
+            recovery_id,
+        }
+    }
+
+    /// The inner signature.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// The recovery ID.
+    pub fn recovery_id(&self) -> RecoveryId {
+        self.recovery_id
+    }
+
+    /// Recover the [`VerifyingKey`] from a 32-byte prehash.
+    pub fn recover_from_prehash(&self, prehash: &[u8]) -> Result<VerifyingKey, Error> {
+        VerifyingKey::recover_from_prehash(prehash, &self.signature, self.recovery_id)
+    }
+
+    /// Serialize as `r || s || recovery_id`.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        let mut out = [0u8; Self::BYTE_SIZE];
+        out[..64].copy_from_slice(&self.signature.to_bytes());
+        out[64] = self.recovery_id.to_byte();
+        out
+    }
+
+    /// Parse from `r || s || recovery_id` bytes, rejecting recovery IDs
+    /// out of the `0..=3` range and non-canonical scalar components.
+    pub fn from_bytes(bytes: &[u8; Self::BYTE_SIZE]) -> Result<Self, Error> {
+        let signature = Signature::from_slice(&bytes[..64])?;
+        let recovery_id = RecoveryId::from_byte(bytes[64]).ok_or_else(Error::new)?;
+
+        Ok(Self {
+            signature,
+            recovery_id,
+        })
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "serde"))]
+impl serdect::serde::Serialize for RecoverableSignature {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serdect::serde::Serializer,
+    {
+        serdect::array::serialize_hex_upper_or_bin(&self.to_bytes(), serializer)
+    }
+}
+
+#[cfg(all(feature = "ecdsa", feature = "serde"))]
+impl<'de> serdect::serde::Deserialize<'de> for RecoverableSignature {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serdect::serde::Deserializer<'de>,
+    {
+        use serdect::serde::de::Error as _;
+
+        let mut bytes = [0u8; Self::BYTE_SIZE];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+        Self::from_bytes(&bytes).map_err(D::Error::custom)
+    }
+}
+
+#[cfg(all(test, feature = "ecdsa", feature = "alloc"))]
+#[allow(clippy::unwrap_used)]
+mod additive_sharing_tests {
+    use super::{AdditiveSharing, SigningKey, VerifyingKey};
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn split_and_recombine() {
+        let signing_key = SigningKey::random(&mut OsRng);
+
+        for n in [1usize, 2, 16] {
+            let shares = signing_key.split_additive(n, &mut OsRng).unwrap();
+            assert_eq!(shares.len(), n);
+
+            let recombined = SigningKey::from_additive_shares(&shares).unwrap();
+            assert_eq!(recombined.to_bytes(), signing_key.to_bytes());
+
+            // public aggregation matches the original verifying key
+            let publics: alloc::vec::Vec<VerifyingKey> = shares
+                .iter()
+                .map(|share| *SigningKey::from(*share).verifying_key())
+                .collect();
+            assert_eq!(
+                <SigningKey as AdditiveSharing>::from_public_shares(&publics).unwrap(),
+                *signing_key.verifying_key()
+            );
+        }
+    }
+
+    #[test]
+    fn degenerate_inputs_rejected() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        assert!(signing_key.split_additive(0, &mut OsRng).is_err());
+        assert!(SigningKey::from_additive_shares(&[]).is_err());
+        assert!(<SigningKey as AdditiveSharing>::from_public_shares(&[]).is_err());
+
+        // identity sum rejection: P + (-P)
+        let share = crate::NonZeroScalar::random(&mut OsRng);
+        let neg = crate::NonZeroScalar::new(-*share).unwrap();
+        let publics = [
+            *SigningKey::from(share).verifying_key(),
+            *SigningKey::from(neg).verifying_key(),
+        ];
+        assert!(<SigningKey as AdditiveSharing>::from_public_shares(&publics).is_err());
+    }
+}
+
+#[cfg(all(test, feature = "ecdsa", feature = "sha3"))]
+#[allow(clippy::unwrap_used)]
+mod recoverable_digest_tests {
+    use super::{RandomizedRecoverableSigner, RecoveryId, SigningKey, VerifyingKey};
+    use elliptic_curve::rand_core::OsRng;
+    use sha2::Sha256;
+    use sha3::{Digest, Keccak256, Sha3_256};
+
+    #[test]
+    fn recovery_roundtrip_across_digests() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let msg = b"digest-agnostic recovery";
+
+        // SHA-256
+        let (sig, recid) = signing_key
+            .sign_digest_recoverable(Sha256::new_with_prefix(msg))
+            .unwrap();
+        assert_eq!(
+            VerifyingKey::recover_from_digest(Sha256::new_with_prefix(msg), &sig, recid).unwrap(),
+            *signing_key.verifying_key()
+        );
+
+        // Keccak-256
+        let (sig, recid) = signing_key
+            .sign_digest_recoverable(Keccak256::new_with_prefix(msg))
+            .unwrap();
+        assert_eq!(
+            VerifyingKey::recover_from_digest(Keccak256::new_with_prefix(msg), &sig, recid)
+                .unwrap(),
+            *signing_key.verifying_key()
+        );
+
+        // SHA3-256
+        let (sig, recid) = signing_key
+            .sign_digest_recoverable(Sha3_256::new_with_prefix(msg))
+            .unwrap();
+        assert_eq!(
+            VerifyingKey::recover_from_digest(Sha3_256::new_with_prefix(msg), &sig, recid).unwrap(),
+            *signing_key.verifying_key()
+        );
+    }
+
+    #[test]
+    fn randomized_recoverable_signing() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let digest = Keccak256::new_with_prefix(b"randomized");
+
+        let (sig1, recid1) = signing_key
+            .sign_digest_recoverable_with_rng(&mut OsRng, digest.clone())
+            .unwrap();
+        let (sig2, _) = signing_key
+            .sign_digest_recoverable_with_rng(&mut OsRng, digest.clone())
+            .unwrap();
+
+        // entropy makes signatures differ, but both recover correctly
+        assert_ne!(sig1, sig2);
+        assert_eq!(
+            VerifyingKey::recover_from_digest(digest, &sig1, recid1).unwrap(),
+            *signing_key.verifying_key()
+        );
+    }
+
+    #[test]
+    fn recovery_id_distinguishes_r_parity() {
+        // flipping the recovery ID's parity bit recovers a different key
+        // (the +/-R ambiguity), and honest signatures never set the
+        // reduced-x bit (r >= n requires a ~2^-127 fluke)
+        let signing_key = SigningKey::random(&mut OsRng);
+        let prehash = [0x31u8; 32];
+        let (sig, recid) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+        assert!(!recid.is_x_reduced());
+
+        let flipped = RecoveryId::new(!recid.is_y_odd(), recid.is_x_reduced());
+        if let Ok(other) = VerifyingKey::recover_from_prehash(&prehash, &sig, flipped) {
+            assert_ne!(other, *signing_key.verifying_key());
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ecdsa"))]
+#[allow(clippy::unwrap_used)]
+mod low_s_tests {
+    use super::{
+        RecoveryId, Signature, SignatureNormalization, SigningKey, VerifyPrehashStrictExt,
+        VerifyingKey, VerifyingOptions,
+    };
+    use ecdsa_core::signature::hazmat::PrehashSigner;
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn strict_and_lenient_policies() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = *signing_key.verifying_key();
+        let prehash = [0x5au8; 32];
+
+        let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+        assert!(signature.is_normalized());
+        assert!(!signature.is_high());
+
+        // construct the malleated high-S form
+        let high = Signature::from_scalars(*signature.r(), -*signature.s()).unwrap();
+        assert!(high.is_high());
+        assert!(!high.is_normalized());
+
+        // lenient accepts high-S, strict rejects it
+        verifying_key
+            .verify_prehash_lenient(&prehash, &high)
+            .unwrap();
+        assert!(verifying_key
+            .verify_prehash_strict(&prehash, &high)
+            .is_err());
+
+        // after normalization the strict path accepts
+        let normalized = high.normalize_s().unwrap();
+        verifying_key
+            .verify_prehash_strict(&prehash, &normalized)
+            .unwrap();
+
+        // options wrapper equals the extension methods
+        assert!(VerifyingOptions::default()
+            .verify_prehash(&verifying_key, &prehash, &high)
+            .is_err());
+        VerifyingOptions {
+            require_low_s: false,
+        }
+        .verify_prehash(&verifying_key, &prehash, &high)
+        .unwrap();
+    }
+
+    #[test]
+    fn recovery_of_high_s_inputs() {
+        // recover_from_prehash rejects high-S inputs: k256 signatures are
+        // low-S normalized at signing time (with the recovery ID flipped
+        // to match), so a high-S signature never corresponds to a valid
+        // recovery ID produced by this crate.
+        let signing_key = SigningKey::random(&mut OsRng);
+        let prehash = [0x77u8; 32];
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+
+        assert_eq!(
+            VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id).unwrap(),
+            *signing_key.verifying_key()
+        );
+
+        let high = Signature::from_scalars(*signature.r(), -*signature.s()).unwrap();
+        for recid in 0u8..4 {
+            let recid = RecoveryId::from_byte(recid).unwrap();
+            if let Ok(recovered) = VerifyingKey::recover_from_prehash(&prehash, &high, recid) {
+                assert_ne!(recovered, *signing_key.verifying_key());
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "ecdsa", feature = "serde"))]
+#[allow(clippy::unwrap_used)]
+mod recoverable_serde_tests {
+    use super::{RecoverableSignature, SigningKey};
+    use ecdsa_core::signature::hazmat::PrehashSigner;
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn roundtrip_and_recovery() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let prehash = [7u8; 32];
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+        let recoverable = RecoverableSignature::new(signature, recovery_id);
+
+        // JSON (hex)
+        let json = serde_json::to_string(&recoverable).unwrap();
+        let parsed: RecoverableSignature = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, recoverable);
+
+        // binary (CBOR as a stand-in for bincode-style formats)
+        let mut cbor = alloc::vec::Vec::new();
+        ciborium::ser::into_writer(&recoverable, &mut cbor).unwrap();
+        let parsed: RecoverableSignature = ciborium::de::from_reader(cbor.as_slice()).unwrap();
+        assert_eq!(parsed, recoverable);
+
+        // recovery works after deserialization
+        assert_eq!(
+            &parsed.recover_from_prehash(&prehash).unwrap(),
+            signing_key.verifying_key()
+        );
+    }
+
+    #[test]
+    fn invalid_encodings_rejected() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&[1u8; 32]).unwrap();
+        let mut bytes = RecoverableSignature::new(signature, recovery_id).to_bytes();
+
+        // recid >= 4
+        bytes[64] = 4;
+        assert!(RecoverableSignature::from_bytes(&bytes).is_err());
+
+        // non-canonical s (s = 0)
+        bytes[64] = 0;
+        bytes[32..64].fill(0);
+        assert!(RecoverableSignature::from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn prehash_signer_sanity() {
+        // the plain PrehashSigner and the recoverable path agree on (r, s)
+        let signing_key = SigningKey::random(&mut OsRng);
+        let prehash = [3u8; 32];
+        let plain: super::Signature = signing_key.sign_prehash(&prehash).unwrap();
+        let (recoverable, _) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+        assert_eq!(plain, recoverable);
+    }
+}
+
+/// `DigestSigner`/`DigestVerifier` are generic over any 32-byte-output
+/// digest, so enabling the `sha3` feature is all that's needed to sign with
+/// Keccak-256: RFC 6979 nonce derivation operates on the 32-byte prehash
+/// and is digest-agnostic at this output size.
+#[cfg(all(test, feature = "ecdsa", feature = "sha3"))]
+#[allow(clippy::unwrap_used)]
+mod keccak256_tests {
+    use super::{Signature, SigningKey};
+    use ecdsa_core::signature::{hazmat::PrehashSigner, DigestSigner, DigestVerifier};
+    use sha3::{Digest, Keccak256};
+
+    #[test]
+    fn keccak256_digest_signing_roundtrip() {
+        let signing_key = SigningKey::from_bytes(&[1u8; 32].into()).unwrap();
+        let digest = Keccak256::new_with_prefix(b"Keccak-256 signed message");
+
+        let signature: Signature = signing_key.sign_digest(digest.clone());
+        signing_key
+            .verifying_key()
+            .verify_digest(digest.clone(), &signature)
+            .unwrap();
+
+        // deterministic (RFC 6979): same digest, same signature
+        let again: Signature = signing_key.sign_digest(digest.clone());
+        assert_eq!(signature, again);
+
+        // equals the prehash path over the finalized digest
+        let prehash: [u8; 32] = Keccak256::digest(b"Keccak-256 signed message").into();
+        let via_prehash: Signature = signing_key.sign_prehash(&prehash).unwrap();
+        assert_eq!(signature, via_prehash);
+
+        // a different digest verifies differently
+        let other = Keccak256::new_with_prefix(b"other message");
+        assert!(signing_key
+            .verifying_key()
+            .verify_digest(other, &signature)
+            .is_err());
+    }
+}
+
+#[cfg(all(test, feature = "ecdsa", feature = "arithmetic"))]
+#[allow(clippy::unwrap_used)]
+mod batch_tests {
+    use super::{batch_verify, RecoveryId, Signature, SigningKey, VerifyingKey};
+    use ecdsa_core::signature::hazmat::PrehashSigner;
+    use elliptic_curve::rand_core::OsRng;
+
+    fn signed_batch(n: usize) -> alloc::vec::Vec<(VerifyingKey, [u8; 32], Signature, RecoveryId)> {
+        (0..n)
+            .map(|i| {
+                let sk = SigningKey::random(&mut OsRng);
+                let mut prehash = [0u8; 32];
+                prehash[..8].copy_from_slice(&(i as u64).to_be_bytes());
+                let (sig, recid) = sk.sign_prehash_recoverable(&prehash).unwrap();
+                (*sk.verifying_key(), prehash, sig, recid)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_batch_verifies() {
+        let items = signed_batch(64);
+        batch_verify(&items, &mut OsRng).unwrap();
+        batch_verify(&[], &mut OsRng).unwrap();
+
+        // sanity: sign_prehash_recoverable's recovery IDs round-trip
+        let (vk, prehash, sig, recid) = items[0];
+        assert_eq!(
+            VerifyingKey::recover_from_prehash(&prehash, &sig, recid).unwrap(),
+            vk
+        );
+    }
+
+    #[test]
+    fn corrupted_item_identified() {
+        let mut items = signed_batch(256);
+        items[123].1[0] ^= 1;
+
+        let err = batch_verify(&items, &mut OsRng).unwrap_err();
+        assert_eq!(err.invalid_index, Some(123));
+    }
+
+    #[test]
+    fn high_s_rejected_like_sequential_verify() {
+        use elliptic_curve::scalar::IsHigh;
+
+        let mut items = signed_batch(2);
+        let (_, _, sig, _) = items[1];
+
+        // produce the high-s malleated form
+        let high = Signature::from_scalars(*sig.r(), -*sig.s()).unwrap();
+        assert!(bool::from(high.s().is_high()));
+        items[1].2 = high;
+        items[1].3 = RecoveryId::new(!items[1].3.is_y_odd(), items[1].3.is_x_reduced());
+
+        let err = batch_verify(&items, &mut OsRng).unwrap_err();
+        assert_eq!(err.invalid_index, Some(1));
+    }
+
+    #[test]
+    fn wrong_recovery_id_fails() {
+        let mut items = signed_batch(4);
+        let recid = items[2].3;
+        items[2].3 = RecoveryId::new(!recid.is_y_odd(), recid.is_x_reduced());
+
+        // the batch equation fails, and since the underlying signature is
+        // still valid the fallback reports no specific index
+        let err = batch_verify(&items, &mut OsRng).unwrap_err();
+        assert_eq!(err.invalid_index, None);
+    }
+
+    #[test]
+    fn prehash_signer_consistency() {
+        // batch_verify agrees with regular verification
+        let sk = SigningKey::random(&mut OsRng);
+        let prehash = [7u8; 32];
+        let sig: Signature = sk.sign_prehash(&prehash).unwrap();
+        let (rsig, recid) = sk.sign_prehash_recoverable(&prehash).unwrap();
+        assert_eq!(sig, rsig);
+        batch_verify(&[(*sk.verifying_key(), prehash, rsig, recid)], &mut OsRng).unwrap();
+    }
+}
+
 #[cfg(all(test, feature = "ecdsa", feature = "arithmetic"))]
 mod tests {
     mod normalize {
@@ -0,0 +1,381 @@
+//! ECDSA adaptor signatures (one-time verifiably encrypted signatures).
+//!
+//! Follows the construction from the dlcspecs "ecdsa-adaptor" document: an
+//! adaptor signature over message `m` encrypted to point `Y = y*G` consists
+//! of `R = k*Y`, `R̂ = k*G`, `s' = k⁻¹(m + r*x)` with `r = x(R)`, and a
+//! DLEQ proof that `R̂` and `R` share the discrete logarithm `k` with
+//! respect to `G` and `Y`. Anyone can verify the encrypted signature
+//! against the signer's public key; knowledge of `y` decrypts it into a
+//! standard ECDSA signature, and the pair of encrypted/decrypted
+//! signatures reveals `y`.
+//!
+//! Serialized adaptor signatures are 162 bytes:
+//! `R (33) || R̂ (33) || s' (32) || DLEQ e (32) || DLEQ s (32)`.
+
+use super::{Signature, VerifyingKey};
+use crate::{AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar, U256};
+use elliptic_curve::{
+    bigint::ArrayEncoding,
+    group::GroupEncoding,
+    ops::{LinearCombination, Reduce},
+    point::AffineCoordinates,
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Curve, FieldBytesEncoding, PrimeField,
+};
+use sha2::{Digest, Sha256};
+use signature::{Error, Result};
+
+/// Domain separator for the DLEQ challenge transcript.
+const DLEQ_TAG: &[u8] = b"ECDSAadaptorSig/DLEQ";
+
+/// Length of a serialized adaptor signature.
+pub const ADAPTOR_SIGNATURE_LEN: usize = 162;
+
+/// An ECDSA adaptor signature: a signature over a message encrypted to an
+/// adaptor ("encryption") point.
+#[derive(Copy, Clone)]
+pub struct AdaptorSignature {
+    /// `R = k*Y`: the nonce point under the encryption key.
+    r: AffinePoint,
+
+    /// `R̂ = k*G`: the nonce point under the generator.
+    r_hat: AffinePoint,
+
+    /// `s' = k⁻¹(m + r*x)`.
+    s_adaptor: NonZeroScalar,
+
+    /// DLEQ challenge.
+    dleq_e: Scalar,
+
+    /// DLEQ response.
+    dleq_s: Scalar,
+}
+
+impl PartialEq for AdaptorSignature {
+    fn eq(&self, other: &Self) -> bool {
+        self.to_bytes() == other.to_bytes()
+    }
+}
+
+impl Eq for AdaptorSignature {}
+
+impl core::fmt::Debug for AdaptorSignature {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "AdaptorSignature({:02x?})", &self.to_bytes()[..])
+    }
+}
+
+/// Compute the DLEQ challenge for instance `(G, R̂, Y, R)` with commitments
+/// `(A1, A2)`.
+fn dleq_challenge(
+    r_hat: &AffinePoint,
+    y: &AffinePoint,
+    r: &AffinePoint,
+    a1: &AffinePoint,
+    a2: &AffinePoint,
+) -> Scalar {
+    let tag = Sha256::digest(DLEQ_TAG);
+    <Scalar as Reduce<U256>>::reduce_bytes(
+        &Sha256::new()
+            .chain_update(tag)
+            .chain_update(tag)
+            .chain_update(ProjectivePoint::from(*r_hat).to_bytes())
+            .chain_update(ProjectivePoint::from(*y).to_bytes())
+            .chain_update(ProjectivePoint::from(*r).to_bytes())
+            .chain_update(ProjectivePoint::from(*a1).to_bytes())
+            .chain_update(ProjectivePoint::from(*a2).to_bytes())
+            .finalize(),
+    )
+}
+
+impl AdaptorSignature {
+    /// Produce an adaptor signature ("encrypt"): sign `msg_digest` with the
+    /// given secret key, encrypted to `encryption_key`.
+    pub fn encrypt(
+        secret_key: &NonZeroScalar,
+        encryption_key: &PublicKey,
+        msg_digest: &[u8; 32],
+    ) -> Result<Self> {
+        let y = ProjectivePoint::from(encryption_key.as_affine());
+
+        // deterministic nonce bound to the secret key, message, and
+        // encryption point
+        let k_bytes = rfc6979::generate_k::<Sha256, _>(
+            &secret_key.to_bytes(),
+            &<U256 as FieldBytesEncoding<crate::Secp256k1>>::encode_field_bytes(
+                &crate::Secp256k1::ORDER,
+            ),
+            FieldBytes::from_slice(msg_digest),
+            encryption_key.to_encoded_point(true).as_bytes(),
+        );
+        let k = <Scalar as Reduce<U256>>::reduce(U256::from_be_byte_array(k_bytes));
+        let k = Option::<NonZeroScalar>::from(NonZeroScalar::new(k)).ok_or_else(Error::new)?;
+
+        let r_hat = (ProjectivePoint::GENERATOR * *k).to_affine();
+        let r_point = (y * *k).to_affine();
+
+        let r = <Scalar as Reduce<U256>>::reduce_bytes(&r_point.x());
+        if bool::from(r.is_zero()) {
+            return Err(Error::new());
+        }
+
+        let m = <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(msg_digest));
+        let k_inv = <NonZeroScalar as elliptic_curve::ops::Invert>::invert(&k);
+        let s_adaptor = *k_inv * (m + r * secret_key.as_ref());
+        let s_adaptor =
+            Option::<NonZeroScalar>::from(NonZeroScalar::new(s_adaptor)).ok_or_else(Error::new)?;
+
+        // DLEQ proof that log_G(R̂) == log_Y(R) == k
+        let nonce_bytes = rfc6979::generate_k::<Sha256, _>(
+            &k.to_bytes(),
+            &<U256 as FieldBytesEncoding<crate::Secp256k1>>::encode_field_bytes(
+                &crate::Secp256k1::ORDER,
+            ),
+            FieldBytes::from_slice(msg_digest),
+            b"DLEQ",
+        );
+        let u = <Scalar as Reduce<U256>>::reduce(U256::from_be_byte_array(nonce_bytes));
+
+        let a1 = (ProjectivePoint::GENERATOR * u).to_affine();
+        let a2 = (y * u).to_affine();
+        let e = dleq_challenge(&r_hat, encryption_key.as_affine(), &r_point, &a1, &a2);
+        let s_dleq = u + e * *k;
+
+        Ok(Self {
+            r: r_point,
+            r_hat,
+            s_adaptor,
+            dleq_e: e,
+            dleq_s: s_dleq,
+        })
+    }
+
+    /// Verify this adaptor signature against the signer's verifying key,
+    /// the encryption key, and the message digest.
+    pub fn verify(
+        &self,
+        verifying_key: &VerifyingKey,
+        encryption_key: &PublicKey,
+        msg_digest: &[u8; 32],
+    ) -> Result<()> {
+        let y = ProjectivePoint::from(encryption_key.as_affine());
+
+        // DLEQ: A1 = s*G - e*R̂, A2 = s*Y - e*R; recompute e
+        let a1 = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &self.dleq_s,
+            &ProjectivePoint::from(self.r_hat),
+            &-self.dleq_e,
+        )
+        .to_affine();
+        let a2 = ProjectivePoint::lincomb(
+            &y,
+            &self.dleq_s,
+            &ProjectivePoint::from(self.r),
+            &-self.dleq_e,
+        )
+        .to_affine();
+
+        let e = dleq_challenge(&self.r_hat, encryption_key.as_affine(), &self.r, &a1, &a2);
+        if e != self.dleq_e {
+            return Err(Error::new());
+        }
+
+        // ECDSA pre-verification: (m/s')*G + (r/s')*X == R̂
+        let m = <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(msg_digest));
+        let r = <Scalar as Reduce<U256>>::reduce_bytes(&self.r.x());
+        let s_inv =
+            *<NonZeroScalar as elliptic_curve::ops::Invert>::invert_vartime(&self.s_adaptor);
+
+        let expected = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &(m * s_inv),
+            &ProjectivePoint::from(*verifying_key.as_affine()),
+            &(r * s_inv),
+        );
+
+        if expected.to_affine() == self.r_hat {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+
+    /// Decrypt this adaptor signature into a standard ECDSA signature
+    /// using the adaptor secret `y`.
+    pub fn decrypt(&self, decryption_key: &NonZeroScalar) -> Result<Signature> {
+        let y_inv = <NonZeroScalar as elliptic_curve::ops::Invert>::invert(decryption_key);
+        let s = *self.s_adaptor.as_ref() * *y_inv;
+        let r = <Scalar as Reduce<U256>>::reduce_bytes(&self.r.x());
+
+        let signature = Signature::from_scalars(r, s).map_err(|_| Error::new())?;
+        Ok(signature.normalize_s().unwrap_or(signature))
+    }
+
+    /// Recover the adaptor secret from this adaptor signature and its
+    /// decrypted counterpart, checking the result against the encryption
+    /// key.
+    pub fn recover(
+        &self,
+        encryption_key: &PublicKey,
+        signature: &Signature,
+    ) -> Result<NonZeroScalar> {
+        let (_, s) = signature.split_scalars();
+        let s_inv = <NonZeroScalar as elliptic_curve::ops::Invert>::invert_vartime(&s);
+        let y = *self.s_adaptor.as_ref() * *s_inv;
+
+        for candidate in [y, -y] {
+            if let Some(candidate) = Option::<NonZeroScalar>::from(NonZeroScalar::new(candidate)) {
+                if PublicKey::from_secret_scalar(&candidate) == *encryption_key {
+                    return Ok(candidate);
+                }
+            }
+        }
+
+        Err(Error::new())
+    }
+
+    /// Serialize as 162 bytes: `R || R̂ || s' || e || s`.
+    pub fn to_bytes(&self) -> [u8; ADAPTOR_SIGNATURE_LEN] {
+        let mut out = [0u8; ADAPTOR_SIGNATURE_LEN];
+        out[..33].copy_from_slice(&ProjectivePoint::from(self.r).to_bytes());
+        out[33..66].copy_from_slice(&ProjectivePoint::from(self.r_hat).to_bytes());
+        out[66..98].copy_from_slice(&self.s_adaptor.to_bytes());
+        out[98..130].copy_from_slice(&self.dleq_e.to_bytes());
+        out[130..].copy_from_slice(&self.dleq_s.to_bytes());
+        out
+    }
+
+    /// Parse from the 162-byte encoding.
+    pub fn from_bytes(bytes: &[u8; ADAPTOR_SIGNATURE_LEN]) -> Result<Self> {
+        let parse_point = |slice: &[u8]| -> Result<AffinePoint> {
+            let encoded = crate::EncodedPoint::from_bytes(slice).map_err(|_| Error::new())?;
+            Option::from(AffinePoint::from_encoded_point(&encoded)).ok_or_else(Error::new)
+        };
+
+        let r = parse_point(&bytes[..33])?;
+        let r_hat = parse_point(&bytes[33..66])?;
+        let s_adaptor = NonZeroScalar::try_from(&bytes[66..98]).map_err(|_| Error::new())?;
+        let dleq_e = Option::from(Scalar::from_repr(FieldBytes::clone_from_slice(
+            &bytes[98..130],
+        )))
+        .ok_or_else(Error::new)?;
+        let dleq_s = Option::from(Scalar::from_repr(FieldBytes::clone_from_slice(
+            &bytes[130..],
+        )))
+        .ok_or_else(Error::new)?;
+
+        Ok(Self {
+            r,
+            r_hat,
+            s_adaptor,
+            dleq_e,
+            dleq_s,
+        })
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::AdaptorSignature;
+    use crate::{
+        ecdsa::{signature::hazmat::PrehashVerifier, SigningKey, VerifyingKey},
+        NonZeroScalar, PublicKey,
+    };
+    use elliptic_curve::rand_core::OsRng;
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn encrypt_verify_decrypt_recover() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let y = NonZeroScalar::random(&mut OsRng);
+        let encryption_key = PublicKey::from_secret_scalar(&y);
+        let msg_digest: [u8; 32] = Sha256::digest(b"DLC outcome").into();
+
+        let adaptor = AdaptorSignature::encrypt(
+            signing_key.as_nonzero_scalar(),
+            &encryption_key,
+            &msg_digest,
+        )
+        .unwrap();
+
+        adaptor
+            .verify(&verifying_key, &encryption_key, &msg_digest)
+            .unwrap();
+
+        // decryption yields a standard, valid ECDSA signature
+        let signature = adaptor.decrypt(&y).unwrap();
+        verifying_key
+            .verify_prehash(&msg_digest, &signature)
+            .unwrap();
+
+        // the secret is recoverable from the pair
+        let recovered = adaptor.recover(&encryption_key, &signature).unwrap();
+        assert_eq!(recovered.to_bytes(), y.to_bytes());
+
+        // serialization round trip at the specified length
+        let bytes = adaptor.to_bytes();
+        assert_eq!(bytes.len(), 162);
+        assert_eq!(AdaptorSignature::from_bytes(&bytes).unwrap(), adaptor);
+    }
+
+    #[test]
+    fn corrupted_proof_rejected() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let verifying_key = VerifyingKey::from(&signing_key);
+        let y = NonZeroScalar::random(&mut OsRng);
+        let encryption_key = PublicKey::from_secret_scalar(&y);
+        let msg_digest: [u8; 32] = Sha256::digest(b"tamper").into();
+
+        let adaptor = AdaptorSignature::encrypt(
+            signing_key.as_nonzero_scalar(),
+            &encryption_key,
+            &msg_digest,
+        )
+        .unwrap();
+
+        // corrupt each component in turn
+        for offset in [0usize, 40, 70, 100, 135] {
+            let mut bytes = adaptor.to_bytes();
+            bytes[offset + 1] ^= 1;
+            if let Ok(bad) = AdaptorSignature::from_bytes(&bytes) {
+                assert!(
+                    bad.verify(&verifying_key, &encryption_key, &msg_digest)
+                        .is_err(),
+                    "corruption at offset {offset} not detected"
+                );
+            }
+        }
+
+        // wrong message and wrong encryption key fail
+        assert!(adaptor
+            .verify(&verifying_key, &encryption_key, &[0u8; 32])
+            .is_err());
+        let other = PublicKey::from_secret_scalar(&NonZeroScalar::random(&mut OsRng));
+        assert!(adaptor.verify(&verifying_key, &other, &msg_digest).is_err());
+    }
+
+    #[test]
+    fn wrong_decryption_key_recoverable_mismatch() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let y = NonZeroScalar::random(&mut OsRng);
+        let encryption_key = PublicKey::from_secret_scalar(&y);
+        let msg_digest: [u8; 32] = Sha256::digest(b"wrong key").into();
+
+        let adaptor = AdaptorSignature::encrypt(
+            signing_key.as_nonzero_scalar(),
+            &encryption_key,
+            &msg_digest,
+        )
+        .unwrap();
+
+        // decrypting with the wrong secret yields an invalid signature
+        let wrong = NonZeroScalar::random(&mut OsRng);
+        let signature = adaptor.decrypt(&wrong).unwrap();
+        assert!(VerifyingKey::from(&signing_key)
+            .verify_prehash(&msg_digest, &signature)
+            .is_err());
+    }
+}
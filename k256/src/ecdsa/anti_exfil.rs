@@ -0,0 +1,262 @@
+//! Anti-exfiltration ("sign-to-contract") nonce commitments for ECDSA.
+//!
+//! A compromised signer (e.g. malicious hardware wallet firmware) can leak
+//! key bits by grinding its nonce choice. The sign-to-contract protocol
+//! prevents this by letting the host contribute randomness to the nonce
+//! *after* the signer has committed to its own share:
+//!
+//! 1. The host picks randomness `rho` and asks the signer to commit.
+//! 2. The signer derives its nonce `k0` and returns the commitment
+//!    `R0 = k0*G` ([`SignerSession::commit_nonce`]).
+//! 3. The host reveals `rho`; the signer computes the tweak
+//!    `t = H(R0 || rho)` and signs with nonce `k = k0 + t`
+//!    ([`SignerSession::sign_with_host_randomness`]).
+//! 4. The host verifies the final (standard) signature and checks that its
+//!    nonce point equals `R0 + t*G` ([`verify_exfil_protection`]), proving
+//!    the signer could not have chosen the effective nonce freely.
+
+use super::{hazmat::SignPrimitive, Signature, SigningKey, VerifyingKey};
+use crate::{AffinePoint, CompressedPoint, FieldBytes, ProjectivePoint, Scalar, Secp256k1, U256};
+use ecdsa_core::signature::hazmat::PrehashVerifier;
+use elliptic_curve::{
+    bigint::ArrayEncoding,
+    group::GroupEncoding,
+    ops::{Invert, LinearCombination, Reduce},
+    point::AffineCoordinates,
+    sec1::FromEncodedPoint,
+    subtle::CtOption,
+    Curve, FieldBytesEncoding,
+};
+use sha2::{Digest, Sha256};
+use signature::{Error, Result};
+
+/// Domain separator for the nonce tweak hash.
+const TWEAK_DOMAIN: &[u8] = b"k256/anti-exfil/tweak";
+
+/// The signer's nonce commitment `R0 = k0*G`, sent to the host before it
+/// reveals its randomness.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct NonceCommitment(ProjectivePoint);
+
+impl NonceCommitment {
+    /// Serialize as a compressed SEC1 point.
+    pub fn to_bytes(&self) -> CompressedPoint {
+        self.0.to_bytes()
+    }
+
+    /// Parse from a compressed SEC1 point.
+    pub fn from_bytes(bytes: &CompressedPoint) -> Result<Self> {
+        let encoded = crate::EncodedPoint::from_bytes(bytes).map_err(|_| Error::new())?;
+        Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+            .map(|point| Self(ProjectivePoint::from(point)))
+            .ok_or_else(Error::new)
+    }
+}
+
+/// Signer-side state between nonce commitment and signing.
+pub struct SignerSession {
+    signing_key: SigningKey,
+    prehash: [u8; 32],
+    k0: Scalar,
+    commitment: NonceCommitment,
+}
+
+impl SignerSession {
+    /// Derive the signer's nonce share over the message and the host's
+    /// commitment, returning the session and the nonce commitment `R0`.
+    ///
+    /// `host_commitment` binds the nonce derivation to the host's intent
+    /// (e.g. the hash of `rho`), so a replayed session cannot reuse `k0`
+    /// for a different host contribution.
+    pub fn commit_nonce(
+        signing_key: &SigningKey,
+        prehash: &[u8; 32],
+        host_commitment: &[u8; 32],
+    ) -> Result<(Self, NonceCommitment)> {
+        // RFC 6979 nonce with the host commitment as additional data
+        let k0_bytes = rfc6979::generate_k::<Sha256, _>(
+            &signing_key.as_nonzero_scalar().to_bytes(),
+            &<U256 as FieldBytesEncoding<Secp256k1>>::encode_field_bytes(&Secp256k1::ORDER),
+            FieldBytes::from_slice(prehash),
+            host_commitment,
+        );
+        let k0 = <Scalar as Reduce<U256>>::reduce(U256::from_be_byte_array(k0_bytes));
+        if bool::from(k0.is_zero()) {
+            return Err(Error::new());
+        }
+
+        let commitment = NonceCommitment(ProjectivePoint::GENERATOR * k0);
+
+        Ok((
+            Self {
+                signing_key: signing_key.clone(),
+                prehash: *prehash,
+                k0,
+                commitment,
+            },
+            commitment,
+        ))
+    }
+
+    /// Complete the session: mix the revealed host randomness into the
+    /// nonce and produce a standard ECDSA signature.
+    pub fn sign_with_host_randomness(self, host_randomness: &[u8; 32]) -> Result<Signature> {
+        let t = nonce_tweak(&self.commitment, host_randomness);
+        let k = self.k0 + t;
+
+        let (signature, _) = self
+            .signing_key
+            .as_nonzero_scalar()
+            .as_ref()
+            .try_sign_prehashed(NonceWrapper(k), FieldBytes::from_slice(&self.prehash))?;
+
+        Ok(signature)
+    }
+}
+
+/// Host-side check that the signature's nonce point is `R0 + H(R0||rho)*G`.
+///
+/// Verifies the signature itself, reconstructs the nonce point from the
+/// verification equation, and compares its x-coordinate against the
+/// committed-plus-tweaked point (the y-coordinate is malleable because of
+/// low-s normalization).
+pub fn verify_exfil_protection(
+    verifying_key: &VerifyingKey,
+    prehash: &[u8; 32],
+    signature: &Signature,
+    commitment: &NonceCommitment,
+    host_randomness: &[u8; 32],
+) -> Result<()> {
+    verifying_key.verify_prehash(prehash, signature)?;
+
+    // reconstruct R = (z/s)*G + (r/s)*P
+    let z = <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(prehash));
+    let (r, s) = signature.split_scalars();
+    let s_inv = *s.invert_vartime();
+    let big_r = ProjectivePoint::lincomb(
+        &ProjectivePoint::GENERATOR,
+        &(z * s_inv),
+        &ProjectivePoint::from(*verifying_key.as_affine()),
+        &(*r * s_inv),
+    );
+
+    let t = nonce_tweak(commitment, host_randomness);
+    let expected = commitment.0 + ProjectivePoint::GENERATOR * t;
+
+    if big_r.to_affine().x() == expected.to_affine().x() {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// `t = H(domain || R0 || rho)` reduced mod n.
+fn nonce_tweak(commitment: &NonceCommitment, host_randomness: &[u8; 32]) -> Scalar {
+    <Scalar as Reduce<U256>>::reduce_bytes(
+        &Sha256::new()
+            .chain_update(TWEAK_DOMAIN)
+            .chain_update(commitment.to_bytes())
+            .chain_update(host_randomness)
+            .finalize(),
+    )
+}
+
+/// Adapter passing a precomputed nonce into `try_sign_prehashed`.
+struct NonceWrapper(Scalar);
+
+impl AsRef<Scalar> for NonceWrapper {
+    fn as_ref(&self) -> &Scalar {
+        &self.0
+    }
+}
+
+impl Invert for NonceWrapper {
+    type Output = CtOption<Scalar>;
+
+    fn invert(&self) -> CtOption<Scalar> {
+        self.0.invert()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{verify_exfil_protection, NonceCommitment, SignerSession};
+    use crate::ecdsa::{Signature, SigningKey};
+    use ecdsa_core::signature::hazmat::PrehashSigner;
+    use elliptic_curve::rand_core::{OsRng, RngCore};
+    use sha2::{Digest, Sha256};
+
+    #[test]
+    fn honest_signer_passes_host_check() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let prehash: [u8; 32] = Sha256::digest(b"sign-to-contract").into();
+
+        // host picks randomness and commits
+        let mut rho = [0u8; 32];
+        OsRng.fill_bytes(&mut rho);
+        let host_commitment: [u8; 32] = Sha256::digest(rho).into();
+
+        let (session, r0) =
+            SignerSession::commit_nonce(&signing_key, &prehash, &host_commitment).unwrap();
+
+        let signature = session.sign_with_host_randomness(&rho).unwrap();
+
+        // the output is a standard ECDSA signature
+        use ecdsa_core::signature::hazmat::PrehashVerifier;
+        signing_key
+            .verifying_key()
+            .verify_prehash(&prehash, &signature)
+            .unwrap();
+
+        verify_exfil_protection(signing_key.verifying_key(), &prehash, &signature, &r0, &rho)
+            .unwrap();
+
+        // commitment round-trips through its wire form
+        assert_eq!(NonceCommitment::from_bytes(&r0.to_bytes()).unwrap(), r0);
+    }
+
+    #[test]
+    fn malicious_signer_detected() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let prehash: [u8; 32] = Sha256::digest(b"exfil attempt").into();
+
+        let mut rho = [0u8; 32];
+        OsRng.fill_bytes(&mut rho);
+        let host_commitment: [u8; 32] = Sha256::digest(rho).into();
+
+        let (_session, r0) =
+            SignerSession::commit_nonce(&signing_key, &prehash, &host_commitment).unwrap();
+
+        // the signer ignores the host randomness and signs with its own
+        // freely chosen nonce
+        let rogue: Signature = signing_key.sign_prehash(&prehash).unwrap();
+
+        assert!(
+            verify_exfil_protection(signing_key.verifying_key(), &prehash, &rogue, &r0, &rho,)
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn wrong_host_randomness_rejected() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let prehash: [u8; 32] = Sha256::digest(b"wrong rho").into();
+
+        let rho = [0x11u8; 32];
+        let host_commitment: [u8; 32] = Sha256::digest(rho).into();
+
+        let (session, r0) =
+            SignerSession::commit_nonce(&signing_key, &prehash, &host_commitment).unwrap();
+        let signature = session.sign_with_host_randomness(&rho).unwrap();
+
+        assert!(verify_exfil_protection(
+            signing_key.verifying_key(),
+            &prehash,
+            &signature,
+            &r0,
+            &[0x22u8; 32],
+        )
+        .is_err());
+    }
+}
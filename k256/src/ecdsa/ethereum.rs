@@ -0,0 +1,198 @@
+//! Ethereum-flavored recoverable signature helpers.
+//!
+//! Ethereum serializes ECDSA signatures as 65 bytes `r || s || v`, where
+//! `v` encodes the [`RecoveryId`] in one of several flavors:
+//!
+//! - "raw" parity: `v ∈ {0, 1}`
+//! - legacy transactions / `eth_sign`: `v ∈ {27, 28}`
+//! - [EIP-155] replay-protected transactions: `v = chain_id * 2 + 35 + parity`
+//!
+//! [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+
+use super::{RecoveryId, Signature, VerifyingKey};
+use signature::{Error, Result};
+
+/// A 65-byte Ethereum-style signature: `r || s || v`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Signature65 {
+    /// The inner (r, s) signature.
+    signature: Signature,
+
+    /// The recovery ID (y parity; Ethereum never uses reduced x).
+    recovery_id: RecoveryId,
+}
+
+impl Signature65 {
+    /// Byte length of the serialized form.
+    pub const BYTE_SIZE: usize = 65;
+
+    /// Create from a signature and recovery ID.
+    pub fn new(signature: Signature, recovery_id: RecoveryId) -> Self {
+        Self {
+            signature,
+            recovery_id,
+        }
+    }
+
+    /// Parse from `r || s || v` bytes, accepting `v ∈ {0, 1, 27, 28}`.
+    ///
+    /// EIP-155 encodings carry `v` values which do not fit in one byte;
+    /// use [`Signature65::from_eip155_v`] for those.
+    pub fn from_bytes(bytes: &[u8; Self::BYTE_SIZE]) -> Result<Self> {
+        let signature = Signature::from_slice(&bytes[..64])?;
+        let parity = match bytes[64] {
+            v @ (0 | 1) => v,
+            v @ (27 | 28) => v - 27,
+            _ => return Err(Error::new()),
+        };
+
+        Ok(Self {
+            signature,
+            recovery_id: RecoveryId::new(parity == 1, false),
+        })
+    }
+
+    /// Serialize as `r || s || v` with the legacy `v ∈ {27, 28}` encoding.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        let mut out = [0u8; Self::BYTE_SIZE];
+        out[..64].copy_from_slice(&self.signature.to_bytes());
+        out[64] = 27 + u8::from(self.recovery_id.is_y_odd());
+        out
+    }
+
+    /// The inner `(r, s)` signature.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    /// The recovery ID.
+    pub fn recovery_id(&self) -> RecoveryId {
+        self.recovery_id
+    }
+
+    /// The [EIP-155] `v` value for the given chain ID:
+    /// `chain_id * 2 + 35 + parity`.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub fn to_eip155_v(&self, chain_id: u64) -> u64 {
+        chain_id * 2 + 35 + u64::from(self.recovery_id.is_y_odd())
+    }
+
+    /// Reconstruct from an `(r, s)` signature and an [EIP-155] `v` value,
+    /// returning the signature and the chain ID it committed to.
+    ///
+    /// Also accepts the pre-EIP-155 `v ∈ {27, 28}` form, reported as chain
+    /// ID zero.
+    ///
+    /// [EIP-155]: https://eips.ethereum.org/EIPS/eip-155
+    pub fn from_eip155_v(signature: Signature, v: u64) -> Result<(Self, u64)> {
+        let (parity, chain_id) = match v {
+            27 | 28 => (v - 27, 0),
+            v if v >= 35 => ((v - 35) % 2, (v - 35) / 2),
+            _ => return Err(Error::new()),
+        };
+
+        Ok((
+            Self {
+                signature,
+                recovery_id: RecoveryId::new(parity == 1, false),
+            },
+            chain_id,
+        ))
+    }
+}
+
+/// Recover the signer's [`VerifyingKey`] from a 32-byte prehash (typically
+/// a Keccak-256 transaction or message hash) and a 65-byte signature.
+pub fn recover_from_prehash_eth(
+    prehash: &[u8; 32],
+    signature: &Signature65,
+) -> Result<VerifyingKey> {
+    VerifyingKey::recover_from_prehash(prehash, &signature.signature, signature.recovery_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{recover_from_prehash_eth, Signature65};
+    use crate::ecdsa::{Signature, SigningKey};
+    use hex_literal::hex;
+    use sha3::{Digest, Keccak256};
+
+    /// Mainnet-style legacy transaction (the signing example from the
+    /// module docs of [`crate::ecdsa`]): key, RLP payload, and the known
+    /// signature with `v = 27`.
+    #[test]
+    fn legacy_transaction_roundtrip() {
+        let signing_key = SigningKey::from_bytes(
+            &hex!("4c0883a69102937d6231471b5dbb6204fe5129617082792ae468d01a3f362318").into(),
+        )
+        .unwrap();
+
+        let tx_rlp = hex!(
+            "e9808504e3b29200831e848094f0109fc8df283027b6285cc889f5aa624eac1f55843b9aca0080018080"
+        );
+        let prehash: [u8; 32] = Keccak256::digest(tx_rlp).into();
+
+        let (signature, recid) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+        let sig65 = Signature65::new(signature, recid);
+
+        let mut expected = [0u8; 65];
+        expected[..64].copy_from_slice(&hex!(
+            "c9cf86333bcb065d140032ecaab5d9281bde80f21b9687b3e94161de42d51895
+             727a108a0b8d101465414033c3f705a9c7b826e596766046ee1183dbc8aeaa68"
+        ));
+        expected[64] = 27;
+        assert_eq!(sig65.to_bytes(), expected);
+
+        // recovery yields the original key
+        let recovered = recover_from_prehash_eth(&prehash, &sig65).unwrap();
+        assert_eq!(&recovered, signing_key.verifying_key());
+
+        // parsing accepts both the {27, 28} and {0, 1} conventions
+        assert_eq!(Signature65::from_bytes(&expected).unwrap(), sig65);
+        let mut raw_v = expected;
+        raw_v[64] = 0;
+        assert_eq!(Signature65::from_bytes(&raw_v).unwrap(), sig65);
+
+        // invalid v values are rejected
+        let mut bad_v = expected;
+        bad_v[64] = 29;
+        assert!(Signature65::from_bytes(&bad_v).is_err());
+    }
+
+    #[test]
+    fn eip155_v_roundtrip() {
+        let signing_key = SigningKey::from_bytes(&[0x42u8; 32].into()).unwrap();
+        let prehash: [u8; 32] = Keccak256::digest(b"EIP-155 message").into();
+        let (signature, recid) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+        let sig65 = Signature65::new(signature, recid);
+
+        // mainnet chain_id = 1: v ∈ {37, 38}
+        let v = sig65.to_eip155_v(1);
+        assert_eq!(v, 35 + u64::from(recid.is_y_odd()) + 2);
+
+        let (parsed, chain_id) = Signature65::from_eip155_v(*sig65.signature(), v).unwrap();
+        assert_eq!(parsed, sig65);
+        assert_eq!(chain_id, 1);
+
+        // legacy form reports chain ID zero
+        let (parsed, chain_id) =
+            Signature65::from_eip155_v(*sig65.signature(), 27 + u64::from(recid.is_y_odd()))
+                .unwrap();
+        assert_eq!(parsed, sig65);
+        assert_eq!(chain_id, 0);
+
+        // sub-27 v values are rejected
+        assert!(Signature65::from_eip155_v(*sig65.signature(), 2).is_err());
+    }
+
+    #[test]
+    fn malformed_r_s_rejected() {
+        let mut bytes = [0u8; 65];
+        bytes[64] = 27;
+        // r = s = 0 is not a valid signature
+        assert!(Signature65::from_bytes(&bytes).is_err());
+
+        let _ = Signature::from_slice(&bytes[..64]).err();
+    }
+}
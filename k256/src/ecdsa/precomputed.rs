@@ -0,0 +1,168 @@
+//! Verification with a precomputed table for a fixed public key.
+
+use super::{Signature, VerifyingKey};
+use crate::{ProjectivePoint, Scalar, WnafTable, U256};
+use ecdsa_core::signature::{digest::Digest, hazmat::PrehashVerifier, DigestVerifier, Verifier};
+use elliptic_curve::{
+    ops::{MulByGenerator, Reduce},
+    point::AffineCoordinates,
+    scalar::IsHigh,
+};
+use signature::{Error, Result};
+
+/// A [`VerifyingKey`] paired with a precomputed multiplication table,
+/// amortizing per-verification cost for services which verify many
+/// signatures under a handful of known keys.
+///
+/// Construction builds a width-7 wNAF table for the public key, so the
+/// `u2*Q` term of the verification equation becomes table lookups while
+/// `u1*G` uses the (optionally precomputed) generator path. The type is
+/// `Send + Sync` and is intended to be built once and shared (e.g. in an
+/// `Arc`).
+///
+/// Accept/reject behavior is identical to [`VerifyingKey`], including the
+/// low-`s` policy.
+#[derive(Clone)]
+pub struct VerifyingKeyWithTable {
+    verifying_key: VerifyingKey,
+    table: WnafTable,
+}
+
+impl VerifyingKeyWithTable {
+    /// Window size for the public-key table.
+    const WINDOW: usize = 7;
+
+    /// Build the precomputed table for the given verifying key.
+    pub fn new(verifying_key: VerifyingKey) -> Self {
+        let table = WnafTable::new(
+            &ProjectivePoint::from(*verifying_key.as_affine()),
+            Self::WINDOW,
+        );
+
+        Self {
+            verifying_key,
+            table,
+        }
+    }
+
+    /// The underlying verifying key.
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+}
+
+impl From<VerifyingKey> for VerifyingKeyWithTable {
+    fn from(verifying_key: VerifyingKey) -> Self {
+        Self::new(verifying_key)
+    }
+}
+
+impl PrehashVerifier<Signature> for VerifyingKeyWithTable {
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature) -> Result<()> {
+        // match VerifyPrimitive's low-s policy
+        if signature.s().is_high().into() {
+            return Err(Error::new());
+        }
+
+        // identical prehash handling (incl. truncation/padding) to the
+        // plain verifier
+        let z_bytes = super::hazmat::bits2field::<crate::Secp256k1>(prehash)?;
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(&z_bytes);
+        let (r, s) = signature.split_scalars();
+        let s_inv = *<crate::NonZeroScalar as elliptic_curve::ops::Invert>::invert_vartime(&s);
+
+        let big_r =
+            ProjectivePoint::mul_by_generator(&(z * s_inv)) + self.table.mul_vartime(&(*r * s_inv));
+
+        if *r == <Scalar as Reduce<U256>>::reduce_bytes(&big_r.to_affine().x()) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl Verifier<Signature> for VerifyingKeyWithTable {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<()> {
+        self.verify_digest(sha2::Sha256::new_with_prefix(msg), signature)
+    }
+}
+
+impl<D> DigestVerifier<D, Signature> for VerifyingKeyWithTable
+where
+    D: Digest,
+{
+    fn verify_digest(&self, msg_digest: D, signature: &Signature) -> Result<()> {
+        self.verify_prehash(&msg_digest.finalize(), signature)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::VerifyingKeyWithTable;
+    use crate::ecdsa::{Signature, SigningKey};
+    use ecdsa_core::signature::{
+        hazmat::{PrehashSigner, PrehashVerifier},
+        Signer, Verifier,
+    };
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn identical_accept_reject_behavior() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let plain = *signing_key.verifying_key();
+        let with_table = VerifyingKeyWithTable::new(plain);
+
+        for i in 0u8..16 {
+            let prehash = [i; 32];
+            let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+
+            assert_eq!(
+                plain.verify_prehash(&prehash, &signature).is_ok(),
+                with_table.verify_prehash(&prehash, &signature).is_ok()
+            );
+
+            // tampered digests reject identically
+            let mut bad = prehash;
+            bad[0] ^= 1;
+            assert!(with_table.verify_prehash(&bad, &signature).is_err());
+            assert!(plain.verify_prehash(&bad, &signature).is_err());
+        }
+
+        // non-32-byte prehashes behave identically (bits2field semantics)
+        let prehash48 = [0x5au8; 48];
+        let signature: Signature = signing_key.sign_prehash(&prehash48).unwrap();
+        assert_eq!(
+            plain.verify_prehash(&prehash48, &signature).is_ok(),
+            with_table.verify_prehash(&prehash48, &signature).is_ok()
+        );
+        assert!(with_table.verify_prehash(&prehash48, &signature).is_ok());
+
+        // message-level APIs agree as well
+        let msg = b"precomputed table verification";
+        let signature: Signature = signing_key.sign(msg);
+        plain.verify(msg, &signature).unwrap();
+        with_table.verify(msg, &signature).unwrap();
+
+        // high-s rejected identically
+        let prehash = [9u8; 32];
+        let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+        let high = Signature::from_scalars(*signature.r(), -*signature.s()).unwrap();
+        assert!(plain.verify_prehash(&prehash, &high).is_err());
+        assert!(with_table.verify_prehash(&prehash, &high).is_err());
+    }
+
+    #[test]
+    fn send_sync_in_arc() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<VerifyingKeyWithTable>();
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let shared =
+            alloc::sync::Arc::new(VerifyingKeyWithTable::new(*signing_key.verifying_key()));
+        let prehash = [1u8; 32];
+        let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+        shared.verify_prehash(&prehash, &signature).unwrap();
+    }
+}
@@ -0,0 +1,260 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES) for secp256k1.
+//!
+//! Authenticated public-key encryption in the style of the widely deployed
+//! `ecies` libraries:
+//!
+//! 1. An ephemeral secp256k1 key pair is generated per message.
+//! 2. An ECDH shared secret is computed with the recipient key.
+//! 3. A symmetric key is derived with HKDF-SHA256 over the concatenation of
+//!    the uncompressed ephemeral public key and the shared x-coordinate.
+//! 4. The payload is sealed with AES-256-GCM (or XChaCha20-Poly1305 via
+//!    [`encrypt_xchacha20`]/[`decrypt_xchacha20`] when the
+//!    `ecies-xchacha20` feature is enabled).
+//!
+//! The wire format is `ephemeral_pub (65) || nonce || ciphertext || tag (16)`
+//! with a 16-byte AES-GCM nonce (24 bytes for XChaCha20-Poly1305).
+
+use crate::{ecdh, AffinePoint, PublicKey, SecretKey};
+use aes_gcm::{
+    aead::{generic_array::GenericArray as AeadArray, Aead, KeyInit, Payload},
+    AesGcm,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use elliptic_curve::{rand_core::CryptoRngCore, sec1::ToEncodedPoint, NonZeroScalar};
+use hkdf::Hkdf;
+use sha2::Sha256;
+
+/// AES-256-GCM with the 16-byte nonce used by the ecies wire format.
+type Aes256Gcm16 = AesGcm<aes_gcm::aes::Aes256, aes_gcm::aead::consts::U16>;
+
+/// Length of the uncompressed ephemeral public key.
+const EPHEMERAL_PUB_LEN: usize = 65;
+
+/// AES-GCM nonce length in this format.
+const AES_NONCE_LEN: usize = 16;
+
+/// Authentication tag length.
+const TAG_LEN: usize = 16;
+
+/// ECIES errors.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The ciphertext is structurally malformed (too short, or the
+    /// ephemeral public key failed to parse).
+    Malformed,
+
+    /// The ciphertext failed authentication (wrong key or tampered data).
+    Authentication,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Malformed => f.write_str("malformed ECIES ciphertext"),
+            Error::Authentication => f.write_str("ECIES authentication failure"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Derive the 32-byte symmetric key: HKDF-SHA256 over
+/// `ephemeral_pub_uncompressed || shared_x`.
+fn derive_key(ephemeral_pub: &PublicKey, shared_x: &[u8; 32]) -> [u8; 32] {
+    let mut ikm = [0u8; EPHEMERAL_PUB_LEN + 32];
+    ikm[..EPHEMERAL_PUB_LEN].copy_from_slice(ephemeral_pub.to_encoded_point(false).as_bytes());
+    ikm[EPHEMERAL_PUB_LEN..].copy_from_slice(shared_x);
+
+    let hk = Hkdf::<Sha256>::new(None, &ikm);
+    let mut okm = [0u8; 32];
+    // 32 bytes is always a valid HKDF-SHA256 output length
+    #[allow(clippy::unwrap_used)]
+    hk.expand(&[], &mut okm).unwrap();
+    okm
+}
+
+/// Compute the shared x-coordinate between a secret scalar and a public
+/// point.
+fn shared_x(secret: &NonZeroScalar<crate::Secp256k1>, public: &AffinePoint) -> [u8; 32] {
+    let shared = ecdh::diffie_hellman(secret, public);
+    let mut x = [0u8; 32];
+    x.copy_from_slice(shared.raw_secret_bytes());
+    x
+}
+
+/// Encrypt `plaintext` to the given recipient public key using an ephemeral
+/// key pair from `rng` and AES-256-GCM.
+pub fn encrypt(recipient: &PublicKey, plaintext: &[u8], rng: &mut impl CryptoRngCore) -> Vec<u8> {
+    let ephemeral_secret = NonZeroScalar::<crate::Secp256k1>::random(rng);
+    let ephemeral_pub = PublicKey::from_secret_scalar(&ephemeral_secret);
+
+    let key = derive_key(
+        &ephemeral_pub,
+        &shared_x(&ephemeral_secret, recipient.as_affine()),
+    );
+
+    let mut nonce = [0u8; AES_NONCE_LEN];
+    rng.fill_bytes(&mut nonce);
+
+    // AEAD encryption with a fresh key/nonce pair cannot fail
+    #[allow(clippy::unwrap_used)]
+    let sealed = Aes256Gcm16::new(AeadArray::from_slice(&key))
+        .encrypt(AeadArray::from_slice(&nonce), Payload::from(plaintext))
+        .unwrap();
+
+    let mut out = Vec::with_capacity(EPHEMERAL_PUB_LEN + AES_NONCE_LEN + sealed.len());
+    out.extend_from_slice(ephemeral_pub.to_encoded_point(false).as_bytes());
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&sealed);
+    out
+}
+
+/// Decrypt an ECIES ciphertext with the recipient's secret key.
+pub fn decrypt(secret_key: &SecretKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+    if ciphertext.len() < EPHEMERAL_PUB_LEN + AES_NONCE_LEN + TAG_LEN {
+        return Err(Error::Malformed);
+    }
+
+    let (pub_bytes, rest) = ciphertext.split_at(EPHEMERAL_PUB_LEN);
+    let (nonce, sealed) = rest.split_at(AES_NONCE_LEN);
+
+    let ephemeral_pub = PublicKey::from_sec1_bytes(pub_bytes).map_err(|_| Error::Malformed)?;
+
+    let key = derive_key(
+        &ephemeral_pub,
+        &shared_x(&secret_key.to_nonzero_scalar(), ephemeral_pub.as_affine()),
+    );
+
+    Aes256Gcm16::new(AeadArray::from_slice(&key))
+        .decrypt(AeadArray::from_slice(nonce), Payload::from(sealed))
+        .map_err(|_| Error::Authentication)
+}
+
+/// XChaCha20-Poly1305 nonce length.
+#[cfg(feature = "ecies-xchacha20")]
+const XCHACHA_NONCE_LEN: usize = 24;
+
+/// Encrypt with XChaCha20-Poly1305 instead of AES-256-GCM; the wire format
+/// is otherwise identical (with a 24-byte nonce).
+#[cfg(feature = "ecies-xchacha20")]
+pub fn encrypt_xchacha20(
+    recipient: &PublicKey,
+    plaintext: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> Vec<u8> {
+    use chacha20poly1305::XChaCha20Poly1305;
+
+    let ephemeral_secret = NonZeroScalar::<crate::Secp256k1>::random(rng);
+    let ephemeral_pub = PublicKey::from_secret_scalar(&ephemeral_secret);
+
+    let key = derive_key(
+        &ephemeral_pub,
+        &shared_x(&ephemeral_secret, recipient.as_affine()),
+    );
+
+    let mut nonce = [0u8; XCHACHA_NONCE_LEN];
+    rng.fill_bytes(&mut nonce);
+
+    #[allow(clippy::unwrap_used)]
+    let sealed = XChaCha20Poly1305::new(AeadArray::from_slice(&key))
+        .encrypt(AeadArray::from_slice(&nonce), Payload::from(plaintext))
+        .unwrap();
+
+    let mut out = Vec::with_capacity(EPHEMERAL_PUB_LEN + XCHACHA_NONCE_LEN + sealed.len());
+    out.extend_from_slice(ephemeral_pub.to_encoded_point(false).as_bytes());
+    out.extend_from_slice(&nonce);
+    out.extend_from_slice(&sealed);
+    out
+}
+
+/// Decrypt an XChaCha20-Poly1305 ECIES ciphertext.
+#[cfg(feature = "ecies-xchacha20")]
+pub fn decrypt_xchacha20(secret_key: &SecretKey, ciphertext: &[u8]) -> Result<Vec<u8>, Error> {
+    use chacha20poly1305::XChaCha20Poly1305;
+
+    if ciphertext.len() < EPHEMERAL_PUB_LEN + XCHACHA_NONCE_LEN + TAG_LEN {
+        return Err(Error::Malformed);
+    }
+
+    let (pub_bytes, rest) = ciphertext.split_at(EPHEMERAL_PUB_LEN);
+    let (nonce, sealed) = rest.split_at(XCHACHA_NONCE_LEN);
+
+    let ephemeral_pub = PublicKey::from_sec1_bytes(pub_bytes).map_err(|_| Error::Malformed)?;
+
+    let key = derive_key(
+        &ephemeral_pub,
+        &shared_x(&secret_key.to_nonzero_scalar(), ephemeral_pub.as_affine()),
+    );
+
+    XChaCha20Poly1305::new(AeadArray::from_slice(&key))
+        .decrypt(AeadArray::from_slice(nonce), Payload::from(sealed))
+        .map_err(|_| Error::Authentication)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{decrypt, encrypt, Error};
+    use crate::SecretKey;
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn encrypt_decrypt_roundtrip() {
+        let sk = SecretKey::random(&mut OsRng);
+        let pk = sk.public_key();
+
+        let msg = b"ECIES test message";
+        let ct = encrypt(&pk, msg, &mut OsRng);
+        assert_eq!(decrypt(&sk, &ct).unwrap(), msg);
+
+        // ciphertexts are randomized
+        assert_ne!(ct, encrypt(&pk, msg, &mut OsRng));
+    }
+
+    #[test]
+    fn error_types_distinguished() {
+        let sk = SecretKey::random(&mut OsRng);
+        let pk = sk.public_key();
+        let ct = encrypt(&pk, b"payload", &mut OsRng);
+
+        // too short: malformed
+        assert_eq!(decrypt(&sk, &ct[..64]).unwrap_err(), Error::Malformed);
+
+        // corrupted ephemeral key: malformed
+        let mut bad = ct.clone();
+        bad[1] ^= 1;
+        assert_eq!(decrypt(&sk, &bad).unwrap_err(), Error::Malformed);
+
+        // corrupted tag: authentication failure
+        let mut bad = ct.clone();
+        let last = bad.len() - 1;
+        bad[last] ^= 1;
+        assert_eq!(decrypt(&sk, &bad).unwrap_err(), Error::Authentication);
+
+        // wrong recipient key: authentication failure
+        let other = SecretKey::random(&mut OsRng);
+        assert_eq!(decrypt(&other, &ct).unwrap_err(), Error::Authentication);
+    }
+
+    #[cfg(feature = "ecies-xchacha20")]
+    #[test]
+    fn xchacha20_roundtrip() {
+        use super::{decrypt_xchacha20, encrypt_xchacha20};
+
+        let sk = SecretKey::random(&mut OsRng);
+        let ct = encrypt_xchacha20(&sk.public_key(), b"xchacha", &mut OsRng);
+        assert_eq!(decrypt_xchacha20(&sk, &ct).unwrap(), b"xchacha");
+        assert!(decrypt_xchacha20(&sk, &ct[..80]).is_err());
+    }
+
+    #[test]
+    fn empty_plaintext() {
+        let sk = SecretKey::random(&mut OsRng);
+        let ct = encrypt(&sk.public_key(), b"", &mut OsRng);
+        assert_eq!(decrypt(&sk, &ct).unwrap(), b"");
+    }
+}
@@ -35,22 +35,107 @@ extern crate alloc;
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
+#[cfg(feature = "bip32")]
+pub mod bip32;
+
+#[cfg(all(feature = "arithmetic", feature = "sha256"))]
+pub mod commitment;
+
 #[cfg(feature = "ecdh")]
 pub mod ecdh;
 
+#[cfg(feature = "ecies")]
+pub mod ecies;
+
 #[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
+#[cfg(feature = "oprf")]
+pub mod oprf;
+
+#[cfg(all(feature = "arithmetic", feature = "sha256"))]
+pub mod proofs;
+
 #[cfg(feature = "schnorr")]
 pub mod schnorr;
 
+#[cfg(feature = "silent-payments")]
+pub mod silent_payments;
+
+/// Raw 64-byte `x || y` point encoding helpers (no SEC1 prefix byte).
+#[cfg(feature = "arithmetic")]
+pub mod untagged {
+    use crate::{AffinePoint, PublicKey};
+    use elliptic_curve::Error;
+
+    /// Serialize a public key as 64 raw `x || y` bytes.
+    pub fn public_key_to_untagged_bytes(public_key: &PublicKey) -> [u8; 64] {
+        public_key.as_affine().to_untagged_bytes()
+    }
+
+    /// Parse a public key from 64 raw `x || y` bytes, validating curve
+    /// membership and rejecting the identity.
+    pub fn public_key_from_untagged_bytes(bytes: &[u8; 64]) -> Result<PublicKey, Error> {
+        let affine =
+            Option::<AffinePoint>::from(AffinePoint::from_untagged_bytes(bytes)).ok_or(Error)?;
+        PublicKey::from_affine(affine)
+    }
+
+    /// serde adapter for `#[serde(with = "k256::untagged::serde_public_key")]`
+    /// fields, serializing as 64 raw bytes (hex in human-readable formats).
+    #[cfg(feature = "serde")]
+    pub mod serde_public_key {
+        use super::{public_key_from_untagged_bytes, public_key_to_untagged_bytes};
+        use crate::PublicKey;
+        use serdect::serde::{de, Deserializer, Serializer};
+
+        /// Serialize a public key in the untagged form.
+        pub fn serialize<S>(public_key: &PublicKey, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            S: Serializer,
+        {
+            serdect::array::serialize_hex_upper_or_bin(
+                &public_key_to_untagged_bytes(public_key),
+                serializer,
+            )
+        }
+
+        /// Deserialize a public key from the untagged form.
+        pub fn deserialize<'de, D>(deserializer: D) -> Result<PublicKey, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            let mut bytes = [0u8; 64];
+            serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+            public_key_from_untagged_bytes(&bytes).map_err(de::Error::custom)
+        }
+    }
+}
+
+#[cfg(feature = "vrf")]
+pub mod vrf;
+
 #[cfg(any(feature = "test-vectors", test))]
 pub mod test_vectors;
 
 pub use elliptic_curve::{self, bigint::U256};
 
 #[cfg(feature = "arithmetic")]
-pub use arithmetic::{affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar};
+pub use arithmetic::{
+    affine::AffinePoint, projective::ProjectivePoint, scalar::Scalar, SignedHalfScalar,
+};
+
+#[cfg(all(feature = "arithmetic", feature = "alloc"))]
+pub use arithmetic::basepoint_table;
+
+#[cfg(feature = "hash2curve")]
+pub use arithmetic::hash2curve;
+
+#[cfg(all(feature = "arithmetic", feature = "alloc"))]
+pub use arithmetic::msm;
+
+#[cfg(all(feature = "arithmetic", feature = "alloc"))]
+pub use arithmetic::wnaf::{self, WnafTable};
 
 #[cfg(feature = "expose-field")]
 pub use arithmetic::FieldElement;
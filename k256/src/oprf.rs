@@ -0,0 +1,235 @@
+//! Oblivious PRF (2HashDH) evaluation over secp256k1.
+//!
+//! The 2HashDH OPRF computes `F(k, x) = H2(x, k*H1(x))` without the server
+//! learning `x` or the client learning `k`:
+//!
+//! 1. The client hashes its input to a curve point and blinds it with a
+//!    random scalar ([`blind`]).
+//! 2. The server multiplies the blinded element by its key ([`evaluate`],
+//!    or [`evaluate_verifiable`] to attach a DLEQ proof against its public
+//!    key).
+//! 3. The client unblinds and hashes to the final output ([`finalize`] /
+//!    [`finalize_verifiable`]).
+
+use crate::{AffinePoint, EncodedPoint, NonZeroScalar, ProjectivePoint, Secp256k1};
+use elliptic_curve::{
+    group::prime::PrimeCurveAffine,
+    hash2curve::{ExpandMsgXmd, GroupDigest},
+    ops::Invert,
+    rand_core::CryptoRngCore,
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+};
+use elliptic_curve::{Error, Result};
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "oprf-verifiable")]
+use crate::{proofs::dleq::DleqProof, PublicKey};
+
+/// Domain separation tag for hashing inputs to the curve.
+const HASH_TO_GROUP_DST: &[u8] = b"HashToGroup-k256-2HashDH-v1";
+
+/// Domain separator for the final output hash.
+const FINALIZE_DST: &[u8] = b"Finalize-k256-2HashDH-v1";
+
+/// DLEQ transcript tag for the verifiable mode.
+#[cfg(feature = "oprf-verifiable")]
+const DLEQ_DST: &[u8] = b"DLEQ-k256-2HashDH-v1";
+
+/// A blinded input element, sent from client to server.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BlindedElement(ProjectivePoint);
+
+/// The server's evaluation of a blinded element.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct EvaluationElement(ProjectivePoint);
+
+macro_rules! impl_element_encoding {
+    ($name:ident) => {
+        impl $name {
+            /// Serialize as a 33-byte compressed SEC1 point.
+            pub fn to_bytes(&self) -> [u8; 33] {
+                let mut out = [0u8; 33];
+                out.copy_from_slice(self.0.to_affine().to_encoded_point(true).as_bytes());
+                out
+            }
+
+            /// Parse from a 33-byte compressed SEC1 point.
+            pub fn from_bytes(bytes: &[u8; 33]) -> Result<Self> {
+                let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| Error)?;
+                let affine = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+                    .ok_or(Error)?;
+
+                if bool::from(affine.is_identity()) {
+                    return Err(Error);
+                }
+
+                Ok(Self(ProjectivePoint::from(affine)))
+            }
+        }
+    };
+}
+
+impl_element_encoding!(BlindedElement);
+impl_element_encoding!(EvaluationElement);
+
+/// Hash an input to a curve point (`H1`).
+fn hash_to_group(input: &[u8]) -> Result<ProjectivePoint> {
+    Secp256k1::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[input], &[HASH_TO_GROUP_DST])
+        .map_err(|_| Error)
+}
+
+/// Client: hash `input` to the curve and blind it with a fresh scalar.
+///
+/// Returns the blinding factor (needed for [`finalize`]) and the element to
+/// send to the server.
+pub fn blind(
+    input: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> Result<(NonZeroScalar, BlindedElement)> {
+    let h = hash_to_group(input)?;
+    let r = NonZeroScalar::random(rng);
+
+    Ok((r, BlindedElement(h * r.as_ref())))
+}
+
+/// Server: evaluate the PRF on a blinded element with the server key.
+pub fn evaluate(server_key: &NonZeroScalar, blinded: &BlindedElement) -> EvaluationElement {
+    EvaluationElement(blinded.0 * server_key.as_ref())
+}
+
+/// Client: unblind the server's evaluation and compute the PRF output
+/// `H2(input || ser(k*H1(input)))`.
+pub fn finalize(
+    input: &[u8],
+    evaluated: &EvaluationElement,
+    blind: &NonZeroScalar,
+) -> Result<[u8; 32]> {
+    let r_inv = blind.invert();
+    let unblinded = EvaluationElement(evaluated.0 * r_inv.as_ref());
+
+    Ok(Sha256::new()
+        .chain_update(FINALIZE_DST)
+        .chain_update((input.len() as u64).to_be_bytes())
+        .chain_update(input)
+        .chain_update(unblinded.to_bytes())
+        .finalize()
+        .into())
+}
+
+/// Server: evaluate and attach a DLEQ proof that the same key was used as
+/// in the server's public key `k*G`.
+#[cfg(feature = "oprf-verifiable")]
+pub fn evaluate_verifiable(
+    server_key: &NonZeroScalar,
+    blinded: &BlindedElement,
+    rng: &mut impl CryptoRngCore,
+) -> (EvaluationElement, DleqProof) {
+    let evaluation = evaluate(server_key, blinded);
+    let public_key = ProjectivePoint::from(PublicKey::from_secret_scalar(server_key).as_affine());
+
+    let proof = DleqProof::prove(
+        server_key,
+        &ProjectivePoint::GENERATOR,
+        &public_key,
+        &blinded.0,
+        &evaluation.0,
+        DLEQ_DST,
+        rng,
+    );
+
+    (evaluation, proof)
+}
+
+/// Client: verify the server's DLEQ proof against its public key, then
+/// unblind and finalize.
+#[cfg(feature = "oprf-verifiable")]
+pub fn finalize_verifiable(
+    input: &[u8],
+    evaluated: &EvaluationElement,
+    proof: &DleqProof,
+    server_public_key: &PublicKey,
+    blinded: &BlindedElement,
+    blind: &NonZeroScalar,
+) -> Result<[u8; 32]> {
+    proof.verify(
+        &ProjectivePoint::GENERATOR,
+        &ProjectivePoint::from(server_public_key.as_affine()),
+        &blinded.0,
+        &evaluated.0,
+        DLEQ_DST,
+    )?;
+
+    finalize(input, evaluated, blind)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{blind, evaluate, finalize, hash_to_group, EvaluationElement};
+    use crate::NonZeroScalar;
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn unblinded_output_matches_direct_evaluation() {
+        let server_key = NonZeroScalar::random(&mut OsRng);
+        let input = b"privacy pass token";
+
+        // protocol run
+        let (r, blinded) = blind(input, &mut OsRng).unwrap();
+        let evaluated = evaluate(&server_key, &blinded);
+        let output = finalize(input, &evaluated, &r).unwrap();
+
+        // direct evaluation: k * H1(input), finalized with a unit blind
+        let direct = EvaluationElement(hash_to_group(input).unwrap() * server_key.as_ref());
+        let one = NonZeroScalar::new(crate::Scalar::ONE).unwrap();
+        let expected = finalize(input, &direct, &one).unwrap();
+
+        assert_eq!(output, expected);
+
+        // serialization round trips
+        assert_eq!(
+            EvaluationElement::from_bytes(&evaluated.to_bytes()).unwrap(),
+            evaluated
+        );
+
+        // different blinds give the same output
+        let (r2, blinded2) = blind(input, &mut OsRng).unwrap();
+        let output2 = finalize(input, &evaluate(&server_key, &blinded2), &r2).unwrap();
+        assert_eq!(output, output2);
+
+        // different inputs give different outputs
+        let (r3, blinded3) = blind(b"other", &mut OsRng).unwrap();
+        let output3 = finalize(b"other", &evaluate(&server_key, &blinded3), &r3).unwrap();
+        assert_ne!(output, output3);
+    }
+
+    #[cfg(feature = "oprf-verifiable")]
+    #[test]
+    fn verifiable_mode_catches_wrong_key() {
+        use super::{evaluate_verifiable, finalize_verifiable};
+        use crate::PublicKey;
+
+        let server_key = NonZeroScalar::random(&mut OsRng);
+        let server_public = PublicKey::from_secret_scalar(&server_key);
+        let input = b"verifiable token";
+
+        let (r, blinded) = blind(input, &mut OsRng).unwrap();
+        let (evaluated, proof) = evaluate_verifiable(&server_key, &blinded, &mut OsRng);
+
+        // honest server verifies and finalizes
+        finalize_verifiable(input, &evaluated, &proof, &server_public, &blinded, &r).unwrap();
+
+        // a server that evaluated with a different key is caught
+        let rogue_key = NonZeroScalar::random(&mut OsRng);
+        let (rogue_eval, rogue_proof) = evaluate_verifiable(&rogue_key, &blinded, &mut OsRng);
+        assert!(finalize_verifiable(
+            input,
+            &rogue_eval,
+            &rogue_proof,
+            &server_public,
+            &blinded,
+            &r
+        )
+        .is_err());
+    }
+}
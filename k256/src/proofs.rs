@@ -0,0 +1,3 @@
+//! Zero-knowledge proofs over secp256k1.
+
+pub mod dleq;
@@ -0,0 +1,284 @@
+//! Chaum-Pedersen proofs of discrete logarithm equality (DLEQ).
+//!
+//! A DLEQ proof demonstrates knowledge of a witness `x` such that
+//! `A = x*G` and `B = x*H` for two independent base points `G` and `H`,
+//! without revealing `x`. The proof is made non-interactive with a
+//! Fiat-Shamir challenge over a tagged SHA-256 transcript which commits to
+//! a caller-supplied domain separation tag and all four points.
+
+use crate::{FieldBytes, NonZeroScalar, ProjectivePoint, Scalar, U256};
+use elliptic_curve::{
+    group::GroupEncoding,
+    ops::{LinearCombination, Reduce},
+    rand_core::CryptoRngCore,
+    PrimeField,
+};
+use elliptic_curve::{Error, Result};
+use sha2::{Digest, Sha256};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
+/// A DLEQ proof: the Fiat-Shamir challenge and response, serialized as
+/// 64 bytes `c || s`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct DleqProof {
+    /// Fiat-Shamir challenge.
+    c: Scalar,
+
+    /// Response `s = k + c*x`.
+    s: Scalar,
+}
+
+/// The Fiat-Shamir challenge over a BIP340-style tagged transcript.
+#[allow(clippy::too_many_arguments)]
+fn challenge(
+    dst: &[u8],
+    g: &ProjectivePoint,
+    a: &ProjectivePoint,
+    h: &ProjectivePoint,
+    b: &ProjectivePoint,
+    t1: &ProjectivePoint,
+    t2: &ProjectivePoint,
+) -> Scalar {
+    let tag = Sha256::digest(b"k256/dleq");
+    <Scalar as Reduce<U256>>::reduce_bytes(
+        &Sha256::new()
+            .chain_update(tag)
+            .chain_update(tag)
+            .chain_update((dst.len() as u64).to_be_bytes())
+            .chain_update(dst)
+            .chain_update(g.to_bytes())
+            .chain_update(a.to_bytes())
+            .chain_update(h.to_bytes())
+            .chain_update(b.to_bytes())
+            .chain_update(t1.to_bytes())
+            .chain_update(t2.to_bytes())
+            .finalize(),
+    )
+}
+
+impl DleqProof {
+    /// Byte length of a serialized proof.
+    pub const BYTE_SIZE: usize = 64;
+
+    /// Prove that `a == witness * g` and `b == witness * h`.
+    ///
+    /// The proving nonce is drawn from `rng`; the scalar arithmetic is
+    /// constant time in the witness.
+    pub fn prove(
+        witness: &NonZeroScalar,
+        g: &ProjectivePoint,
+        a: &ProjectivePoint,
+        h: &ProjectivePoint,
+        b: &ProjectivePoint,
+        dst: &[u8],
+        rng: &mut impl CryptoRngCore,
+    ) -> Self {
+        let k = NonZeroScalar::random(rng);
+        let t1 = g * k.as_ref();
+        let t2 = h * k.as_ref();
+
+        let c = challenge(dst, g, a, h, b, &t1, &t2);
+        let s = *k.as_ref() + c * witness.as_ref();
+
+        Self { c, s }
+    }
+
+    /// Verify this proof for the statement `a == x*g` and `b == x*h`.
+    pub fn verify(
+        &self,
+        g: &ProjectivePoint,
+        a: &ProjectivePoint,
+        h: &ProjectivePoint,
+        b: &ProjectivePoint,
+        dst: &[u8],
+    ) -> Result<()> {
+        // T1 = s*G - c*A, T2 = s*H - c*B
+        let t1 = ProjectivePoint::lincomb(g, &self.s, a, &-self.c);
+        let t2 = ProjectivePoint::lincomb(h, &self.s, b, &-self.c);
+
+        if challenge(dst, g, a, h, b, &t1, &t2) == self.c {
+            Ok(())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// Verify many proofs sharing a domain separation tag.
+    ///
+    /// Each item is `(proof, g, a, h, b)`. Returns an error if any proof
+    /// in the batch is invalid (without identifying which).
+    ///
+    /// Note: with the compact 64-byte `(c, s)` encoding, verification is
+    /// bound by the Fiat-Shamir hash over the recomputed commitments, so
+    /// unlike signature batching there is no random-linear-combination
+    /// shortcut; this amortizes only setup costs. A batchable variant
+    /// would need the `(T1, T2, s)` proof representation.
+    #[cfg(feature = "alloc")]
+    pub fn verify_batch(
+        items: &[(
+            DleqProof,
+            ProjectivePoint,
+            ProjectivePoint,
+            ProjectivePoint,
+            ProjectivePoint,
+        )],
+        dst: &[u8],
+    ) -> Result<()> {
+        for (proof, g, a, h, b) in items {
+            proof.verify(g, a, h, b, dst)?;
+        }
+
+        Ok(())
+    }
+
+    /// Serialize as `c || s`.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        let mut out = [0u8; Self::BYTE_SIZE];
+        out[..32].copy_from_slice(&self.c.to_bytes());
+        out[32..].copy_from_slice(&self.s.to_bytes());
+        out
+    }
+
+    /// Parse from `c || s` bytes.
+    pub fn from_bytes(bytes: &[u8; Self::BYTE_SIZE]) -> Result<Self> {
+        let c = Option::from(Scalar::from_repr(FieldBytes::clone_from_slice(
+            &bytes[..32],
+        )))
+        .ok_or(Error)?;
+        let s = Option::from(Scalar::from_repr(FieldBytes::clone_from_slice(
+            &bytes[32..],
+        )))
+        .ok_or(Error)?;
+        Ok(Self { c, s })
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for DleqProof {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serdect::array::serialize_hex_upper_or_bin(&self.to_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for DleqProof {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; Self::BYTE_SIZE];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+        Self::from_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::DleqProof;
+    use crate::{NonZeroScalar, ProjectivePoint, Scalar};
+    use elliptic_curve::{rand_core::OsRng, Field, Group};
+
+    const DST: &[u8] = b"k256 dleq tests";
+
+    fn statement() -> (
+        NonZeroScalar,
+        ProjectivePoint,
+        ProjectivePoint,
+        ProjectivePoint,
+        ProjectivePoint,
+    ) {
+        let x = NonZeroScalar::random(&mut OsRng);
+        let g = ProjectivePoint::GENERATOR;
+        let h = ProjectivePoint::random(&mut OsRng);
+        let a = g * x.as_ref();
+        let b = h * x.as_ref();
+        (x, g, a, h, b)
+    }
+
+    #[test]
+    fn roundtrip() {
+        let (x, g, a, h, b) = statement();
+        let proof = DleqProof::prove(&x, &g, &a, &h, &b, DST, &mut OsRng);
+        proof.verify(&g, &a, &h, &b, DST).unwrap();
+
+        // serialization round trip
+        let parsed = DleqProof::from_bytes(&proof.to_bytes()).unwrap();
+        assert_eq!(parsed, proof);
+        parsed.verify(&g, &a, &h, &b, DST).unwrap();
+
+        // wrong DST fails
+        assert!(proof.verify(&g, &a, &h, &b, b"other dst").is_err());
+    }
+
+    #[test]
+    fn swapped_points_rejected() {
+        let (x, g, a, h, b) = statement();
+        let proof = DleqProof::prove(&x, &g, &a, &h, &b, DST, &mut OsRng);
+
+        assert!(proof.verify(&a, &g, &h, &b, DST).is_err());
+        assert!(proof.verify(&g, &b, &h, &a, DST).is_err());
+        assert!(proof.verify(&h, &a, &g, &b, DST).is_err());
+        let wrong_b = b + ProjectivePoint::GENERATOR;
+        assert!(proof.verify(&g, &a, &h, &wrong_b, DST).is_err());
+    }
+
+    #[test]
+    fn unequal_logs_unprovable() {
+        // a proof over a false statement (different logs) does not verify
+        let x = NonZeroScalar::random(&mut OsRng);
+        let g = ProjectivePoint::GENERATOR;
+        let h = ProjectivePoint::random(&mut OsRng);
+        let a = g * x.as_ref();
+        let b = h * Scalar::random(&mut OsRng); // different witness
+
+        let proof = DleqProof::prove(&x, &g, &a, &h, &b, DST, &mut OsRng);
+        assert!(proof.verify(&g, &a, &h, &b, DST).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn batch_verification() {
+        let items: alloc::vec::Vec<_> = (0..16)
+            .map(|_| {
+                let (x, g, a, h, b) = statement();
+                (
+                    DleqProof::prove(&x, &g, &a, &h, &b, DST, &mut OsRng),
+                    g,
+                    a,
+                    h,
+                    b,
+                )
+            })
+            .collect();
+
+        DleqProof::verify_batch(&items, DST).unwrap();
+        DleqProof::verify_batch(&[], DST).unwrap();
+
+        // corrupt one response scalar
+        let mut bad = items.clone();
+        bad[7].0 = DleqProof::from_bytes(&{
+            let mut bytes = bad[7].0.to_bytes();
+            bytes[40] ^= 1;
+            bytes
+        })
+        .unwrap();
+        assert!(DleqProof::verify_batch(&bad, DST).is_err());
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_roundtrip() {
+        let (x, g, a, h, b) = statement();
+        let proof = DleqProof::prove(&x, &g, &a, &h, &b, DST, &mut OsRng);
+
+        let json = serde_json::to_string(&proof).unwrap();
+        let parsed: DleqProof = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed, proof);
+    }
+}
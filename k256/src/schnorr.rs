@@ -62,10 +62,22 @@
 
 #![allow(non_snake_case, clippy::many_single_char_names)]
 
+mod adaptor;
+#[cfg(feature = "alloc")]
+pub mod batch;
+#[cfg(feature = "alloc")]
+pub mod musig;
 mod signing;
+pub mod taproot;
 mod verifying;
-
-pub use self::{signing::SigningKey, verifying::VerifyingKey};
+mod xonly;
+
+pub use self::{
+    adaptor::AdaptorSignature,
+    signing::SigningKey,
+    verifying::VerifyingKey,
+    xonly::{Parity, XOnlyPublicKey},
+};
 pub use signature::{self, rand_core::CryptoRngCore, Error};
 
 use crate::{arithmetic::FieldElement, NonZeroScalar};
@@ -177,6 +189,18 @@ impl signature::PrehashSignature for Signature {
     type Digest = Sha256;
 }
 
+/// Construct a SHA-256 instance pre-keyed with a BIP340-style tag
+/// (`SHA256(SHA256(tag) || SHA256(tag))` midstate), suitable for feeding
+/// incrementally and for use with the [`DigestSigner`]/[`DigestVerifier`]
+/// impls on the Schnorr keys - enabling streaming of large messages
+/// without buffering.
+///
+/// [`DigestSigner`]: signature::DigestSigner
+/// [`DigestVerifier`]: signature::DigestVerifier
+pub fn tagged_sha256(tag: &[u8]) -> Sha256 {
+    tagged_hash(tag)
+}
+
 fn tagged_hash(tag: &[u8]) -> Sha256 {
     let tag_hash = Sha256::digest(tag);
     let mut digest = Sha256::new();
@@ -448,3 +472,121 @@ mod tests {
         }
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod streaming_tests {
+    use super::{tagged_sha256, SigningKey};
+    use crate::schnorr::Signature;
+    use elliptic_curve::rand_core::OsRng;
+    use sha2::{Digest, Sha256};
+    use signature::{DigestSigner, DigestVerifier, Signer};
+
+    #[test]
+    fn streaming_equals_one_shot() {
+        let signing_key = SigningKey::random(&mut OsRng);
+
+        // a "large" message fed incrementally in chunks
+        let chunk = [0xa5u8; 1024];
+        let mut digest = Sha256::new();
+        let mut msg = alloc::vec::Vec::new();
+        for _ in 0..64 {
+            digest.update(chunk);
+            msg.extend_from_slice(&chunk);
+        }
+
+        let streamed: Signature = signing_key.sign_digest(digest.clone());
+        let one_shot: Signature = signing_key.sign(&msg);
+        assert_eq!(streamed, one_shot);
+
+        signing_key
+            .verifying_key()
+            .verify_digest(digest, &streamed)
+            .unwrap();
+    }
+
+    #[test]
+    fn tagged_sha256_matches_construction() {
+        let tag_hash = Sha256::digest(b"BIP0340/challenge");
+        let expected: [u8; 32] = Sha256::new()
+            .chain_update(tag_hash)
+            .chain_update(tag_hash)
+            .chain_update(b"streamed")
+            .finalize()
+            .into();
+
+        let streamed: [u8; 32] = tagged_sha256(b"BIP0340/challenge")
+            .chain_update(b"str")
+            .chain_update(b"eamed")
+            .finalize()
+            .into();
+
+        assert_eq!(streamed, expected);
+    }
+
+    /// BIP340 vector 0 via the digest path: the 32-byte message is the
+    /// prehash, so signing `Sha256` of data is not applicable; instead the
+    /// prehash interface must reproduce the byte-exact vector.
+    #[test]
+    fn bip340_vector_via_prehash_digest_parity() {
+        use hex_literal::hex;
+
+        let sk = SigningKey::from_bytes(&hex!(
+            "0000000000000000000000000000000000000000000000000000000000000003"
+        ))
+        .unwrap();
+        let sig = sk
+            .sign_prehash_with_aux_rand(&[0u8; 32], &[0u8; 32])
+            .unwrap();
+        assert_eq!(
+            sig.to_bytes().as_slice(),
+            &hex!(
+                "E907831F80848D1069A5371B402410364BDF1C5F8307B0084C55F1CE2DCA8215
+                 25F66A4A85EA8B71E482A74F382D2CE5EBEEE8FDB2172F477DF4900D310536C0"
+            )
+        );
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod variable_length_tests {
+    use super::SigningKey;
+    use elliptic_curve::rand_core::OsRng;
+    use signature::hazmat::PrehashSigner;
+
+    #[test]
+    fn variable_length_messages_roundtrip() {
+        let sk = SigningKey::random(&mut OsRng);
+        let vk = sk.verifying_key();
+
+        for len in [0usize, 1, 17, 32, 100] {
+            let msg = alloc::vec![0xc4u8; len];
+            let sig = sk.sign_message_with_aux_rand(&msg, &[0u8; 32]).unwrap();
+            vk.verify_raw(&msg, &sig).unwrap();
+
+            // a different message of the same length fails
+            if len > 0 {
+                let mut other = msg.clone();
+                other[0] ^= 1;
+                assert!(vk.verify_raw(&other, &sig).is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn strict_prehash_paths_unchanged() {
+        let sk = SigningKey::random(&mut OsRng);
+
+        // 32-byte message: the variable-length path agrees with the strict
+        // prehash path (identical BIP340 hashing)
+        let msg = [0x2au8; 32];
+        let via_message = sk.sign_message_with_aux_rand(&msg, &[0u8; 32]).unwrap();
+        let via_prehash = sk.sign_prehash_with_aux_rand(&msg, &[0u8; 32]).unwrap();
+        assert_eq!(via_message, via_prehash);
+
+        // the strict PrehashSigner still rejects non-32-byte input
+        let sig: signature::Result<super::Signature> = sk.sign_prehash(&[0u8; 16]);
+        assert!(sig.is_err());
+    }
+}
@@ -0,0 +1,295 @@
+//! Taproot Schnorr adaptor (pre-)signatures.
+//!
+//! An adaptor signature binds a standard [BIP340] signature to a secret
+//! adaptor scalar `t` with public adaptor point `T = t*G`: anyone holding the
+//! pre-signature and `t` can produce a valid BIP340 signature ([`adapt`]),
+//! and anyone holding the pre-signature and the final signature can recover
+//! `t` ([`recover_adaptor_secret`]). This is the basic building block of
+//! discreet log contracts and atomic swaps.
+//!
+//! [`adapt`]: AdaptorSignature::adapt
+//! [`recover_adaptor_secret`]: AdaptorSignature::recover_adaptor_secret
+//! [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+
+use super::{tagged_hash, Signature, SigningKey, VerifyingKey, AUX_TAG, CHALLENGE_TAG, NONCE_TAG};
+use crate::FieldBytes;
+use crate::{AffinePoint, NonZeroScalar, ProjectivePoint, Scalar};
+use elliptic_curve::{
+    bigint::U256,
+    group::prime::PrimeCurveAffine,
+    ops::{LinearCombination, Reduce},
+    point::DecompactPoint,
+    sec1::ToEncodedPoint,
+    subtle::{Choice, ConditionallySelectable},
+    PrimeField,
+};
+use sha2::Digest;
+use signature::{Error, Result};
+
+/// Taproot Schnorr adaptor signature.
+///
+/// Serialized as 65 bytes: `x(R) || s' || negation_flag`, where `R` is the
+/// even-Y combined nonce point of the final signature and the flag records
+/// whether the nonce/adaptor pair had to be negated to achieve even parity.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AdaptorSignature {
+    /// x-coordinate of the (even-Y) final nonce point `R = R0 + T`.
+    r: crate::arithmetic::FieldElement,
+
+    /// Pre-signature scalar `s'`.
+    s: Scalar,
+
+    /// Whether `R0 + T` had odd Y and the relation is negated.
+    negated: bool,
+}
+
+impl AdaptorSignature {
+    /// Size of a serialized adaptor signature in bytes.
+    pub const BYTE_SIZE: usize = 65;
+
+    /// Serialize this adaptor signature as bytes.
+    pub fn to_bytes(&self) -> [u8; Self::BYTE_SIZE] {
+        let mut ret = [0u8; Self::BYTE_SIZE];
+        ret[..32].copy_from_slice(&self.r.to_bytes());
+        ret[32..64].copy_from_slice(&self.s.to_bytes());
+        ret[64] = u8::from(self.negated);
+        ret
+    }
+
+    /// Parse an adaptor signature from bytes.
+    pub fn from_bytes(bytes: &[u8; Self::BYTE_SIZE]) -> Result<Self> {
+        let r = Option::from(crate::arithmetic::FieldElement::from_bytes(
+            FieldBytes::from_slice(&bytes[..32]),
+        ))
+        .ok_or_else(Error::new)?;
+
+        let s = Option::from(Scalar::from_repr(FieldBytes::clone_from_slice(
+            &bytes[32..64],
+        )))
+        .ok_or_else(Error::new)?;
+
+        let negated = match bytes[64] {
+            0 => false,
+            1 => true,
+            _ => return Err(Error::new()),
+        };
+
+        Ok(Self { r, s, negated })
+    }
+
+    /// Verify this adaptor signature against the given verifying key,
+    /// message digest, and adaptor point.
+    ///
+    /// Checks `s'*G - e*P == R -/+ T`, where the sign depends on the
+    /// negation flag.
+    pub fn verify(
+        &self,
+        verifying_key: &VerifyingKey,
+        msg_digest: &[u8; 32],
+        adaptor_point: &AffinePoint,
+    ) -> Result<()> {
+        if bool::from(adaptor_point.is_identity()) {
+            return Err(Error::new());
+        }
+
+        let e = challenge(&self.r.to_bytes(), verifying_key, msg_digest);
+
+        // lift_x(r): the final nonce point with even Y
+        let big_r = Option::<AffinePoint>::from(AffinePoint::decompact(&self.r.to_bytes()))
+            .ok_or_else(Error::new)?;
+
+        let lhs = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            &self.s,
+            &ProjectivePoint::from(verifying_key.as_affine()),
+            &-e,
+        );
+
+        let t = ProjectivePoint::from(*adaptor_point);
+        let rhs = if self.negated {
+            ProjectivePoint::from(big_r) + t
+        } else {
+            ProjectivePoint::from(big_r) - t
+        };
+
+        if lhs == rhs {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+
+    /// Complete this adaptor signature into a standard [BIP340] signature
+    /// using the secret adaptor scalar.
+    ///
+    /// [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+    pub fn adapt(&self, adaptor_secret: &NonZeroScalar) -> Result<Signature> {
+        let s = if self.negated {
+            self.s - adaptor_secret.as_ref()
+        } else {
+            self.s + adaptor_secret.as_ref()
+        };
+
+        let s = Option::from(NonZeroScalar::new(s)).ok_or_else(Error::new)?;
+
+        Ok(Signature { r: self.r, s })
+    }
+
+    /// Recover the secret adaptor scalar from this adaptor signature and the
+    /// completed signature produced by [`AdaptorSignature::adapt`].
+    pub fn recover_adaptor_secret(&self, signature: &Signature) -> Result<NonZeroScalar> {
+        let t = if self.negated {
+            self.s - signature.s.as_ref()
+        } else {
+            *signature.s.as_ref() - self.s
+        };
+
+        Option::from(NonZeroScalar::new(t)).ok_or_else(Error::new)
+    }
+}
+
+impl SigningKey {
+    /// Compute a Schnorr adaptor signature over the given message digest,
+    /// bound to the given adaptor point.
+    ///
+    /// The nonce is derived deterministically following the BIP340 scheme,
+    /// with the compressed adaptor point mixed into the nonce hash alongside
+    /// the message, so distinct adaptor points yield distinct nonces.
+    pub fn sign_adaptor(
+        &self,
+        msg_digest: &[u8; 32],
+        adaptor_point: &AffinePoint,
+        aux_rand: &[u8; 32],
+    ) -> Result<AdaptorSignature> {
+        if bool::from(adaptor_point.is_identity()) {
+            return Err(Error::new());
+        }
+
+        let mut t = tagged_hash(AUX_TAG).chain_update(aux_rand).finalize();
+
+        for (a, b) in t.iter_mut().zip(self.as_nonzero_scalar().to_bytes().iter()) {
+            *a ^= b
+        }
+
+        let rand = tagged_hash(NONCE_TAG)
+            .chain_update(t)
+            .chain_update(self.verifying_key().to_bytes())
+            .chain_update(adaptor_point.to_encoded_point(true).as_bytes())
+            .chain_update(msg_digest)
+            .finalize();
+
+        let mut k = *NonZeroScalar::try_from(&*rand).map_err(|_| Error::new())?;
+
+        // R = R0 + T must have even Y in the final signature; if it does not,
+        // negate the nonce (and record that the adaptor relation is negated).
+        let big_r0 = ProjectivePoint::GENERATOR * k;
+        let big_r = (big_r0 + ProjectivePoint::from(*adaptor_point)).to_affine();
+
+        if bool::from(big_r.is_identity()) {
+            return Err(Error::new());
+        }
+
+        let negated = bool::from(big_r.y.normalize().is_odd());
+        k.conditional_assign(&-k, Choice::from(u8::from(negated)));
+
+        let r = big_r.x.normalize();
+
+        let e = challenge(&r.to_bytes(), self.verifying_key(), msg_digest);
+        let s = k + e * **self.as_nonzero_scalar();
+
+        let sig = AdaptorSignature { r, s, negated };
+
+        #[cfg(debug_assertions)]
+        sig.verify(self.verifying_key(), msg_digest, adaptor_point)?;
+
+        Ok(sig)
+    }
+}
+
+/// Compute the BIP340 challenge scalar.
+fn challenge(r_bytes: &FieldBytes, verifying_key: &VerifyingKey, msg_digest: &[u8; 32]) -> Scalar {
+    <Scalar as Reduce<U256>>::reduce_bytes(
+        &tagged_hash(CHALLENGE_TAG)
+            .chain_update(r_bytes)
+            .chain_update(verifying_key.to_bytes())
+            .chain_update(msg_digest)
+            .finalize(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AdaptorSignature;
+    use crate::{
+        schnorr::{signature::hazmat::PrehashVerifier, SigningKey},
+        NonZeroScalar, ProjectivePoint,
+    };
+    use hex_literal::hex;
+
+    #[test]
+    fn adaptor_roundtrip() {
+        let sk = SigningKey::from_bytes(&hex!(
+            "b7e151628aed2a6abf7158809cf4f3c762e7160f38b4da56a784d9045190cfef"
+        ))
+        .unwrap();
+        let t = NonZeroScalar::try_from(
+            &hex!("c90fdaa22168c234c4c6628b80dc1cd129024e088a67cc74020bbea63b14e5c9")[..],
+        )
+        .unwrap();
+        let big_t = (ProjectivePoint::GENERATOR * *t).to_affine();
+        let msg = hex!("243f6a8885a308d313198a2e03707344a4093822299f31d0082efa98ec4e6c89");
+
+        let pre_sig = sk.sign_adaptor(&msg, &big_t, &[0u8; 32]).unwrap();
+
+        // pre-signature verifies against the adaptor point
+        pre_sig.verify(sk.verifying_key(), &msg, &big_t).unwrap();
+
+        // ...but is not a valid BIP340 signature completion for a wrong point
+        let wrong_t = (ProjectivePoint::GENERATOR * *t + ProjectivePoint::GENERATOR).to_affine();
+        assert!(pre_sig.verify(sk.verifying_key(), &msg, &wrong_t).is_err());
+
+        // completing with the secret yields a valid BIP340 signature
+        let sig = pre_sig.adapt(&t).unwrap();
+        sk.verifying_key().verify_prehash(&msg, &sig).unwrap();
+
+        // the adaptor secret is recoverable from the pair
+        let recovered = pre_sig.recover_adaptor_secret(&sig).unwrap();
+        assert_eq!(recovered.to_bytes(), t.to_bytes());
+
+        // serialization round trip
+        let bytes = pre_sig.to_bytes();
+        assert_eq!(AdaptorSignature::from_bytes(&bytes).unwrap(), pre_sig);
+    }
+
+    /// Fixed vector so other implementations can cross-check the encoding.
+    #[test]
+    fn adaptor_fixed_vector() {
+        let sk = SigningKey::from_bytes(&hex!(
+            "0000000000000000000000000000000000000000000000000000000000000003"
+        ))
+        .unwrap();
+        let t = NonZeroScalar::try_from(
+            &hex!("0000000000000000000000000000000000000000000000000000000000000005")[..],
+        )
+        .unwrap();
+        let big_t = (ProjectivePoint::GENERATOR * *t).to_affine();
+        let msg = [0xabu8; 32];
+
+        let pre_sig = sk.sign_adaptor(&msg, &big_t, &[0u8; 32]).unwrap();
+        pre_sig.verify(sk.verifying_key(), &msg, &big_t).unwrap();
+
+        let sig = pre_sig.adapt(&t).unwrap();
+        sk.verifying_key().verify_prehash(&msg, &sig).unwrap();
+        assert_eq!(
+            pre_sig.recover_adaptor_secret(&sig).unwrap().to_bytes(),
+            t.to_bytes()
+        );
+    }
+
+    #[test]
+    fn identity_adaptor_point_rejected() {
+        let sk = SigningKey::from_bytes(&[1u8; 32]).unwrap();
+        let identity = crate::AffinePoint::IDENTITY;
+        assert!(sk.sign_adaptor(&[0u8; 32], &identity, &[0u8; 32]).is_err());
+    }
+}
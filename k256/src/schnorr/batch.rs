@@ -0,0 +1,177 @@
+//! Batch verification of BIP340 Schnorr signatures.
+
+use super::{tagged_hash, Signature, VerifyingKey, CHALLENGE_TAG};
+use crate::{AffinePoint, ProjectivePoint, Scalar};
+use alloc::vec::Vec;
+use core::fmt;
+use core::result::Result;
+use elliptic_curve::{
+    bigint::U256,
+    group::{prime::PrimeCurveAffine, Group},
+    ops::{LinearCombinationExt, Reduce},
+    point::DecompactPoint,
+    rand_core::CryptoRngCore,
+    PrimeField,
+};
+use sha2::Digest;
+use signature::hazmat::PrehashVerifier;
+
+/// Error returned by [`verify_batch`].
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BatchVerifyError {
+    /// Index of the first invalid item, identified by the per-item
+    /// fallback scan after the batched equation failed.
+    pub invalid_index: Option<usize>,
+}
+
+impl fmt::Display for BatchVerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.invalid_index {
+            Some(index) => write!(f, "invalid signature in batch at index {index}"),
+            None => f.write_str("batch verification failed"),
+        }
+    }
+}
+
+/// Verify a batch of BIP340 signatures with a single multiscalar
+/// multiplication.
+///
+/// Each signature equation `s_i*G == R_i + e_i*P_i` is multiplied by an
+/// independent random 128-bit coefficient `a_i` (with `a_0 = 1`) and the
+/// equations are summed, so a forged signature cannot cancel against the
+/// others except with probability ~2^-128. On failure, a per-item
+/// fallback scan identifies the first invalid index.
+///
+/// An empty batch verifies trivially.
+pub fn verify_batch(
+    items: &[(VerifyingKey, [u8; 32], Signature)],
+    rng: &mut impl CryptoRngCore,
+) -> Result<(), BatchVerifyError> {
+    if items.is_empty() {
+        return Ok(());
+    }
+
+    let mut terms: Vec<(ProjectivePoint, Scalar)> = Vec::with_capacity(2 * items.len() + 1);
+    let mut s_sum = Scalar::ZERO;
+
+    for (i, (verifying_key, msg_digest, signature)) in items.iter().enumerate() {
+        let (r, s) = signature.split();
+
+        // lift_x(r) with even Y; failure means this item is invalid
+        let big_r = match Option::<AffinePoint>::from(AffinePoint::decompact(&r.to_bytes())) {
+            Some(point) if !bool::from(point.is_identity()) => point,
+            _ => {
+                return Err(BatchVerifyError {
+                    invalid_index: Some(i),
+                })
+            }
+        };
+
+        let e = <Scalar as Reduce<U256>>::reduce_bytes(
+            &tagged_hash(CHALLENGE_TAG)
+                .chain_update(r.to_bytes())
+                .chain_update(verifying_key.to_bytes())
+                .chain_update(msg_digest)
+                .finalize(),
+        );
+
+        // a_0 = 1; a_i (i > 0) random with at least 128 bits of entropy
+        let a = if i == 0 {
+            Scalar::ONE
+        } else {
+            random_128bit_nonzero(rng)
+        };
+
+        s_sum += a * **s;
+        terms.push((ProjectivePoint::from(big_r), -a));
+        terms.push((ProjectivePoint::from(verifying_key.as_affine()), -(a * e)));
+    }
+
+    terms.push((ProjectivePoint::GENERATOR, s_sum));
+
+    if bool::from(ProjectivePoint::lincomb_ext(terms.as_slice()).is_identity()) {
+        return Ok(());
+    }
+
+    // slow path: identify the offender
+    for (i, (verifying_key, msg_digest, signature)) in items.iter().enumerate() {
+        if verifying_key.verify_prehash(msg_digest, signature).is_err() {
+            return Err(BatchVerifyError {
+                invalid_index: Some(i),
+            });
+        }
+    }
+
+    // The batched equation failed but every item verifies individually;
+    // this cannot happen for honestly computed coefficients.
+    Err(BatchVerifyError {
+        invalid_index: None,
+    })
+}
+
+/// Generate a non-zero scalar with 128 bits of randomness.
+fn random_128bit_nonzero(rng: &mut impl CryptoRngCore) -> Scalar {
+    loop {
+        let mut bytes = [0u8; 32];
+        rng.fill_bytes(&mut bytes[16..]);
+
+        // the high half is zero, so this is always canonical
+        #[allow(clippy::unwrap_used)]
+        let scalar = Option::<Scalar>::from(Scalar::from_repr(bytes.into())).unwrap();
+
+        if scalar != Scalar::ZERO {
+            return scalar;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::verify_batch;
+    use crate::schnorr::{Signature, SigningKey, VerifyingKey};
+    use alloc::vec::Vec;
+    use elliptic_curve::rand_core::OsRng;
+
+    fn signed_batch(n: usize) -> Vec<(VerifyingKey, [u8; 32], Signature)> {
+        (0..n)
+            .map(|i| {
+                let sk = SigningKey::random(&mut OsRng);
+                let mut msg = [0u8; 32];
+                msg[..8].copy_from_slice(&(i as u64).to_be_bytes());
+                let sig = sk.sign_prehash_with_aux_rand(&msg, &[0u8; 32]).unwrap();
+                (*sk.verifying_key(), msg, sig)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_batch_verifies() {
+        let items = signed_batch(64);
+        verify_batch(&items, &mut OsRng).unwrap();
+
+        // empty batches are trivially valid
+        verify_batch(&[], &mut OsRng).unwrap();
+    }
+
+    #[test]
+    fn corrupted_item_in_large_batch_identified() {
+        let mut items = signed_batch(1000);
+
+        // corrupt a single message in the middle of the batch
+        items[617].1[31] ^= 1;
+
+        let err = verify_batch(&items, &mut OsRng).unwrap_err();
+        assert_eq!(err.invalid_index, Some(617));
+    }
+
+    #[test]
+    fn corrupted_first_item_identified() {
+        // index 0 uses the fixed coefficient a_0 = 1; make sure it is
+        // still covered by the batch equation
+        let mut items = signed_batch(8);
+        items[0].1[0] ^= 1;
+
+        let err = verify_batch(&items, &mut OsRng).unwrap_err();
+        assert_eq!(err.invalid_index, Some(0));
+    }
+}
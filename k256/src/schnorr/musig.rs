@@ -0,0 +1,813 @@
+//! MuSig2 multi-signatures as defined in [BIP327].
+//!
+//! MuSig2 lets `n` parties cooperatively produce a single [BIP340] Schnorr
+//! signature over an aggregate of their public keys in two communication
+//! rounds: a nonce exchange followed by partial signing. The final signature
+//! verifies under the ordinary [`VerifyingKey`] for the aggregate key, so
+//! verifiers need no knowledge of MuSig2.
+//!
+//! The implementation follows the BIP327 algorithms: `KeySort`/`KeyAgg` with
+//! the second-distinct-key optimization, tweak support (plain and x-only
+//! taproot tweaks), `NonceGen`/`NonceAgg`, and partial signature
+//! generation/verification/aggregation.
+//!
+//! [BIP327]: https://github.com/bitcoin/bips/blob/master/bip-0327.mediawiki
+//! [BIP340]: https://github.com/bitcoin/bips/blob/master/bip-0340.mediawiki
+
+use super::{tagged_hash, Signature, VerifyingKey, CHALLENGE_TAG};
+use crate::{AffinePoint, FieldBytes, NonZeroScalar, ProjectivePoint, PublicKey, Scalar};
+use alloc::vec::Vec;
+use elliptic_curve::{
+    bigint::U256,
+    group::{Group, GroupEncoding},
+    ops::Reduce,
+    rand_core::CryptoRngCore,
+    sec1::ToEncodedPoint,
+    subtle::{Choice, ConditionallySelectable},
+    PrimeField,
+};
+use sha2::Digest;
+use signature::{Error, Result};
+
+const KEYAGG_LIST_TAG: &[u8] = b"KeyAgg list";
+const KEYAGG_COEFF_TAG: &[u8] = b"KeyAgg coefficient";
+const AUX_TAG: &[u8] = b"MuSig/aux";
+const NONCE_TAG: &[u8] = b"MuSig/nonce";
+const NONCE_COEF_TAG: &[u8] = b"MuSig/noncecoef";
+
+/// Sort compressed public keys in the lexicographic order required by
+/// BIP327's `KeySort` algorithm.
+pub fn key_sort(keys: &mut [PublicKey]) {
+    keys.sort_by_key(|pk| pk.to_encoded_point(true).as_bytes().to_vec());
+}
+
+/// Aggregate public key context (BIP327 `KeyAgg`), tracking the accumulated
+/// tweak state.
+#[derive(Clone)]
+pub struct KeyAggContext {
+    /// Aggregate point `Q`
+    q: ProjectivePoint,
+
+    /// Accumulated negation factor `gacc`
+    gacc: Scalar,
+
+    /// Accumulated tweak `tacc`
+    tacc: Scalar,
+
+    /// The ordered public keys being aggregated
+    keys: Vec<PublicKey>,
+
+    /// `KeyAgg list` hash of the ordered keys
+    keys_hash: [u8; 32],
+
+    /// The second distinct key, if any
+    second_key: Option<PublicKey>,
+}
+
+impl KeyAggContext {
+    /// Aggregate the given public keys in the given order.
+    ///
+    /// Callers which want the canonical ordering should call [`key_sort`]
+    /// first.
+    pub fn new(keys: &[PublicKey]) -> Result<Self> {
+        if keys.is_empty() {
+            return Err(Error::new());
+        }
+
+        let mut hasher = tagged_hash(KEYAGG_LIST_TAG);
+        for key in keys {
+            hasher.update(key.to_encoded_point(true).as_bytes());
+        }
+        let keys_hash: [u8; 32] = hasher.finalize().into();
+
+        let second_key = keys.iter().find(|pk| *pk != &keys[0]).copied();
+
+        let mut ctx = Self {
+            q: ProjectivePoint::IDENTITY,
+            gacc: Scalar::ONE,
+            tacc: Scalar::ZERO,
+            keys: keys.to_vec(),
+            keys_hash,
+            second_key,
+        };
+
+        let mut q = ProjectivePoint::IDENTITY;
+        for key in keys {
+            q += ProjectivePoint::from(key.as_affine()) * ctx.key_coefficient(key);
+        }
+
+        if bool::from(q.is_identity()) {
+            return Err(Error::new());
+        }
+
+        ctx.q = q;
+        Ok(ctx)
+    }
+
+    /// The BIP327 `KeyAggCoeff` for the given key.
+    fn key_coefficient(&self, key: &PublicKey) -> Scalar {
+        // the second distinct key gets coefficient 1
+        if Some(key) == self.second_key.as_ref() {
+            return Scalar::ONE;
+        }
+
+        <Scalar as Reduce<U256>>::reduce_bytes(
+            &tagged_hash(KEYAGG_COEFF_TAG)
+                .chain_update(self.keys_hash)
+                .chain_update(key.to_encoded_point(true).as_bytes())
+                .finalize(),
+        )
+    }
+
+    /// The public keys being aggregated, in aggregation order.
+    pub fn public_keys(&self) -> &[PublicKey] {
+        &self.keys
+    }
+
+    /// The x-only aggregate public key.
+    pub fn aggregate_public_key(&self) -> Result<VerifyingKey> {
+        VerifyingKey::from_bytes(&self.q.to_affine().x.normalize().to_bytes())
+            .map_err(|_| Error::new())
+    }
+
+    /// Apply a tweak to the aggregate key (BIP327 `ApplyTweak`).
+    ///
+    /// With `is_xonly` set this follows the BIP341 x-only semantics: the
+    /// aggregate key is negated to even Y before the tweak is added.
+    pub fn apply_tweak(&mut self, tweak: &Scalar, is_xonly: bool) -> Result<()> {
+        let g = if is_xonly && bool::from(self.q.to_affine().y.normalize().is_odd()) {
+            -Scalar::ONE
+        } else {
+            Scalar::ONE
+        };
+
+        let q = self.q * g + ProjectivePoint::GENERATOR * tweak;
+        if bool::from(q.is_identity()) {
+            return Err(Error::new());
+        }
+
+        self.q = q;
+        self.gacc = g * self.gacc;
+        self.tacc = *tweak + g * self.tacc;
+        Ok(())
+    }
+}
+
+/// Secret nonce produced by [`NonceGen::nonce_gen`].
+///
+/// ⚠️ A secret nonce must never be reused across signing sessions: doing so
+/// leaks the secret key.
+pub struct SecNonce {
+    k1: NonZeroScalar,
+    k2: NonZeroScalar,
+    public_key: PublicKey,
+}
+
+/// Public nonce: a pair of curve points, serialized as 66 bytes of
+/// compressed SEC1 encodings.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PubNonce {
+    r1: ProjectivePoint,
+    r2: ProjectivePoint,
+}
+
+impl PubNonce {
+    /// Serialize this public nonce as 66 bytes.
+    pub fn to_bytes(&self) -> [u8; 66] {
+        let mut out = [0u8; 66];
+        out[..33].copy_from_slice(&point_bytes(&self.r1));
+        out[33..].copy_from_slice(&point_bytes(&self.r2));
+        out
+    }
+
+    /// Parse a public nonce from bytes.
+    pub fn from_bytes(bytes: &[u8; 66]) -> Result<Self> {
+        Ok(Self {
+            r1: parse_point(&bytes[..33])?,
+            r2: parse_point(&bytes[33..])?,
+        })
+    }
+}
+
+/// Aggregate nonce (BIP327 `NonceAgg`), serialized identically to
+/// [`PubNonce`]. Points at infinity encode as 33 zero bytes.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct AggNonce {
+    r1: ProjectivePoint,
+    r2: ProjectivePoint,
+}
+
+impl AggNonce {
+    /// Aggregate the given public nonces.
+    pub fn new(nonces: &[PubNonce]) -> Result<Self> {
+        if nonces.is_empty() {
+            return Err(Error::new());
+        }
+
+        let mut r1 = ProjectivePoint::IDENTITY;
+        let mut r2 = ProjectivePoint::IDENTITY;
+        for nonce in nonces {
+            r1 += nonce.r1;
+            r2 += nonce.r2;
+        }
+
+        Ok(Self { r1, r2 })
+    }
+
+    /// Serialize this aggregate nonce as 66 bytes.
+    pub fn to_bytes(&self) -> [u8; 66] {
+        let mut out = [0u8; 66];
+        out[..33].copy_from_slice(&point_bytes(&self.r1));
+        out[33..].copy_from_slice(&point_bytes(&self.r2));
+        out
+    }
+}
+
+/// Nonce generation (BIP327 `NonceGen`).
+pub struct NonceGen;
+
+impl NonceGen {
+    /// Generate a fresh secret/public nonce pair for the given signer,
+    /// following the BIP327 `NonceGen` algorithm exactly.
+    ///
+    /// The RNG output is hashed together with the signer's public key, the
+    /// aggregate public key (when known), the message, the optional secret
+    /// key, and optional auxiliary input, so even a weak RNG does not
+    /// immediately repeat nonces across distinct sessions.
+    pub fn nonce_gen(
+        rng: &mut impl CryptoRngCore,
+        public_key: &PublicKey,
+        secret_key: Option<&NonZeroScalar>,
+        aggregate_public_key: Option<&VerifyingKey>,
+        msg: Option<&[u8]>,
+        extra_in: Option<&[u8]>,
+    ) -> Result<(SecNonce, PubNonce)> {
+        let mut rand = [0u8; 32];
+        rng.fill_bytes(&mut rand);
+
+        Self::nonce_gen_internal(
+            rand,
+            public_key,
+            secret_key,
+            aggregate_public_key,
+            msg,
+            extra_in,
+        )
+    }
+
+    /// BIP327 `NonceGen` with the fresh randomness supplied by the caller
+    /// (the spec's internal deterministic core; exposed for test vectors).
+    fn nonce_gen_internal(
+        rand: [u8; 32],
+        public_key: &PublicKey,
+        secret_key: Option<&NonZeroScalar>,
+        aggregate_public_key: Option<&VerifyingKey>,
+        msg: Option<&[u8]>,
+        extra_in: Option<&[u8]>,
+    ) -> Result<(SecNonce, PubNonce)> {
+        // rand' = sk XOR H_aux(rand) when a secret key is provided
+        let rand = match secret_key {
+            Some(sk) => {
+                let mask = tagged_hash(AUX_TAG).chain_update(rand).finalize();
+                let sk_bytes = sk.to_bytes();
+                let mut out = [0u8; 32];
+                for (i, byte) in out.iter_mut().enumerate() {
+                    *byte = sk_bytes[i] ^ mask[i];
+                }
+                out
+            }
+            None => rand,
+        };
+
+        let extra = extra_in.unwrap_or(&[]);
+        let extra_len = u32::try_from(extra.len()).map_err(|_| Error::new())?;
+
+        let derive = |counter: u8| -> Result<NonZeroScalar> {
+            let mut hasher = tagged_hash(NONCE_TAG).chain_update(rand);
+
+            let pk_bytes = public_key.to_encoded_point(true);
+            hasher.update([pk_bytes.as_bytes().len() as u8]);
+            hasher.update(pk_bytes.as_bytes());
+
+            match aggregate_public_key {
+                Some(aggpk) => {
+                    hasher.update([32u8]);
+                    hasher.update(aggpk.to_bytes());
+                }
+                None => hasher.update([0u8]),
+            }
+
+            match msg {
+                Some(m) => {
+                    hasher.update([1u8]);
+                    hasher.update((m.len() as u64).to_be_bytes());
+                    hasher.update(m);
+                }
+                None => hasher.update([0u8]),
+            }
+
+            hasher.update(extra_len.to_be_bytes());
+            hasher.update(extra);
+
+            hasher.update([counter]);
+
+            let k = <Scalar as Reduce<U256>>::reduce_bytes(&hasher.finalize());
+            Option::<NonZeroScalar>::from(NonZeroScalar::new(k)).ok_or_else(Error::new)
+        };
+
+        let k1 = derive(0)?;
+        let k2 = derive(1)?;
+
+        let sec = SecNonce {
+            k1,
+            k2,
+            public_key: *public_key,
+        };
+        let pub_nonce = PubNonce {
+            r1: ProjectivePoint::GENERATOR * *k1,
+            r2: ProjectivePoint::GENERATOR * *k2,
+        };
+
+        Ok((sec, pub_nonce))
+    }
+}
+
+/// A partial signature (BIP327 `psig`), serialized as a 32-byte scalar.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct PartialSignature(Scalar);
+
+impl PartialSignature {
+    /// Serialize as bytes.
+    pub fn to_bytes(&self) -> FieldBytes {
+        self.0.to_bytes()
+    }
+
+    /// Parse from bytes.
+    pub fn from_bytes(bytes: &FieldBytes) -> Result<Self> {
+        Option::from(Scalar::from_repr(*bytes))
+            .map(Self)
+            .ok_or_else(Error::new)
+    }
+}
+
+/// Session state for a single message: the effective nonce, challenge, and
+/// nonce-binding coefficient.
+struct Session {
+    b: Scalar,
+    big_r: AffinePoint,
+    r_odd: bool,
+    e: Scalar,
+}
+
+impl Session {
+    fn new(ctx: &KeyAggContext, agg_nonce: &AggNonce, msg: &[u8; 32]) -> Result<Self> {
+        let q_x = ctx.q.to_affine().x.normalize().to_bytes();
+
+        let b = <Scalar as Reduce<U256>>::reduce_bytes(
+            &tagged_hash(NONCE_COEF_TAG)
+                .chain_update(agg_nonce.to_bytes())
+                .chain_update(q_x)
+                .chain_update(msg)
+                .finalize(),
+        );
+
+        let mut big_r = agg_nonce.r1 + agg_nonce.r2 * b;
+        // BIP327: an infinite effective nonce is replaced by the generator
+        if bool::from(big_r.is_identity()) {
+            big_r = ProjectivePoint::GENERATOR;
+        }
+        let big_r = big_r.to_affine();
+        let r_odd = bool::from(big_r.y.normalize().is_odd());
+
+        let e = <Scalar as Reduce<U256>>::reduce_bytes(
+            &tagged_hash(CHALLENGE_TAG)
+                .chain_update(big_r.x.normalize().to_bytes())
+                .chain_update(q_x)
+                .chain_update(msg)
+                .finalize(),
+        );
+
+        Ok(Self { b, big_r, r_odd, e })
+    }
+}
+
+/// Produce a partial signature (BIP327 `Sign`).
+pub fn partial_sign(
+    ctx: &KeyAggContext,
+    sec_nonce: SecNonce,
+    secret_key: &NonZeroScalar,
+    agg_nonce: &AggNonce,
+    msg: &[u8; 32],
+) -> Result<PartialSignature> {
+    let public_key = PublicKey::from_secret_scalar(secret_key);
+    if public_key != sec_nonce.public_key {
+        return Err(Error::new());
+    }
+
+    let session = Session::new(ctx, agg_nonce, msg)?;
+
+    let mut k1 = *sec_nonce.k1;
+    let mut k2 = *sec_nonce.k2;
+    let negate = Choice::from(u8::from(session.r_odd));
+    k1.conditional_assign(&-k1, negate);
+    k2.conditional_assign(&-k2, negate);
+
+    // g = 1 if Q has even Y else -1; d = g * gacc * d'
+    let g = if bool::from(ctx.q.to_affine().y.normalize().is_odd()) {
+        -Scalar::ONE
+    } else {
+        Scalar::ONE
+    };
+    let d = g * ctx.gacc * secret_key.as_ref();
+
+    let a = ctx.key_coefficient(&public_key);
+    let s = k1 + session.b * k2 + session.e * a * d;
+
+    Ok(PartialSignature(s))
+}
+
+/// Verify a partial signature (BIP327 `PartialSigVerify`).
+pub fn partial_sig_verify(
+    ctx: &KeyAggContext,
+    partial_sig: &PartialSignature,
+    pub_nonce: &PubNonce,
+    public_key: &PublicKey,
+    agg_nonce: &AggNonce,
+    msg: &[u8; 32],
+) -> Result<()> {
+    let session = Session::new(ctx, agg_nonce, msg)?;
+
+    let mut r_eff = pub_nonce.r1 + pub_nonce.r2 * session.b;
+    if session.r_odd {
+        r_eff = -r_eff;
+    }
+
+    let g = if bool::from(ctx.q.to_affine().y.normalize().is_odd()) {
+        -Scalar::ONE
+    } else {
+        Scalar::ONE
+    };
+
+    let a = ctx.key_coefficient(public_key);
+    let expected =
+        r_eff + ProjectivePoint::from(public_key.as_affine()) * (session.e * a * g * ctx.gacc);
+
+    if ProjectivePoint::GENERATOR * partial_sig.0 == expected {
+        Ok(())
+    } else {
+        Err(Error::new())
+    }
+}
+
+/// Aggregate partial signatures into a final BIP340 signature
+/// (BIP327 `PartialSigAgg`).
+pub fn partial_sig_agg(
+    ctx: &KeyAggContext,
+    partial_sigs: &[PartialSignature],
+    agg_nonce: &AggNonce,
+    msg: &[u8; 32],
+) -> Result<Signature> {
+    let session = Session::new(ctx, agg_nonce, msg)?;
+
+    let g = if bool::from(ctx.q.to_affine().y.normalize().is_odd()) {
+        -Scalar::ONE
+    } else {
+        Scalar::ONE
+    };
+
+    let mut s = session.e * g * ctx.tacc;
+    for partial in partial_sigs {
+        s += partial.0;
+    }
+
+    let s = Option::from(NonZeroScalar::new(s)).ok_or_else(Error::new)?;
+
+    Ok(Signature {
+        r: session.big_r.x.normalize(),
+        s,
+    })
+}
+
+/// Compressed encoding of a point; the identity encodes as 33 zero bytes
+/// per BIP327.
+fn point_bytes(point: &ProjectivePoint) -> [u8; 33] {
+    let mut out = [0u8; 33];
+    if !bool::from(point.is_identity()) {
+        out.copy_from_slice(point.to_bytes().as_ref());
+    }
+    out
+}
+
+/// Parse a point; 33 zero bytes decode to the identity.
+fn parse_point(bytes: &[u8]) -> Result<ProjectivePoint> {
+    if bytes.iter().all(|b| *b == 0) {
+        return Ok(ProjectivePoint::IDENTITY);
+    }
+
+    PublicKey::from_sec1_bytes(bytes)
+        .map(|pk| ProjectivePoint::from(pk.as_affine()))
+        .map_err(|_| Error::new())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schnorr::signature::hazmat::PrehashVerifier;
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+
+    fn signer(seed: u8) -> (NonZeroScalar, PublicKey) {
+        let sk = NonZeroScalar::try_from(&[seed; 32][..]).unwrap();
+        (sk, PublicKey::from_secret_scalar(&sk))
+    }
+
+    fn run_session(tweaks: &[(Scalar, bool)]) {
+        let (sk1, pk1) = signer(0x11);
+        let (sk2, pk2) = signer(0x22);
+        let (sk3, pk3) = signer(0x33);
+
+        let mut keys = [pk1, pk2, pk3];
+        key_sort(&mut keys);
+
+        let mut ctx = KeyAggContext::new(&keys).unwrap();
+        for (tweak, is_xonly) in tweaks {
+            ctx.apply_tweak(tweak, *is_xonly).unwrap();
+        }
+
+        let msg = [0x99u8; 32];
+
+        let signers = [(sk1, pk1), (sk2, pk2), (sk3, pk3)];
+        let mut sec_nonces = Vec::new();
+        let mut pub_nonces = Vec::new();
+        for (sk, pk) in &signers {
+            let (sec, public) =
+                NonceGen::nonce_gen(&mut OsRng, pk, Some(sk), None, Some(&msg), None).unwrap();
+            sec_nonces.push(sec);
+            pub_nonces.push(public);
+        }
+
+        let agg_nonce = AggNonce::new(&pub_nonces).unwrap();
+
+        let mut partials = Vec::new();
+        for (i, (sk, pk)) in signers.iter().enumerate() {
+            let partial = partial_sign(&ctx, sec_nonces.remove(0), sk, &agg_nonce, &msg).unwrap();
+            partial_sig_verify(&ctx, &partial, &pub_nonces[i], pk, &agg_nonce, &msg).unwrap();
+            partials.push(partial);
+        }
+
+        let signature = partial_sig_agg(&ctx, &partials, &agg_nonce, &msg).unwrap();
+
+        // the final signature verifies as an ordinary BIP340 signature
+        // under the (tweaked) aggregate key
+        let agg_pk = ctx.aggregate_public_key().unwrap();
+        agg_pk.verify_prehash(&msg, &signature).unwrap();
+    }
+
+    #[test]
+    fn musig2_roundtrip() {
+        run_session(&[]);
+    }
+
+    #[test]
+    fn musig2_with_plain_tweak() {
+        run_session(&[(Scalar::from(12345u64), false)]);
+    }
+
+    #[test]
+    fn musig2_with_xonly_tweak() {
+        run_session(&[(Scalar::from(54321u64), true)]);
+    }
+
+    #[test]
+    fn musig2_with_mixed_tweaks() {
+        run_session(&[
+            (Scalar::from(7u64), false),
+            (Scalar::from(11u64), true),
+            (Scalar::from(13u64), true),
+        ]);
+    }
+
+    #[test]
+    fn corrupted_partial_signature_rejected() {
+        let (sk1, pk1) = signer(0x41);
+        let (sk2, pk2) = signer(0x42);
+
+        let mut keys = [pk1, pk2];
+        key_sort(&mut keys);
+        let ctx = KeyAggContext::new(&keys).unwrap();
+        let msg = [0x77u8; 32];
+
+        let (sec1, pub1) =
+            NonceGen::nonce_gen(&mut OsRng, &pk1, Some(&sk1), None, Some(&msg), None).unwrap();
+        let (_sec2, pub2) =
+            NonceGen::nonce_gen(&mut OsRng, &pk2, Some(&sk2), None, Some(&msg), None).unwrap();
+        let agg_nonce = AggNonce::new(&[pub1, pub2]).unwrap();
+
+        let partial = partial_sign(&ctx, sec1, &sk1, &agg_nonce, &msg).unwrap();
+
+        // tampered partial signature fails verification
+        let bad = PartialSignature(partial.0 + Scalar::ONE);
+        assert!(partial_sig_verify(&ctx, &bad, &pub1, &pk1, &agg_nonce, &msg).is_err());
+
+        // verification against the wrong signer's nonce also fails
+        assert!(partial_sig_verify(&ctx, &partial, &pub2, &pk2, &agg_nonce, &msg).is_err());
+    }
+
+    #[test]
+    fn key_sort_is_lexicographic() {
+        let (_, pk1) = signer(0x51);
+        let (_, pk2) = signer(0x52);
+        let (_, pk3) = signer(0x53);
+
+        let mut keys = [pk3, pk1, pk2];
+        key_sort(&mut keys);
+
+        let bytes: Vec<_> = keys
+            .iter()
+            .map(|k| k.to_encoded_point(true).as_bytes().to_vec())
+            .collect();
+        assert!(bytes.windows(2).all(|w| w[0] <= w[1]));
+    }
+
+    #[test]
+    fn secnonce_bound_to_key() {
+        let (sk1, pk1) = signer(0x61);
+        let (sk2, pk2) = signer(0x62);
+
+        let ctx = KeyAggContext::new(&[pk1, pk2]).unwrap();
+        let msg = [0u8; 32];
+
+        let (sec1, pub1) =
+            NonceGen::nonce_gen(&mut OsRng, &pk1, Some(&sk1), None, Some(&msg), None).unwrap();
+        let (_sec2, pub2) =
+            NonceGen::nonce_gen(&mut OsRng, &pk2, Some(&sk2), None, Some(&msg), None).unwrap();
+        let agg_nonce = AggNonce::new(&[pub1, pub2]).unwrap();
+
+        // signing with a mismatched secret key is rejected
+        assert!(partial_sign(&ctx, sec1, &sk2, &agg_nonce, &msg).is_err());
+    }
+
+    /// Valid cases from the BIP327 `key_agg_vectors.json` reference file.
+    #[test]
+    fn bip327_key_agg_vectors() {
+        let pubkeys: Vec<PublicKey> = [
+            hex!("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+            hex!("03dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659"),
+            hex!("023590a94e768f8e1815c2f24b4d80a8e3149316c3518ce7b7ad338368d038ca66"),
+        ]
+        .iter()
+        .map(|bytes| PublicKey::from_sec1_bytes(bytes).unwrap())
+        .collect();
+
+        let agg = |indices: &[usize]| -> [u8; 32] {
+            let keys: Vec<PublicKey> = indices.iter().map(|i| pubkeys[*i]).collect();
+            KeyAggContext::new(&keys)
+                .unwrap()
+                .aggregate_public_key()
+                .unwrap()
+                .to_bytes()
+                .into()
+        };
+
+        assert_eq!(
+            agg(&[0, 1, 2]),
+            hex!("90539eede565f5d054f32cc0c220126889ed1e5d193baf15aef344fe59d4610c")
+        );
+        assert_eq!(
+            agg(&[2, 1, 0]),
+            hex!("6204de8b083426dc6eaf9502d27024d53fc826bf7d2012148a0575435df54b2b")
+        );
+        assert_eq!(
+            agg(&[0, 0, 0]),
+            hex!("b436e3bad62b8cd409969a224731c193d051162d8c5ae8b109306127da3aa935")
+        );
+        assert_eq!(
+            agg(&[0, 0, 1, 1]),
+            hex!("69bc22bfa5d106306e48a20679de1d7389386124d07571d0d872686028c26a3e")
+        );
+    }
+
+    /// Plain and x-only tweaks diverge exactly when the untweaked
+    /// aggregate has odd parity; pin both modes over the reference keys.
+    #[test]
+    fn tweak_modes_pinned() {
+        let pubkeys: Vec<PublicKey> = [
+            hex!("023590a94e768f8e1815c2f24b4d80a8e3149316c3518ce7b7ad338368d038ca66"),
+            hex!("03dff1d77f2a671c5f36183726db2341be58feae1da2deced843240f7b502ba659"),
+            hex!("02f9308a019258c31049344f85f89d5229b531c845836f99b08601f113bce036f9"),
+        ]
+        .iter()
+        .map(|bytes| PublicKey::from_sec1_bytes(bytes).unwrap())
+        .collect();
+        let tweak = Scalar::from_repr(
+            hex!("e8f791ff9225a2af0102afff4a9a723d9612a682a25ebe79802b263cdfcd83bb").into(),
+        )
+        .unwrap();
+
+        let tweaked = |is_xonly: bool| -> [u8; 32] {
+            let mut ctx = KeyAggContext::new(&pubkeys).unwrap();
+            ctx.apply_tweak(&tweak, is_xonly).unwrap();
+            ctx.aggregate_public_key().unwrap().to_bytes().into()
+        };
+
+        assert_eq!(
+            tweaked(false),
+            hex!("7127b997978587213aebea116e69fad619652d1e3e6079c8b5ad491cf606af06")
+        );
+        assert_eq!(
+            tweaked(true),
+            hex!("317d8a78cafe6577afd84dfd841a0c0c0b51f09db4c592182b41ad8271587acc")
+        );
+    }
+
+    /// `NonceAgg` over deterministic spec nonces, including the
+    /// point-at-infinity halves encoding as 33 zero bytes.
+    #[test]
+    fn nonce_agg_pinned_and_infinity_encoding() {
+        let (sk1, pk1) = signer(7);
+        let (sk2, pk2) = signer(9);
+        let rand = [0xabu8; 32];
+
+        let n1 = NonceGen::nonce_gen_internal(rand, &pk1, Some(&sk1), None, None, None)
+            .unwrap()
+            .1;
+        let n2 = NonceGen::nonce_gen_internal(rand, &pk2, Some(&sk2), None, None, None)
+            .unwrap()
+            .1;
+
+        let agg = AggNonce::new(&[n1, n2]).unwrap();
+        assert_eq!(
+            agg.to_bytes(),
+            hex!(
+                "0390f0ad8c05f1092dbbc6e137e09562b0a290619abb52ea369711da038e92e45c
+                 03f33cb0cc70ae2e332a84936a4d51040ad4db307adfb4afa0fe37ef8df512cd5b"
+            )
+        );
+
+        // R and -R cancel; the infinite half must serialize as zeros
+        let neg = PubNonce::from_bytes(&{
+            let mut bytes = n1.to_bytes();
+            bytes[0] ^= 1; // flip compressed-point parity: negates the point
+            bytes[33] ^= 1;
+            bytes
+        })
+        .unwrap();
+        let cancelled = AggNonce::new(&[n1, neg]).unwrap();
+        assert_eq!(cancelled.to_bytes(), [0u8; 66]);
+    }
+
+    /// The exact BIP327 `NonceGen` serialization, checked against an
+    /// independent implementation of the algorithm (fixed `rand`; secret
+    /// key, aggregate key, message, and `extra_in` all bound in).
+    #[test]
+    fn nonce_gen_serialization() {
+        let sk = NonZeroScalar::new(Scalar::from(3u64)).unwrap();
+        let pk = PublicKey::from_secret_scalar(&sk);
+        let agg_sk = NonZeroScalar::new(Scalar::from(5u64)).unwrap();
+        let aggpk = VerifyingKey::try_from(PublicKey::from_secret_scalar(&agg_sk)).unwrap();
+
+        let mut rand = [0u8; 32];
+        for (i, byte) in rand.iter_mut().enumerate() {
+            *byte = i as u8;
+        }
+
+        let (_, pub_nonce) = NonceGen::nonce_gen_internal(
+            rand,
+            &pk,
+            Some(&sk),
+            Some(&aggpk),
+            Some(b"BIP327 nonce vector"),
+            Some(b"extra"),
+        )
+        .unwrap();
+
+        assert_eq!(
+            pub_nonce.to_bytes(),
+            hex!(
+                "02c7df15018fa140748cbe9af3f4bcf64ca30fdaef4b37c1618e3e9772b88a0450
+                 030832d5ed1a0e38d3e18c2ff9bdae4aab65e883e5f813d9ac7b59175d72053c00"
+            )
+        );
+
+        // every optional input changes the nonce
+        let base = NonceGen::nonce_gen_internal(rand, &pk, Some(&sk), None, None, None)
+            .unwrap()
+            .1;
+        for variant in [
+            NonceGen::nonce_gen_internal(rand, &pk, None, None, None, None)
+                .unwrap()
+                .1,
+            NonceGen::nonce_gen_internal(rand, &pk, Some(&sk), Some(&aggpk), None, None)
+                .unwrap()
+                .1,
+            NonceGen::nonce_gen_internal(rand, &pk, Some(&sk), None, Some(b""), None)
+                .unwrap()
+                .1,
+            NonceGen::nonce_gen_internal(rand, &pk, Some(&sk), None, None, Some(b"x"))
+                .unwrap()
+                .1,
+        ] {
+            assert_ne!(variant.to_bytes(), base.to_bytes());
+        }
+    }
+}
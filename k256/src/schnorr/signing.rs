@@ -155,6 +155,103 @@ impl From<&SecretKey> for SigningKey {
     }
 }
 
+impl SigningKey {
+    /// Compute a Schnorr signature over `msg` (hashed with SHA-256) using
+    /// caller-provided BIP340 auxiliary randomness.
+    ///
+    /// Passing 32 zero bytes yields the fully deterministic variant
+    /// (identical to [`Signer::try_sign`]); fresh random bytes yield
+    /// "synthetic" nonces which additionally defend against fault attacks.
+    pub fn sign_with_aux_rand(&self, msg: &[u8], aux_rand: &[u8; 32]) -> Result<Signature> {
+        self.sign_prehash_with_aux_rand(
+            &Sha256::new_with_prefix(msg).finalize_fixed().into(),
+            aux_rand,
+        )
+    }
+
+    /// Compute a Schnorr signature over a message of arbitrary length, per
+    /// the current BIP340 specification (which no longer restricts messages
+    /// to 32 bytes), with caller-provided auxiliary randomness.
+    ///
+    /// The strict 32-byte entry points ([`Self::sign_prehash_with_aux_rand`]
+    /// and the `PrehashSigner` impl) are unchanged and continue to reject
+    /// other lengths.
+    pub fn sign_message_with_aux_rand(&self, msg: &[u8], aux_rand: &[u8; 32]) -> Result<Signature> {
+        let mut t = tagged_hash(AUX_TAG).chain_update(aux_rand).finalize();
+
+        for (a, b) in t.iter_mut().zip(self.secret_key.to_bytes().iter()) {
+            *a ^= b
+        }
+
+        let rand = tagged_hash(NONCE_TAG)
+            .chain_update(t)
+            .chain_update(self.verifying_key.as_affine().x.to_bytes())
+            .chain_update(msg)
+            .finalize();
+
+        let k = NonZeroScalar::try_from(&*rand)
+            .map(Self::from)
+            .map_err(|_| Error::new())?;
+
+        let secret_key = k.secret_key;
+        let verifying_point = AffinePoint::from(k.verifying_key);
+        let r = verifying_point.x.normalize();
+
+        let e = <Scalar as Reduce<U256>>::reduce_bytes(
+            &tagged_hash(CHALLENGE_TAG)
+                .chain_update(r.to_bytes())
+                .chain_update(self.verifying_key.to_bytes())
+                .chain_update(msg)
+                .finalize(),
+        );
+
+        let s = *secret_key + e * *self.secret_key;
+        let s = Option::from(NonZeroScalar::new(s)).ok_or_else(Error::new)?;
+        let sig = Signature { r, s };
+
+        #[cfg(debug_assertions)]
+        self.verifying_key.verify_raw(msg, &sig)?;
+
+        Ok(sig)
+    }
+
+    /// Compute a Schnorr signature over a prehashed message using an
+    /// externally supplied nonce.
+    ///
+    /// # ⚠️ Warning
+    ///
+    /// This is a hazmat interface for protocol constructions (e.g. MuSig)
+    /// where nonces are derived elsewhere. The nonce **must** be unique and
+    /// uniformly random per signature: reusing a nonce, or using a biased
+    /// one, leaks the secret key. The nonce's parity is normalized
+    /// internally per BIP340.
+    pub fn sign_raw(&self, msg_digest: &[u8; 32], nonce: NonZeroScalar) -> Result<Signature> {
+        let mut k = *nonce;
+        let big_r = (ProjectivePoint::GENERATOR * k).to_affine();
+
+        // BIP340 requires an even-Y nonce point
+        k.conditional_assign(&-k, big_r.y.normalize().is_odd());
+        let r = big_r.x.normalize();
+
+        let e = <Scalar as Reduce<U256>>::reduce_bytes(
+            &tagged_hash(CHALLENGE_TAG)
+                .chain_update(r.to_bytes())
+                .chain_update(self.verifying_key.to_bytes())
+                .chain_update(msg_digest)
+                .finalize(),
+        );
+
+        let s = k + e * *self.secret_key;
+        let s = Option::<NonZeroScalar>::from(NonZeroScalar::new(s)).ok_or_else(Error::new)?;
+        let sig = Signature { r, s };
+
+        #[cfg(debug_assertions)]
+        self.verifying_key.verify_prehash(msg_digest, &sig)?;
+
+        Ok(sig)
+    }
+}
+
 //
 // `*Signer` trait impls
 //
@@ -258,3 +355,44 @@ impl<'de> Deserialize<'de> for SigningKey {
         Ok(SigningKey::from(NonZeroScalar::deserialize(deserializer)?))
     }
 }
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::SigningKey;
+    use crate::{schnorr::Signature, NonZeroScalar, ProjectivePoint};
+    use elliptic_curve::rand_core::OsRng;
+    use sha2::{Digest, Sha256};
+    use signature::{hazmat::PrehashVerifier, Signer, Verifier};
+
+    #[test]
+    fn sign_with_aux_rand_matches_signer_for_zero_aux() {
+        let sk = SigningKey::random(&mut OsRng);
+        let msg = b"aux rand control";
+
+        let deterministic: Signature = sk.try_sign(msg).unwrap();
+        let explicit = sk.sign_with_aux_rand(msg, &[0u8; 32]).unwrap();
+        assert_eq!(deterministic, explicit);
+
+        // distinct aux produces a distinct (but valid) signature
+        let other = sk.sign_with_aux_rand(msg, &[1u8; 32]).unwrap();
+        assert_ne!(deterministic, other);
+        sk.verifying_key().verify(msg, &other).unwrap();
+    }
+
+    #[test]
+    fn sign_raw_with_external_nonce() {
+        let sk = SigningKey::random(&mut OsRng);
+        let msg_digest: [u8; 32] = Sha256::digest(b"external nonce").into();
+        let nonce = NonZeroScalar::random(&mut OsRng);
+
+        let sig = sk.sign_raw(&msg_digest, nonce).unwrap();
+        sk.verifying_key()
+            .verify_prehash(&msg_digest, &sig)
+            .unwrap();
+
+        // r is the x-coordinate of the nonce point regardless of parity
+        let big_r = (ProjectivePoint::GENERATOR * *nonce).to_affine();
+        assert_eq!(sig.to_bytes()[..32], big_r.x.normalize().to_bytes()[..]);
+    }
+}
@@ -0,0 +1,147 @@
+//! BIP341 (Taproot) key tweaking helpers.
+
+use super::{xonly::Parity, SigningKey, VerifyingKey, XOnlyPublicKey};
+use crate::{NonZeroScalar, Scalar};
+use elliptic_curve::{bigint::U256, ops::Reduce};
+use sha2::Digest;
+use signature::{Error, Result};
+
+/// The BIP341 `TapTweak` tag.
+const TAP_TWEAK_TAG: &[u8] = b"TapTweak";
+
+/// Compute a BIP340-style tagged hash:
+/// `SHA256(SHA256(tag) || SHA256(tag) || data)`.
+pub fn tagged_hash(tag: &[u8], data: &[u8]) -> [u8; 32] {
+    super::tagged_hash(tag).chain_update(data).finalize().into()
+}
+
+/// Compute the BIP341 `TapTweak` scalar for an internal key and optional
+/// merkle root.
+fn tap_tweak_scalar(internal_key: &XOnlyPublicKey, merkle_root: Option<&[u8; 32]>) -> Scalar {
+    let mut hasher = super::tagged_hash(TAP_TWEAK_TAG).chain_update(internal_key.to_bytes());
+    if let Some(root) = merkle_root {
+        hasher.update(root);
+    }
+
+    <Scalar as Reduce<U256>>::reduce_bytes(&hasher.finalize())
+}
+
+impl VerifyingKey {
+    /// Compute the BIP341 taproot output key for this internal key and
+    /// optional script tree merkle root.
+    ///
+    /// Returns the tweaked output key along with the parity of the tweaked
+    /// point, which is needed for script-path control blocks.
+    pub fn tap_tweak(&self, merkle_root: Option<&[u8; 32]>) -> Result<(VerifyingKey, Parity)> {
+        let internal_key = XOnlyPublicKey::from(self);
+        let tweak = tap_tweak_scalar(&internal_key, merkle_root);
+
+        let (output_key, parity) = internal_key.add_tweak(&tweak)?;
+        let verifying_key = VerifyingKey::try_from(&output_key)?;
+        Ok((verifying_key, parity))
+    }
+}
+
+impl SigningKey {
+    /// Derive the BIP341 taproot output signing key for this internal key
+    /// and optional script tree merkle root.
+    ///
+    /// The returned key signs for the output key produced by
+    /// [`VerifyingKey::tap_tweak`] on the corresponding internal key.
+    pub fn tap_tweak(&self, merkle_root: Option<&[u8; 32]>) -> Result<SigningKey> {
+        let internal_key = XOnlyPublicKey::from(self.verifying_key());
+        let tweak = tap_tweak_scalar(&internal_key, merkle_root);
+
+        // the stored secret already corresponds to the even-Y internal key
+        let tweaked = *self.as_nonzero_scalar().as_ref() + tweak;
+        let tweaked =
+            Option::<NonZeroScalar>::from(NonZeroScalar::new(tweaked)).ok_or_else(Error::new)?;
+
+        // SigningKey::from normalizes the parity of the tweaked key
+        Ok(SigningKey::from(tweaked))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::tagged_hash;
+    use crate::schnorr::{signature::hazmat::PrehashVerifier, Parity, SigningKey, XOnlyPublicKey};
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+
+    #[test]
+    fn tagged_hash_matches_bip340_construction() {
+        use sha2::{Digest, Sha256};
+
+        let tag_hash = Sha256::digest(b"TapTweak");
+        let expected: [u8; 32] = Sha256::new()
+            .chain_update(tag_hash)
+            .chain_update(tag_hash)
+            .chain_update(b"data")
+            .finalize()
+            .into();
+
+        assert_eq!(tagged_hash(b"TapTweak", b"data"), expected);
+    }
+
+    #[test]
+    fn key_path_tweak_roundtrip() {
+        for merkle_root in [None, Some(&[0x42u8; 32])] {
+            let signing_key = SigningKey::random(&mut OsRng);
+
+            let tweaked_signing = signing_key.tap_tweak(merkle_root).unwrap();
+            let (output_key, _parity) = signing_key.verifying_key().tap_tweak(merkle_root).unwrap();
+
+            // the tweaked signing key signs for the tweaked output key
+            assert_eq!(tweaked_signing.verifying_key(), &output_key);
+
+            let msg = [0x24u8; 32];
+            let signature = tweaked_signing
+                .sign_prehash_with_aux_rand(&msg, &[0u8; 32])
+                .unwrap();
+            output_key.verify_prehash(&msg, &signature).unwrap();
+        }
+    }
+
+    #[test]
+    fn tweak_depends_on_merkle_root() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let (q1, _) = signing_key.verifying_key().tap_tweak(None).unwrap();
+        let (q2, _) = signing_key
+            .verifying_key()
+            .tap_tweak(Some(&[1u8; 32]))
+            .unwrap();
+        assert_ne!(q1, q2);
+    }
+
+    /// Fixed vector: internal key 1*G with no script tree.
+    #[test]
+    fn fixed_vector_generator_key() {
+        let signing_key = SigningKey::from_bytes(&hex!(
+            "0000000000000000000000000000000000000000000000000000000000000001"
+        ))
+        .unwrap();
+
+        let (output_key, parity) = signing_key.verifying_key().tap_tweak(None).unwrap();
+
+        // independently recompute: t = TapTweak(x(G)); Q = G + t*G
+        let internal = XOnlyPublicKey::from(signing_key.verifying_key());
+        let tweak_bytes = tagged_hash(b"TapTweak", &internal.to_bytes());
+        let t = <crate::Scalar as elliptic_curve::ops::Reduce<crate::U256>>::reduce_bytes(
+            &tweak_bytes.into(),
+        );
+        let q =
+            (crate::ProjectivePoint::GENERATOR + crate::ProjectivePoint::GENERATOR * t).to_affine();
+
+        assert_eq!(
+            output_key.to_bytes().as_slice(),
+            q.x.normalize().to_bytes().as_slice()
+        );
+        let expected_parity = if bool::from(q.y.normalize().is_odd()) {
+            Parity::Odd
+        } else {
+            Parity::Even
+        };
+        assert_eq!(parity, expected_parity);
+    }
+}
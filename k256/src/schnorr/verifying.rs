@@ -25,6 +25,39 @@ pub struct VerifyingKey {
 }
 
 impl VerifyingKey {
+    /// Verify a Schnorr signature over a message of arbitrary length, per
+    /// the current BIP340 specification.
+    ///
+    /// The strict 32-byte [`PrehashVerifier`] entry point is unchanged.
+    pub fn verify_raw(&self, msg: &[u8], signature: &Signature) -> core::result::Result<(), Error> {
+        let (r, s) = signature.split();
+
+        let e = <Scalar as Reduce<U256>>::reduce_bytes(
+            &tagged_hash(CHALLENGE_TAG)
+                .chain_update(signature.r.to_bytes())
+                .chain_update(self.to_bytes())
+                .chain_update(msg)
+                .finalize(),
+        );
+
+        let big_r = ProjectivePoint::lincomb(
+            &ProjectivePoint::GENERATOR,
+            s,
+            &self.inner.to_projective(),
+            &-e,
+        )
+        .to_affine();
+
+        if big_r.is_identity().into()
+            || big_r.y.normalize().is_odd().into()
+            || big_r.x.normalize() != *r
+        {
+            return Err(Error::new());
+        }
+
+        Ok(())
+    }
+
     /// Borrow the inner [`AffinePoint`] this type wraps.
     pub fn as_affine(&self) -> &AffinePoint {
         self.inner.as_affine()
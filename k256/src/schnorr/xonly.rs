@@ -0,0 +1,250 @@
+//! First-class x-only public keys (BIP340/BIP341).
+
+use super::VerifyingKey;
+use crate::{AffinePoint, FieldBytes, ProjectivePoint, PublicKey, Scalar};
+use elliptic_curve::{group::prime::PrimeCurveAffine, point::DecompactPoint};
+use signature::{Error, Result};
+
+#[cfg(feature = "serde")]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
+/// Y-coordinate parity of a secp256k1 point.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Parity {
+    /// Even Y coordinate.
+    Even,
+
+    /// Odd Y coordinate.
+    Odd,
+}
+
+impl Parity {
+    /// Parity of the given affine point.
+    fn of(point: &AffinePoint) -> Self {
+        if bool::from(point.y.normalize().is_odd()) {
+            Parity::Odd
+        } else {
+            Parity::Even
+        }
+    }
+}
+
+impl From<Parity> for u8 {
+    fn from(parity: Parity) -> u8 {
+        match parity {
+            Parity::Even => 0,
+            Parity::Odd => 1,
+        }
+    }
+}
+
+/// A 32-byte x-only public key as used by Taproot (BIP340/BIP341): the
+/// x-coordinate of a point, implicitly lifted to the point with even Y.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct XOnlyPublicKey {
+    /// The even-Y lifting of the x-coordinate.
+    point: AffinePoint,
+}
+
+impl XOnlyPublicKey {
+    /// Parse an x-only public key from its 32-byte encoding.
+    ///
+    /// Fails if the x-coordinate has no corresponding curve point. This
+    /// operation is variable time; x-only keys are public data.
+    pub fn from_bytes(bytes: &[u8; 32]) -> Result<Self> {
+        let point =
+            Option::<AffinePoint>::from(AffinePoint::decompact(FieldBytes::from_slice(bytes)))
+                .ok_or_else(Error::new)?;
+
+        if bool::from(point.is_identity()) {
+            return Err(Error::new());
+        }
+
+        Ok(Self { point })
+    }
+
+    /// Serialize as the 32-byte x coordinate.
+    pub fn to_bytes(&self) -> [u8; 32] {
+        self.point.x.normalize().to_bytes().into()
+    }
+
+    /// The even-Y affine point this key represents.
+    pub fn as_affine(&self) -> &AffinePoint {
+        &self.point
+    }
+
+    /// Apply a BIP341-style additive tweak: `Q = lift_x(self) + t*G`.
+    ///
+    /// Returns the x-only form of `Q` along with the parity of `Q`, which is
+    /// required later to reconstruct the full point (e.g. for control
+    /// blocks). Fails if the result is the point at infinity.
+    pub fn add_tweak(&self, tweak: &Scalar) -> Result<(XOnlyPublicKey, Parity)> {
+        let q =
+            (ProjectivePoint::from(self.point) + ProjectivePoint::GENERATOR * tweak).to_affine();
+
+        if bool::from(q.is_identity()) {
+            return Err(Error::new());
+        }
+
+        let parity = Parity::of(&q);
+        let x_only = XOnlyPublicKey::from_bytes(&q.x.normalize().to_bytes().into())?;
+        Ok((x_only, parity))
+    }
+
+    /// Reconstruct the full public key given the parity of the original
+    /// point.
+    pub fn public_key(&self, parity: Parity) -> PublicKey {
+        let point = match parity {
+            Parity::Even => self.point,
+            Parity::Odd => -self.point,
+        };
+
+        // non-identity is an invariant of XOnlyPublicKey
+        #[allow(clippy::unwrap_used)]
+        PublicKey::from_affine(point).unwrap()
+    }
+}
+
+/// Conversion from a full public key, returning the parity that was dropped.
+impl From<&PublicKey> for XOnlyPublicKey {
+    fn from(public_key: &PublicKey) -> Self {
+        XOnlyPublicKey::split(public_key).0
+    }
+}
+
+impl XOnlyPublicKey {
+    /// Split a full public key into its x-only form and Y parity.
+    pub fn split(public_key: &PublicKey) -> (Self, Parity) {
+        let affine = public_key.as_affine();
+        let parity = Parity::of(affine);
+
+        let point = match parity {
+            Parity::Even => *affine,
+            Parity::Odd => -*affine,
+        };
+
+        (Self { point }, parity)
+    }
+}
+
+impl From<&VerifyingKey> for XOnlyPublicKey {
+    fn from(verifying_key: &VerifyingKey) -> Self {
+        // VerifyingKey already guarantees even Y
+        Self {
+            point: *verifying_key.as_affine(),
+        }
+    }
+}
+
+impl TryFrom<&XOnlyPublicKey> for VerifyingKey {
+    type Error = Error;
+
+    fn try_from(x_only: &XOnlyPublicKey) -> Result<VerifyingKey> {
+        PublicKey::from_affine(x_only.point)
+            .map_err(|_| Error::new())?
+            .try_into()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl Serialize for XOnlyPublicKey {
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        serdect::array::serialize_hex_upper_or_bin(&self.to_bytes(), serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> Deserialize<'de> for XOnlyPublicKey {
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        let mut bytes = [0u8; 32];
+        serdect::array::deserialize_hex_or_bin(&mut bytes, deserializer)?;
+        Self::from_bytes(&bytes).map_err(de::Error::custom)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Parity, XOnlyPublicKey};
+    use crate::{schnorr::SigningKey, ProjectivePoint, PublicKey, Scalar};
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn roundtrip_through_bytes() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let x_only = XOnlyPublicKey::from(signing_key.verifying_key());
+
+        let bytes = x_only.to_bytes();
+        assert_eq!(XOnlyPublicKey::from_bytes(&bytes).unwrap(), x_only);
+        assert_eq!(bytes, signing_key.verifying_key().to_bytes().as_slice());
+    }
+
+    #[test]
+    fn split_and_reconstruct() {
+        for _ in 0..8 {
+            let sk = crate::NonZeroScalar::random(&mut OsRng);
+            let pk = PublicKey::from_secret_scalar(&sk);
+
+            let (x_only, parity) = XOnlyPublicKey::split(&pk);
+            assert_eq!(x_only.public_key(parity), pk);
+        }
+    }
+
+    #[test]
+    fn add_tweak_matches_group_math() {
+        let sk = SigningKey::random(&mut OsRng);
+        let x_only = XOnlyPublicKey::from(sk.verifying_key());
+        let tweak = Scalar::from(0xdeadbeefu64);
+
+        let (tweaked, parity) = x_only.add_tweak(&tweak).unwrap();
+
+        // Q = P + t*G
+        let q = (ProjectivePoint::from(*x_only.as_affine()) + ProjectivePoint::GENERATOR * tweak)
+            .to_affine();
+        assert_eq!(
+            tweaked.to_bytes().as_slice(),
+            q.x.normalize().to_bytes().as_slice()
+        );
+        assert_eq!(tweaked.public_key(parity).as_affine(), &q);
+    }
+
+    #[test]
+    fn tweak_to_identity_rejected() {
+        // lift_x(P) + (-d)*G == identity when P = d*G with even Y
+        let sk = SigningKey::random(&mut OsRng);
+        let x_only = XOnlyPublicKey::from(sk.verifying_key());
+        let neg_d = -**sk.as_nonzero_scalar();
+        assert!(x_only.add_tweak(&neg_d).is_err());
+    }
+
+    #[test]
+    fn invalid_x_rejected() {
+        // x = p - 1 is not on the curve (p - 1 is a quadratic residue check)
+        let bad = [0xffu8; 32];
+        assert!(XOnlyPublicKey::from_bytes(&bad).is_err());
+    }
+
+    #[test]
+    fn parity_is_tracked() {
+        // find a key with odd parity to exercise both branches
+        let mut found_odd = false;
+        let mut found_even = false;
+        for _ in 0..32 {
+            let sk = crate::NonZeroScalar::random(&mut OsRng);
+            let pk = PublicKey::from_secret_scalar(&sk);
+            match XOnlyPublicKey::split(&pk).1 {
+                Parity::Even => found_even = true,
+                Parity::Odd => found_odd = true,
+            }
+            if found_even && found_odd {
+                return;
+            }
+        }
+        panic!("expected both parities among 32 random keys");
+    }
+}
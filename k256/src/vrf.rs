@@ -0,0 +1,312 @@
+//! Verifiable Random Function (ECVRF) for secp256k1.
+//!
+//! Implements the `ECVRF-SECP256K1-SHA256-TAI` ciphersuite from [RFC 9381]
+//! (suite string `0xFE`): try-and-increment hash-to-curve, RFC 6979 nonce
+//! generation, and the 81-byte `gamma || c || s` proof encoding with a
+//! 16-byte challenge.
+//!
+//! [RFC 9381]: https://www.rfc-editor.org/rfc/rfc9381
+
+use crate::{AffinePoint, EncodedPoint, NonZeroScalar, ProjectivePoint, PublicKey, Scalar};
+use elliptic_curve::{
+    bigint::{ArrayEncoding, U256},
+    ops::{LinearCombination, Reduce},
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Curve, Error, FieldBytesEncoding, PrimeField, Result,
+};
+use sha2::{Digest, Sha256};
+
+/// RFC 9381 suite string for ECVRF-SECP256K1-SHA256-TAI.
+const SUITE_STRING: u8 = 0xFE;
+
+/// Challenge length in bytes (`cLen`).
+const C_LEN: usize = 16;
+
+/// Length of a serialized proof: 33-byte gamma, 16-byte c, 32-byte s.
+pub const PROOF_LEN: usize = 81;
+
+/// VRF secret key.
+#[derive(Clone)]
+pub struct VrfSecretKey {
+    scalar: NonZeroScalar,
+    public_key: VrfPublicKey,
+}
+
+/// VRF public key.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct VrfPublicKey(PublicKey);
+
+/// VRF proof: `(Gamma, c, s)`.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct VrfProof {
+    gamma: AffinePoint,
+    c: Scalar,
+    s: Scalar,
+}
+
+impl VrfSecretKey {
+    /// Create a VRF secret key from a non-zero scalar.
+    pub fn new(scalar: NonZeroScalar) -> Self {
+        let public_key = VrfPublicKey(PublicKey::from_secret_scalar(&scalar));
+        Self { scalar, public_key }
+    }
+
+    /// Parse a VRF secret key from its big-endian byte encoding.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        NonZeroScalar::try_from(bytes).map(Self::new)
+    }
+
+    /// The corresponding public key.
+    pub fn public_key(&self) -> &VrfPublicKey {
+        &self.public_key
+    }
+
+    /// Compute a VRF proof and output over the input `alpha`.
+    pub fn prove(&self, alpha: &[u8]) -> (VrfProof, [u8; 32]) {
+        let h = self.public_key.encode_to_curve_tai(alpha);
+        let h_point = ProjectivePoint::from(h);
+
+        let gamma = (h_point * *self.scalar).to_affine();
+
+        // ECVRF nonce generation per RFC 6979 with SHA-256:
+        // h1 = SHA-256(h_string), fed through bits2octets
+        let h_string = h.to_encoded_point(true);
+        let h1 = Sha256::digest(h_string.as_bytes());
+        let h1_reduced = <Scalar as Reduce<U256>>::reduce_bytes(&h1).to_bytes();
+        let k_bytes = rfc6979::generate_k::<Sha256, _>(
+            &self.scalar.to_bytes(),
+            &<U256 as FieldBytesEncoding<crate::Secp256k1>>::encode_field_bytes(
+                &crate::Secp256k1::ORDER,
+            ),
+            &h1_reduced,
+            &[],
+        );
+        let k = <Scalar as Reduce<U256>>::reduce(U256::from_be_byte_array(k_bytes));
+
+        let u = (ProjectivePoint::GENERATOR * k).to_affine();
+        let v = (h_point * k).to_affine();
+
+        let c = challenge(&self.public_key, &h, &gamma, &u, &v);
+        let s = k + c * *self.scalar;
+
+        let proof = VrfProof { gamma, c, s };
+        let beta = proof.to_hash();
+        (proof, beta)
+    }
+}
+
+impl VrfPublicKey {
+    /// Create a VRF public key from a standard public key.
+    pub fn new(public_key: PublicKey) -> Self {
+        Self(public_key)
+    }
+
+    /// Parse from a SEC1-encoded point.
+    pub fn from_sec1_bytes(bytes: &[u8]) -> Result<Self> {
+        PublicKey::from_sec1_bytes(bytes).map(Self)
+    }
+
+    /// Serialize as a compressed SEC1 point.
+    pub fn to_bytes(&self) -> [u8; 33] {
+        let mut out = [0u8; 33];
+        out.copy_from_slice(self.0.to_encoded_point(true).as_bytes());
+        out
+    }
+
+    /// Verify a VRF proof over `alpha`, returning the VRF output hash.
+    pub fn verify(&self, alpha: &[u8], proof: &VrfProof) -> Result<[u8; 32]> {
+        let h = self.encode_to_curve_tai(alpha);
+        let h_point = ProjectivePoint::from(h);
+        let y = ProjectivePoint::from(self.0.as_affine());
+        let gamma = ProjectivePoint::from(proof.gamma);
+
+        // U = s*G - c*Y
+        let u = ProjectivePoint::lincomb(&ProjectivePoint::GENERATOR, &proof.s, &y, &-proof.c)
+            .to_affine();
+
+        // V = s*H - c*Gamma
+        let v = ProjectivePoint::lincomb(&h_point, &proof.s, &gamma, &-proof.c).to_affine();
+
+        let c = challenge(self, &h, &proof.gamma, &u, &v);
+
+        if c == proof.c {
+            Ok(proof.to_hash())
+        } else {
+            Err(Error)
+        }
+    }
+
+    /// RFC 9381 `ECVRF_encode_to_curve` with try-and-increment, using the
+    /// compressed public key as the salt.
+    fn encode_to_curve_tai(&self, alpha: &[u8]) -> AffinePoint {
+        let salt = self.0.to_encoded_point(true);
+
+        for ctr in 0u8..=255 {
+            let digest = Sha256::new()
+                .chain_update([SUITE_STRING, 0x01])
+                .chain_update(salt.as_bytes())
+                .chain_update(alpha)
+                .chain_update([ctr, 0x00])
+                .finalize();
+
+            // attempt to interpret the hash as a compressed even-Y point
+            let mut candidate = [0u8; 33];
+            candidate[0] = 0x02;
+            candidate[1..].copy_from_slice(&digest);
+
+            if let Ok(encoded) = EncodedPoint::from_bytes(candidate) {
+                if let Some(point) =
+                    Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+                {
+                    return point;
+                }
+            }
+        }
+
+        // With overwhelming probability one of the 256 counters succeeds;
+        // RFC 9381 treats running out of counters as an unreachable failure.
+        unreachable!("ECVRF try-and-increment exhausted all counters")
+    }
+}
+
+impl VrfProof {
+    /// Serialize this proof as `gamma || c || s` (81 bytes).
+    pub fn to_bytes(&self) -> [u8; PROOF_LEN] {
+        let mut out = [0u8; PROOF_LEN];
+        out[..33].copy_from_slice(self.gamma.to_encoded_point(true).as_bytes());
+        out[33..49].copy_from_slice(&self.c.to_bytes()[16..]);
+        out[49..].copy_from_slice(&self.s.to_bytes());
+        out
+    }
+
+    /// Parse a proof from its 81-byte encoding.
+    pub fn from_bytes(bytes: &[u8; PROOF_LEN]) -> Result<Self> {
+        let encoded = EncodedPoint::from_bytes(&bytes[..33]).map_err(|_| Error)?;
+        if !encoded.is_compressed() {
+            return Err(Error);
+        }
+        let gamma =
+            Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded)).ok_or(Error)?;
+
+        let mut c_bytes = [0u8; 32];
+        c_bytes[16..].copy_from_slice(&bytes[33..49]);
+        let c = Option::from(Scalar::from_repr(c_bytes.into())).ok_or(Error)?;
+
+        let mut s_bytes = [0u8; 32];
+        s_bytes.copy_from_slice(&bytes[49..]);
+        let s = Option::from(Scalar::from_repr(s_bytes.into())).ok_or(Error)?;
+
+        Ok(Self { gamma, c, s })
+    }
+
+    /// RFC 9381 `ECVRF_proof_to_hash`: derive the 32-byte VRF output from
+    /// the proof.
+    pub fn to_hash(&self) -> [u8; 32] {
+        // cofactor is 1 for secp256k1, so cofactor * Gamma = Gamma
+        Sha256::new()
+            .chain_update([SUITE_STRING, 0x03])
+            .chain_update(self.gamma.to_encoded_point(true).as_bytes())
+            .chain_update([0x00])
+            .finalize()
+            .into()
+    }
+}
+
+/// RFC 9381 `ECVRF_challenge_generation` with a 16-byte challenge.
+fn challenge(
+    public_key: &VrfPublicKey,
+    h: &AffinePoint,
+    gamma: &AffinePoint,
+    u: &AffinePoint,
+    v: &AffinePoint,
+) -> Scalar {
+    let digest = Sha256::new()
+        .chain_update([SUITE_STRING, 0x02])
+        .chain_update(public_key.0.to_encoded_point(true).as_bytes())
+        .chain_update(h.to_encoded_point(true).as_bytes())
+        .chain_update(gamma.to_encoded_point(true).as_bytes())
+        .chain_update(u.to_encoded_point(true).as_bytes())
+        .chain_update(v.to_encoded_point(true).as_bytes())
+        .chain_update([0x00])
+        .finalize();
+
+    let mut c_bytes = [0u8; 32];
+    c_bytes[16..].copy_from_slice(&digest[..C_LEN]);
+
+    // c < 2^128 < n, so this cannot fail
+    #[allow(clippy::unwrap_used)]
+    Option::<Scalar>::from(Scalar::from_repr(c_bytes.into())).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{VrfProof, VrfSecretKey};
+    use hex_literal::hex;
+
+    fn secret_key() -> VrfSecretKey {
+        VrfSecretKey::from_bytes(&hex!(
+            "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn prove_verify_roundtrip() {
+        let sk = secret_key();
+        let (proof, beta) = sk.prove(b"sample");
+
+        let beta2 = sk.public_key().verify(b"sample", &proof).unwrap();
+        assert_eq!(beta, beta2);
+        assert_eq!(proof.to_hash(), beta);
+
+        // deterministic
+        let (proof2, beta3) = sk.prove(b"sample");
+        assert_eq!(proof.to_bytes(), proof2.to_bytes());
+        assert_eq!(beta, beta3);
+
+        // distinct inputs give distinct outputs
+        let (_, other) = sk.prove(b"other");
+        assert_ne!(beta, other);
+    }
+
+    #[test]
+    fn serialization_roundtrip() {
+        let sk = secret_key();
+        let (proof, _) = sk.prove(b"serialize me");
+
+        let bytes = proof.to_bytes();
+        assert_eq!(bytes.len(), 81);
+        assert_eq!(VrfProof::from_bytes(&bytes).unwrap(), proof);
+    }
+
+    #[test]
+    fn invalid_proofs_rejected() {
+        let sk = secret_key();
+        let (proof, _) = sk.prove(b"tamper");
+        let pk = sk.public_key();
+
+        // wrong message
+        assert!(pk.verify(b"tampered", &proof).is_err());
+
+        // malleated c
+        let mut bytes = proof.to_bytes();
+        bytes[40] ^= 1;
+        let bad = VrfProof::from_bytes(&bytes).unwrap();
+        assert!(pk.verify(b"tamper", &bad).is_err());
+
+        // wrong gamma: substitute the generator
+        let mut bytes = proof.to_bytes();
+        use crate::elliptic_curve::sec1::ToEncodedPoint;
+        bytes[..33].copy_from_slice(
+            crate::AffinePoint::GENERATOR
+                .to_encoded_point(true)
+                .as_bytes(),
+        );
+        let bad = VrfProof::from_bytes(&bytes).unwrap();
+        assert!(pk.verify(b"tamper", &bad).is_err());
+
+        // wrong public key
+        let other = VrfSecretKey::from_bytes(&[0x42u8; 32]).unwrap();
+        assert!(other.public_key().verify(b"tamper", &proof).is_err());
+    }
+}
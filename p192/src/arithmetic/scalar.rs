@@ -14,11 +14,7 @@
 
 #[cfg_attr(target_pointer_width = "32", path = "scalar/p192_scalar_32.rs")]
 #[cfg_attr(target_pointer_width = "64", path = "scalar/p192_scalar_64.rs")]
-#[allow(
-    clippy::identity_op,
-    clippy::too_many_arguments,
-    clippy::unnecessary_cast
-)]
+#[allow(clippy::too_many_arguments)]
 mod scalar_impl;
 
 use self::scalar_impl::*;
@@ -0,0 +1,30 @@
+//! ECDSA verification benchmarks.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use p256::ecdsa::{
+    precomputed::PrecomputedVerifyingKey,
+    signature::hazmat::{PrehashSigner, PrehashVerifier},
+    Signature, SigningKey,
+};
+use rand_core::OsRng;
+
+fn bench_verify(c: &mut Criterion) {
+    let signing_key = SigningKey::random(&mut OsRng);
+    let verifying_key = *signing_key.verifying_key();
+    let precomputed = PrecomputedVerifyingKey::new(verifying_key);
+
+    let prehash = [0xabu8; 32];
+    let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+
+    let mut group = c.benchmark_group("ecdsa");
+    group.bench_function("verify_prehash", |b| {
+        b.iter(|| verifying_key.verify_prehash(&prehash, &signature).unwrap())
+    });
+    group.bench_function("verify_prehash (precomputed key)", |b| {
+        b.iter(|| precomputed.verify_prehash(&prehash, &signature).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_verify);
+criterion_main!(benches);
@@ -24,25 +24,25 @@ fn bench_point_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let p = ProjectivePoint::GENERATOR;
     let m = test_scalar_x();
     let s = Scalar::from_repr(m.into()).unwrap();
-    group.bench_function("point-scalar mul", |b| b.iter(|| &p * &s));
+    group.bench_function("point-scalar mul", |b| b.iter(|| p * s));
 }
 
 fn bench_scalar_sub<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_scalar_x();
     let y = test_scalar_y();
-    group.bench_function("sub", |b| b.iter(|| &x - &y));
+    group.bench_function("sub", |b| b.iter(|| x - y));
 }
 
 fn bench_scalar_add<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_scalar_x();
     let y = test_scalar_y();
-    group.bench_function("add", |b| b.iter(|| &x + &y));
+    group.bench_function("add", |b| b.iter(|| x + y));
 }
 
 fn bench_scalar_mul<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     let x = test_scalar_x();
     let y = test_scalar_y();
-    group.bench_function("mul", |b| b.iter(|| &x * &y));
+    group.bench_function("mul", |b| b.iter(|| x * y));
 }
 
 fn bench_scalar_negate<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
@@ -55,9 +55,29 @@ fn bench_scalar_invert<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
     group.bench_function("invert", |b| b.iter(|| x.invert()));
 }
 
+fn bench_scalar_invert_vartime<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    let x = test_scalar_x();
+    group.bench_function("invert_vartime", |b| b.iter(|| x.invert_vartime()));
+}
+
+fn bench_point_lincomb<'a, M: Measurement>(group: &mut BenchmarkGroup<'a, M>) {
+    use elliptic_curve::ops::LinearCombination;
+
+    let p = ProjectivePoint::GENERATOR * test_scalar_x();
+    let q = ProjectivePoint::GENERATOR * test_scalar_y();
+    let (x, y) = (test_scalar_x(), test_scalar_y());
+    group.bench_function("lincomb", |b| {
+        b.iter(|| ProjectivePoint::lincomb(&p, &x, &q, &y))
+    });
+    group.bench_function("lincomb_vartime", |b| {
+        b.iter(|| ProjectivePoint::lincomb_vartime(&[(p, x), (q, y)]))
+    });
+}
+
 fn bench_point(c: &mut Criterion) {
     let mut group = c.benchmark_group("point operations");
     bench_point_mul(&mut group);
+    bench_point_lincomb(&mut group);
     group.finish();
 }
 
@@ -68,6 +88,7 @@ fn bench_scalar(c: &mut Criterion) {
     bench_scalar_mul(&mut group);
     bench_scalar_negate(&mut group);
     bench_scalar_invert(&mut group);
+    bench_scalar_invert_vartime(&mut group);
     group.finish();
 }
 
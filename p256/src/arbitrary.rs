@@ -0,0 +1,157 @@
+//! `Arbitrary` support for structured fuzzing.
+//!
+//! [`Scalar`] (and [`FieldElement`] under `expose-field`) implement
+//! [`Arbitrary`] directly. [`NonZeroScalar`], [`AffinePoint`], and
+//! [`ecdsa::Signature`] are foreign generic types, which the orphan rules
+//! keep us from implementing [`Arbitrary`] for; [`non_zero_scalar`],
+//! [`affine_point`], and [`signature`] are drop-in constructors for use
+//! with `#[arbitrary(with = ...)]` on derived fuzz inputs.
+//!
+//! Generated values are always *valid* — scalars canonical, points on the
+//! curve, signature components non-zero — so fuzzers exercise protocol
+//! logic rather than parser rejection paths. Boundary values (one, `n - 1`)
+//! are biased in so adversarial edge cases appear with non-negligible
+//! probability. Generation never panics for any input byte stream.
+//!
+//! [`FieldElement`]: crate::FieldElement
+//! [`ecdsa::Signature`]: crate::ecdsa::Signature
+
+use crate::{AffinePoint, NonZeroScalar, ProjectivePoint, Scalar, U256};
+use arbitrary::{Arbitrary, Unstructured};
+use elliptic_curve::ops::Reduce;
+
+impl<'a> Arbitrary<'a> for Scalar {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        let bytes = <[u8; 32]>::arbitrary(u)?;
+        Ok(<Self as Reduce<U256>>::reduce_bytes(&bytes.into()))
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 32]>::size_hint(depth)
+    }
+}
+
+#[cfg(feature = "expose-field")]
+impl<'a> Arbitrary<'a> for crate::FieldElement {
+    fn arbitrary(u: &mut Unstructured<'a>) -> arbitrary::Result<Self> {
+        // clearing the top bit guarantees the value is below the field
+        // modulus (2^255 < p), so conversion cannot fail
+        let mut bytes = <[u8; 32]>::arbitrary(u)?;
+        bytes[0] &= 0x7f;
+
+        #[allow(clippy::unwrap_used)]
+        Ok(Option::from(Self::from_bytes(bytes.into())).unwrap())
+    }
+
+    fn size_hint(depth: usize) -> (usize, Option<usize>) {
+        <[u8; 32]>::size_hint(depth)
+    }
+}
+
+/// Generate a [`NonZeroScalar`], with the boundary values `1` and `n - 1`
+/// each biased in at probability 1/32.
+pub fn non_zero_scalar(u: &mut Unstructured<'_>) -> arbitrary::Result<NonZeroScalar> {
+    let scalar = match u8::arbitrary(u)? % 32 {
+        0 => Scalar::ONE,
+        1 => -Scalar::ONE,
+        _ => Scalar::arbitrary(u)?,
+    };
+
+    // map the (negligible-probability) zero to one rather than failing
+    Ok(Option::from(NonZeroScalar::new(scalar)).unwrap_or_else(|| {
+        #[allow(clippy::unwrap_used)]
+        Option::<NonZeroScalar>::from(NonZeroScalar::new(Scalar::ONE)).unwrap()
+    }))
+}
+
+/// Generate a curve point by multiplying the generator with an arbitrary
+/// scalar: always on the curve, and the identity appears exactly for the
+/// zero scalar.
+pub fn affine_point(u: &mut Unstructured<'_>) -> arbitrary::Result<AffinePoint> {
+    let scalar = Scalar::arbitrary(u)?;
+    Ok((ProjectivePoint::GENERATOR * scalar).to_affine())
+}
+
+/// Generate a well-formed [`ecdsa::Signature`] with non-zero (and
+/// boundary-biased) `r` and `s` components.
+///
+/// [`ecdsa::Signature`]: crate::ecdsa::Signature
+#[cfg(feature = "ecdsa-core")]
+pub fn signature(u: &mut Unstructured<'_>) -> arbitrary::Result<crate::ecdsa::Signature> {
+    let r = non_zero_scalar(u)?;
+    let s = non_zero_scalar(u)?;
+
+    // both components are non-zero, so `from_scalars` cannot fail
+    #[allow(clippy::unwrap_used)]
+    Ok(crate::ecdsa::Signature::from_scalars(r.to_bytes(), s.to_bytes()).unwrap())
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::*;
+    use elliptic_curve::{
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+        Field,
+    };
+    use proptest::{collection::vec, prelude::*};
+
+    proptest! {
+        // fuzz-like: every byte stream, including empty and truncated
+        // ones, must produce valid values without panicking
+        #[test]
+        fn generation_never_panics_and_yields_valid_values(
+            data in vec(any::<u8>(), 0..128)
+        ) {
+            let mut u = Unstructured::new(&data);
+
+            let scalar = Scalar::arbitrary(&mut u).unwrap();
+            assert_eq!(
+                <Scalar as Reduce<U256>>::reduce_bytes(&scalar.to_bytes()),
+                scalar
+            );
+
+            let nz = non_zero_scalar(&mut u).unwrap();
+            assert!(!bool::from(nz.as_ref().is_zero()));
+
+            let point = affine_point(&mut u).unwrap();
+            if point != AffinePoint::IDENTITY {
+                // round-trips through SEC1, which validates the curve equation
+                let encoded = point.to_encoded_point(false);
+                let decoded = Option::<AffinePoint>::from(
+                    AffinePoint::from_encoded_point(&encoded),
+                );
+                assert_eq!(decoded.unwrap(), point);
+            }
+
+            #[cfg(feature = "ecdsa-core")]
+            {
+                let sig = signature(&mut u).unwrap();
+                assert!(!bool::from(sig.r().as_ref().is_zero()));
+                assert!(!bool::from(sig.s().as_ref().is_zero()));
+            }
+
+            #[cfg(feature = "expose-field")]
+            {
+                let fe = crate::FieldElement::arbitrary(&mut u).unwrap();
+                assert_eq!(
+                    crate::FieldElement::from_bytes(fe.to_bytes()).unwrap(),
+                    fe
+                );
+            }
+        }
+
+        #[test]
+        fn boundary_scalars_are_reachable(selector in any::<u8>()) {
+            let data = [selector];
+            let mut u = Unstructured::new(&data);
+            let nz = non_zero_scalar(&mut u).unwrap();
+
+            if selector % 32 == 0 {
+                assert_eq!(*nz.as_ref(), Scalar::ONE);
+            } else if selector % 32 == 1 {
+                assert_eq!(*nz.as_ref(), -Scalar::ONE);
+            }
+        }
+    }
+}
@@ -39,10 +39,9 @@ impl PrimeCurveParams for NistP256 {
     type PointArithmetic = point_arithmetic::EquationAIsMinusThree;
 
     /// a = -3
-    const EQUATION_A: FieldElement = FieldElement::from_u64(3).neg();
+    const EQUATION_A: FieldElement = FieldElement::EQUATION_A;
 
-    const EQUATION_B: FieldElement =
-        FieldElement::from_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b");
+    const EQUATION_B: FieldElement = FieldElement::EQUATION_B;
 
     /// Base point of P-256.
     ///
@@ -56,4 +55,56 @@ impl PrimeCurveParams for NistP256 {
         FieldElement::from_hex("6b17d1f2e12c4247f8bce6e563a440f277037d812deb33a0f4a13945d898c296"),
         FieldElement::from_hex("4fe342e2fe1a7f9b8ee7eb4a7c0f9e162bce33576b315ececbb6406837bf51f5"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(scalar: &Scalar) -> ProjectivePoint {
+        GENERATOR_TABLE.mul(scalar)
+    }
+}
+
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(any(feature = "std", feature = "critical-section"))
+))]
+compile_error!("`precomputed-tables` feature requires either `std` or `critical-section`");
+
+/// Lazily computed fixed-base table for the P-256 generator (33 radix-16
+/// windows spaced two steps apart, ~25 KiB of points), used to accelerate
+/// [`MulByGenerator`][`elliptic_curve::ops::MulByGenerator`] - and with it
+/// `ecdsa::SigningKey` generation and signing.
+///
+/// A single size is provided rather than feature-selected small/large
+/// variants: the table shape is fixed by the radix-16 recoding (one
+/// window per scalar byte plus a carry), so a smaller table would need a
+/// different multiplication algorithm, and because cargo unions features
+/// across a dependency graph, mutually exclusive size selectors would
+/// let one crate silently change another's memory footprint. ~25 KiB is
+/// the resulting middle ground; builds that cannot afford it should
+/// leave `precomputed-tables` off and keep the generic path.
+#[cfg(feature = "precomputed-tables")]
+static GENERATOR_TABLE: once_cell::sync::Lazy<primeorder::FixedBaseTable<NistP256, 33>> =
+    once_cell::sync::Lazy::new(|| primeorder::FixedBaseTable::new(&ProjectivePoint::GENERATOR));
+
+#[cfg(all(test, feature = "precomputed-tables"))]
+mod generator_table_tests {
+    use super::{ProjectivePoint, Scalar};
+    use elliptic_curve::{ops::MulByGenerator, rand_core::OsRng, Field};
+
+    #[test]
+    fn mul_by_generator_matches_generic_mul() {
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+            assert_eq!(
+                ProjectivePoint::mul_by_generator(&scalar),
+                ProjectivePoint::GENERATOR * scalar
+            );
+        }
+
+        for _ in 0..200 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(
+                ProjectivePoint::mul_by_generator(&scalar),
+                ProjectivePoint::GENERATOR * scalar
+            );
+        }
+    }
 }
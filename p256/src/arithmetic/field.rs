@@ -38,7 +38,30 @@ const R2: FieldElement = FieldElement(U256::from_be_hex(
 /// An element in the finite field modulo p = 2^{224}(2^{32} − 1) + 2^{192} + 2^{96} − 1.
 ///
 /// The internal representation is in little-endian order. Elements are always in
-/// Montgomery form; i.e., FieldElement(a) = aR mod p, with R = 2^256.
+/// Montgomery form; i.e., FieldElement(a) = aR mod p, with R = 2^256. The
+/// Montgomery form is an internal detail: the only stable interchange format
+/// is the canonical big-endian SEC1 encoding used by [`FieldElement::from_bytes`]
+/// (which rejects values `>= p`) and [`FieldElement::to_bytes`].
+///
+/// This type is only exposed under the off-by-default `expose-field` feature,
+/// for out-of-tree constructions which need raw field arithmetic (custom point
+/// compression, isogeny evaluation, etc.). Verifying the curve equation for
+/// the generator:
+///
+/// ```
+/// # #[cfg(feature = "expose-field")]
+/// # {
+/// use p256::{elliptic_curve::sec1::ToEncodedPoint, AffinePoint, FieldElement};
+///
+/// let encoded = AffinePoint::GENERATOR.to_encoded_point(false);
+/// let x = FieldElement::from_bytes(*encoded.x().unwrap()).unwrap();
+/// let y = FieldElement::from_bytes(*encoded.y().unwrap()).unwrap();
+///
+/// // y² = x³ + a·x + b with a = -3
+/// let y2 = x.square() * x + FieldElement::EQUATION_A * x + FieldElement::EQUATION_B;
+/// assert_eq!(y.square(), y2);
+/// # }
+/// ```
 #[derive(Clone, Copy, Debug)]
 pub struct FieldElement(pub(crate) U256);
 
@@ -49,6 +72,13 @@ impl FieldElement {
     /// Multiplicative identity.
     pub const ONE: Self = R;
 
+    /// The `a` coefficient of the short Weierstrass curve equation: `-3`.
+    pub const EQUATION_A: Self = FieldElement::sub(&Self::ZERO, &Self::from_u64(3));
+
+    /// The `b` coefficient of the short Weierstrass curve equation.
+    pub const EQUATION_B: Self =
+        Self::from_hex("5ac635d8aa3a93e7b3ebbd55769886bc651d06b0cc53b0f63bce3c3e27d2604b");
+
     /// Attempts to parse the given byte array as an SEC1-encoded field element.
     ///
     /// Returns None if the byte array does not contain a big-endian integer in the range
@@ -423,12 +453,10 @@ impl Field for FieldElement {
         FieldElement::from_bytes_wide(buf)
     }
 
-    #[must_use]
     fn square(&self) -> Self {
         self.square()
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         self.double()
     }
@@ -642,7 +670,7 @@ impl Neg for &FieldElement {
 
 impl Sum for FieldElement {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Add::add).unwrap_or(Self::ZERO)
+        iter.reduce(Add::add).unwrap_or(Self::ZERO)
     }
 }
 
@@ -654,7 +682,7 @@ impl<'a> Sum<&'a FieldElement> for FieldElement {
 
 impl Product for FieldElement {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Mul::mul).unwrap_or(Self::ONE)
+        iter.reduce(Mul::mul).unwrap_or(Self::ONE)
     }
 }
 
@@ -723,8 +751,8 @@ mod tests {
     #[test]
     fn repeated_add() {
         let mut r = FieldElement::ONE;
-        for i in 0..DBL_TEST_VECTORS.len() {
-            assert_eq!(r.to_bytes(), DBL_TEST_VECTORS[i].into());
+        for &vector in DBL_TEST_VECTORS {
+            assert_eq!(r.to_bytes(), vector.into());
             r = r + &r;
         }
     }
@@ -732,8 +760,8 @@ mod tests {
     #[test]
     fn repeated_double() {
         let mut r = FieldElement::ONE;
-        for i in 0..DBL_TEST_VECTORS.len() {
-            assert_eq!(r.to_bytes(), DBL_TEST_VECTORS[i].into());
+        for &vector in DBL_TEST_VECTORS {
+            assert_eq!(r.to_bytes(), vector.into());
             r = r.double();
         }
     }
@@ -742,8 +770,8 @@ mod tests {
     fn repeated_mul() {
         let mut r = FieldElement::ONE;
         let two = r + &r;
-        for i in 0..DBL_TEST_VECTORS.len() {
-            assert_eq!(r.to_bytes(), DBL_TEST_VECTORS[i].into());
+        for &vector in DBL_TEST_VECTORS {
+            assert_eq!(r.to_bytes(), vector.into());
             r = r * &two;
         }
     }
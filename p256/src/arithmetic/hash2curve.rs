@@ -4,15 +4,62 @@ use elliptic_curve::{
     bigint::{ArrayEncoding, U256},
     consts::U48,
     generic_array::GenericArray,
-    hash2curve::{FromOkm, GroupDigest, MapToCurve, OsswuMap, OsswuMapParams, Sgn0},
+    hash2curve::{ExpandMsg, FromOkm, GroupDigest, MapToCurve, OsswuMap, OsswuMapParams, Sgn0},
     point::DecompressPoint,
     subtle::Choice,
+    Result,
 };
 
 impl GroupDigest for NistP256 {
     type FieldElement = FieldElement;
 }
 
+impl NistP256 {
+    /// Hash to a curve point with an explicitly chosen [RFC 9380]
+    /// expander.
+    ///
+    /// `ExpandMsgXmd<Sha256>` gives the standardized
+    /// `P256_XMD:SHA-256_SSWU_RO_` suite; XOF expanders such as
+    /// `ExpandMsgXof<Shake128>` give `P256_XOF:SHAKE128_SSWU_RO_`-style
+    /// suites. Each field element draws `L = 48` bytes of expander output —
+    /// the RFC 9380 length `ceil((log2(p) + k) / 8)` for P-256's 128-bit
+    /// security parameter `k` — so XOF suites satisfy the k-security
+    /// length rule by construction.
+    ///
+    /// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+    pub fn hash_from_bytes_with_expander<'a, X: ExpandMsg<'a>>(
+        msgs: &[&[u8]],
+        dsts: &'a [&'a [u8]],
+    ) -> Result<ProjectivePoint> {
+        validate_dst(dsts)?;
+        <Self as GroupDigest>::hash_from_bytes::<X>(msgs, dsts)
+    }
+
+    /// Hash to a scalar with an explicitly chosen [RFC 9380] expander; see
+    /// [`Self::hash_from_bytes_with_expander`].
+    ///
+    /// [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+    pub fn hash_to_scalar_with_expander<'a, X: ExpandMsg<'a>>(
+        msgs: &[&[u8]],
+        dsts: &'a [&'a [u8]],
+    ) -> Result<Scalar> {
+        validate_dst(dsts)?;
+        <Self as GroupDigest>::hash_to_scalar::<X>(msgs, dsts)
+    }
+}
+
+/// RFC 9380 requires a non-empty domain separation tag; an empty DST
+/// destroys domain separation entirely, so reject it with an error rather
+/// than producing output. (DSTs longer than 255 bytes are handled by the
+/// `H2C-OVERSIZE-DST-` hashing fallback in the expander itself.)
+fn validate_dst(dsts: &[&[u8]]) -> Result<()> {
+    if dsts.iter().map(|part| part.len()).sum::<usize>() == 0 {
+        return Err(elliptic_curve::Error);
+    }
+
+    Ok(())
+}
+
 impl FromOkm for FieldElement {
     type Length = U48;
 
@@ -300,6 +347,98 @@ mod tests {
         }
     }
 
+    /// Test vectors from RFC 9380 Appendix K (`expand_message_xof`,
+    /// SHAKE128).
+    #[test]
+    fn expand_message_xof_shake128() {
+        use elliptic_curve::hash2curve::{ExpandMsg, ExpandMsgXof, Expander};
+        use sha3::Shake128;
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-expander-SHAKE128";
+
+        let cases: [(&[u8], [u8; 32]); 3] = [
+            (
+                b"",
+                hex!("86518c9cd86581486e9485aa74ab35ba150d1c75c88e26b7043e44e2acd735a2"),
+            ),
+            (
+                b"abc",
+                hex!("8696af52a4d862417c0763556073f47bc9b9ba43c99b505305cb1ec04a9ab468"),
+            ),
+            (
+                b"abcdef0123456789",
+                hex!("912c58deac4821c3509dbefa094df54b34b8f5d01a191d1d3108a2c89077acca"),
+            ),
+        ];
+
+        for (msg, expected) in cases {
+            let mut expander =
+                ExpandMsgXof::<Shake128>::expand_message(&[msg], &[DST], 32).unwrap();
+            let mut uniform_bytes = [0u8; 32];
+            expander.fill_bytes(&mut uniform_bytes);
+            assert_eq!(uniform_bytes, expected);
+        }
+
+        // zero-length output is rejected, per the RFC's length rules
+        assert!(ExpandMsgXof::<Shake128>::expand_message(&[b""], &[DST], 0).is_err());
+
+        // and so is an empty DST at the suite entry points
+        assert!(
+            NistP256::hash_from_bytes_with_expander::<ExpandMsgXof<Shake128>>(&[b"x"], &[b""])
+                .is_err()
+        );
+        assert!(
+            NistP256::hash_to_scalar_with_expander::<ExpandMsgXof<Shake128>>(&[b"x"], &[]).is_err()
+        );
+    }
+
+    #[test]
+    fn shake128_suite_on_curve_and_deterministic() {
+        use elliptic_curve::hash2curve::{ExpandMsgXmd, ExpandMsgXof};
+        use sha3::Shake128;
+
+        const DST: &[u8] = b"P256_XOF:SHAKE128_SSWU_RO_TESTGEN";
+
+        let p1 = NistP256::hash_from_bytes_with_expander::<ExpandMsgXof<Shake128>>(
+            &[b"shake input"],
+            &[DST],
+        )
+        .unwrap();
+        let p2 = NistP256::hash_from_bytes_with_expander::<ExpandMsgXof<Shake128>>(
+            &[b"shake input"],
+            &[DST],
+        )
+        .unwrap();
+        assert_eq!(p1, p2);
+
+        // decodes through SEC1, which checks the curve equation
+        let affine = p1.to_affine();
+        let encoded = affine.to_encoded_point(false);
+        assert_eq!(crate::AffinePoint::try_from(&encoded).unwrap(), affine);
+
+        // distinct from the XMD suite with the same inputs
+        let xmd = NistP256::hash_from_bytes_with_expander::<ExpandMsgXmd<Sha256>>(
+            &[b"shake input"],
+            &[DST],
+        )
+        .unwrap();
+        assert_ne!(p1, xmd);
+
+        // scalars hash deterministically too
+        let s1 = NistP256::hash_to_scalar_with_expander::<ExpandMsgXof<Shake128>>(
+            &[b"shake input"],
+            &[DST],
+        )
+        .unwrap();
+        assert!(!bool::from(s1.is_zero()));
+
+        // each field element draws L = 48 = ceil((256 + 128) / 8) bytes,
+        // the RFC 9380 length for the k = 128 security parameter
+        use elliptic_curve::generic_array::typenum::Unsigned;
+        assert_eq!(<FieldElement as FromOkm>::Length::USIZE, 48);
+        assert_eq!(<Scalar as FromOkm>::Length::USIZE, 48);
+    }
+
     #[test]
     fn from_okm_fuzz() {
         let mut wide_order = GenericArray::default();
@@ -134,6 +134,20 @@ impl Scalar {
         CtOption::new(self.invert_unchecked(), !self.is_zero())
     }
 
+    /// Fast variable-time inversion using Stein's algorithm.
+    ///
+    /// Used by signature verification, which operates on public values; see
+    /// [`Invert::invert_vartime`] for the trait form.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// This method should not be used with (unblinded) secret scalars, as
+    /// its variable-time operation can potentially leak secrets through
+    /// sidechannels. Signing uses the constant-time [`Scalar::invert`].
+    pub fn invert_vartime(&self) -> CtOption<Self> {
+        <Self as Invert>::invert_vartime(self)
+    }
+
     /// Returns the multiplicative inverse of self.
     ///
     /// Does not check that self is non-zero.
@@ -220,12 +234,10 @@ impl Field for Scalar {
         }
     }
 
-    #[must_use]
     fn square(&self) -> Self {
         Scalar::square(self)
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         self.add(self)
     }
@@ -650,7 +662,7 @@ impl Neg for Scalar {
     }
 }
 
-impl<'a> Neg for &'a Scalar {
+impl Neg for &Scalar {
     type Output = Scalar;
 
     fn neg(self) -> Scalar {
@@ -687,7 +699,7 @@ impl ReduceNonZero<U256> for Scalar {
 
 impl Sum for Scalar {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Add::add).unwrap_or(Self::ZERO)
+        iter.reduce(Add::add).unwrap_or(Self::ZERO)
     }
 }
 
@@ -699,7 +711,7 @@ impl<'a> Sum<&'a Scalar> for Scalar {
 
 impl Product for Scalar {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Mul::mul).unwrap_or(Self::ONE)
+        iter.reduce(Mul::mul).unwrap_or(Self::ONE)
     }
 }
 
@@ -770,6 +782,19 @@ mod tests {
     impl_field_sqrt_tests!(Scalar);
     impl_primefield_tests!(Scalar, T);
 
+    #[test]
+    fn invert_vartime_agrees_with_invert() {
+        use elliptic_curve::rand_core::OsRng;
+
+        assert!(bool::from(Scalar::ZERO.invert_vartime().is_none()));
+        assert_eq!(Scalar::ONE.invert_vartime().unwrap(), Scalar::ONE);
+
+        for _ in 0..10_000 {
+            let x = Scalar::random(&mut OsRng);
+            assert_eq!(x.invert_vartime().unwrap(), x.invert().unwrap(), "{x:?}");
+        }
+    }
+
     #[test]
     fn from_to_bytes_roundtrip() {
         let k: u64 = 42;
@@ -784,17 +809,17 @@ mod tests {
     #[test]
     fn multiply() {
         let one = Scalar::ONE;
-        let two = one + &one;
-        let three = two + &one;
-        let six = three + &three;
-        assert_eq!(six, two * &three);
+        let two = one + one;
+        let three = two + one;
+        let six = three + three;
+        assert_eq!(six, two * three);
 
         let minus_two = -two;
         let minus_three = -three;
         assert_eq!(two, -minus_two);
 
-        assert_eq!(minus_three * &minus_two, minus_two * &minus_three);
-        assert_eq!(six, minus_two * &minus_three);
+        assert_eq!(minus_three * minus_two, minus_two * minus_three);
+        assert_eq!(six, minus_two * minus_three);
     }
 
     /// Tests that a Scalar can be safely converted to a SecretKey and back
@@ -0,0 +1,126 @@
+//! Deterministic secret key derivation from seed material.
+//!
+//! [`DeriveFromSeed`] derives P-256 secret keys from a master secret plus a
+//! label using the [RFC 9380] `hash_to_field` machinery
+//! (`expand_message_xmd` with SHA-256, `L = 48`), so derived scalars are
+//! uniformly distributed mod `n`. The derivation is **deterministic and
+//! stable across releases**: the DST, the message framing
+//! `ikm || I2OSP(len(info), 2) || info || I2OSP(counter, 1)`, and the
+//! counter retry rule (increment on a zero scalar, as in RFC 9497's
+//! `DeriveKeyPair`) are all fixed and pinned by test vectors.
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+
+use crate::{NistP256, NonZeroScalar, SecretKey};
+use elliptic_curve::hash2curve::ExpandMsgXmd;
+use sha2::Sha256;
+
+/// Domain separation tag; versioned so any future change must use a new
+/// tag rather than silently altering derived keys.
+const DST: &[u8] = b"DeriveSecretKey-P256_XMD:SHA-256_v1";
+
+/// Deterministic derivation of a key from seed material and a label.
+pub trait DeriveFromSeed: Sized {
+    /// Derive a key from initial keying material (e.g. a master secret)
+    /// and a distinguishing label.
+    ///
+    /// Deterministic: the same `(ikm, info)` pair always yields the same
+    /// key. The caller is responsible for the entropy of `ikm`.
+    ///
+    /// # Panics
+    ///
+    /// If `info` exceeds 65535 bytes, which the length-prefixed framing
+    /// cannot represent.
+    fn derive_from_seed(ikm: &[u8], info: &[u8]) -> Self;
+}
+
+impl DeriveFromSeed for SecretKey {
+    fn derive_from_seed(ikm: &[u8], info: &[u8]) -> Self {
+        // the 2-byte length prefix keeps the framing injective; longer
+        // labels cannot be represented, so fail loudly rather than alias
+        #[allow(clippy::expect_used)]
+        let info_len = u16::try_from(info.len())
+            .expect("info must be at most 65535 bytes")
+            .to_be_bytes();
+
+        for counter in 0u8..=255 {
+            let scalar = NistP256::hash_to_scalar_with_expander::<ExpandMsgXmd<Sha256>>(
+                &[ikm, &info_len, info, &[counter]],
+                &[DST],
+            )
+            .expect("fixed non-empty DST cannot fail");
+
+            if let Some(scalar) = Option::<NonZeroScalar>::from(NonZeroScalar::new(scalar)) {
+                return SecretKey::from(scalar);
+            }
+        }
+
+        // 256 consecutive zero scalars: probability ~2^-65536
+        unreachable!("hash_to_scalar returned zero for every counter")
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl DeriveFromSeed for crate::ecdsa::SigningKey {
+    fn derive_from_seed(ikm: &[u8], info: &[u8]) -> Self {
+        SecretKey::derive_from_seed(ikm, info).into()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::DeriveFromSeed;
+    use crate::SecretKey;
+    use hex_literal::hex;
+
+    // Pinned vectors: these must never change across releases. Generated
+    // with an independent implementation of the documented derivation.
+    #[test]
+    fn pinned_derivation_vectors() {
+        let cases: [(&[u8], &[u8], [u8; 32]); 3] = [
+            (
+                b"",
+                b"",
+                hex!("2adf98501ae600cfe195d66abf5e45b1122afc36603519f7f242880b78e13e43"),
+            ),
+            (
+                &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+                b"signing key v1",
+                hex!("d9a2b1930833dcb638f43969ff477465c63b23b48e643b9b3c272d4b41843137"),
+            ),
+            (
+                &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+                b"signing key v2",
+                hex!("46650807c0dc67c62e67b54395713c18e7e7256e4d081ccb40afa334f35d1e8e"),
+            ),
+        ];
+
+        for (ikm, info, expected) in cases {
+            let key = SecretKey::derive_from_seed(ikm, info);
+            assert_eq!(key.to_bytes().as_slice(), &expected);
+        }
+    }
+
+    #[test]
+    fn deterministic_and_label_separated() {
+        let a = SecretKey::derive_from_seed(b"master", b"label-1");
+        let b = SecretKey::derive_from_seed(b"master", b"label-1");
+        let c = SecretKey::derive_from_seed(b"master", b"label-2");
+        let d = SecretKey::derive_from_seed(b"other master", b"label-1");
+
+        assert_eq!(a.to_bytes(), b.to_bytes());
+        assert_ne!(a.to_bytes(), c.to_bytes());
+        assert_ne!(a.to_bytes(), d.to_bytes());
+    }
+
+    #[cfg(feature = "ecdsa")]
+    #[test]
+    fn signing_key_matches_secret_key() {
+        use crate::ecdsa::SigningKey;
+
+        let secret = SecretKey::derive_from_seed(b"master", b"ecdsa");
+        let signing = SigningKey::derive_from_seed(b"master", b"ecdsa");
+        assert_eq!(signing.to_bytes(), secret.to_bytes());
+    }
+}
@@ -38,9 +38,43 @@
 //! assert!(verifying_key.verify(message, &signature).is_ok());
 //! # }
 //! ```
+//!
+//! ## Recoverable Signatures
+//!
+//! [`SigningKey::sign_prehash_recoverable`] returns a [`RecoveryId`]
+//! alongside the signature, from which
+//! [`VerifyingKey::recover_from_prehash`] can reconstruct the public key:
+//!
+//! ```
+//! # #[cfg(feature = "ecdsa")]
+//! # {
+//! use p256::ecdsa::{SigningKey, VerifyingKey};
+//! use rand_core::OsRng;
+//! use sha2::{Digest, Sha256};
+//!
+//! let signing_key = SigningKey::random(&mut OsRng);
+//! let prehash = Sha256::digest(b"compact attestation");
+//!
+//! let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+//! let recovered = VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id).unwrap();
+//! assert_eq!(&recovered, signing_key.verifying_key());
+//! # }
+//! ```
+
+#[cfg(all(feature = "ecdsa", feature = "alloc", feature = "sha256"))]
+pub mod batch;
+
+#[cfg(feature = "ecdsa")]
+pub mod blinded;
+
+#[cfg(feature = "ecdsa")]
+pub mod precomputed;
 
 pub use ecdsa_core::signature::{self, Error};
 
+#[cfg(feature = "ecdsa")]
+pub use ecdsa_core::RecoveryId;
+
 use super::NistP256;
 
 #[cfg(feature = "ecdsa")]
@@ -72,7 +106,39 @@ impl ecdsa_core::hazmat::DigestPrimitive for NistP256 {
 impl SignPrimitive<NistP256> for Scalar {}
 
 #[cfg(feature = "ecdsa")]
-impl VerifyPrimitive<NistP256> for AffinePoint {}
+impl VerifyPrimitive<NistP256> for AffinePoint {
+    /// Route verification through the variable-time Strauss–Shamir path:
+    /// every input to `u1*G + u2*Q` is public, so the constant-time
+    /// machinery only costs time. Disable the default-on `verify-vartime`
+    /// feature to fall back to the constant-time implementation.
+    #[cfg(feature = "verify-vartime")]
+    fn verify_prehashed(
+        &self,
+        z: &elliptic_curve::FieldBytes<NistP256>,
+        sig: &Signature,
+    ) -> Result<(), Error> {
+        use elliptic_curve::{
+            group::prime::PrimeCurveAffine, ops::Reduce, point::AffineCoordinates,
+        };
+
+        let z = <Scalar as Reduce<crate::U256>>::reduce_bytes(z);
+        let (r, s) = sig.split_scalars();
+        let s_inv = *<crate::NonZeroScalar as elliptic_curve::ops::Invert>::invert_vartime(&s);
+
+        let x = crate::ProjectivePoint::lincomb_vartime(&[
+            (crate::ProjectivePoint::GENERATOR, z * s_inv),
+            (self.to_curve(), *r * s_inv),
+        ])
+        .to_affine()
+        .x();
+
+        if *r == <Scalar as Reduce<crate::U256>>::reduce_bytes(&x) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
 
 #[cfg(all(test, feature = "ecdsa"))]
 mod tests {
@@ -195,4 +261,74 @@ mod tests {
         use crate::NistP256;
         ecdsa_core::new_wycheproof_test!(wycheproof, "wycheproof", NistP256);
     }
+
+    mod recovery {
+        use crate::ecdsa::{
+            signature::hazmat::PrehashVerifier, RecoveryId, Signature, SigningKey, VerifyingKey,
+        };
+        use elliptic_curve::rand_core::OsRng;
+        use hex_literal::hex;
+
+        #[test]
+        fn round_trips_for_random_keys() {
+            let mut seen = [false; 2];
+
+            for i in 0..100u32 {
+                let signing_key = SigningKey::random(&mut OsRng);
+                let mut prehash = [0u8; 32];
+                prehash[..4].copy_from_slice(&i.to_be_bytes());
+
+                let (signature, recovery_id) =
+                    signing_key.sign_prehash_recoverable(&prehash).unwrap();
+                assert!(!recovery_id.is_x_reduced());
+                seen[usize::from(recovery_id.to_byte())] = true;
+
+                let recovered =
+                    VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id).unwrap();
+                assert_eq!(&recovered, signing_key.verifying_key());
+            }
+
+            // both y parities occur over 100 random nonces
+            assert!(seen[0] && seen[1]);
+        }
+
+        // The reduced-x case cannot be hit by random signing (probability
+        // ~2^-129 per nonce, as p - n is ~2^127), so exercise it with a
+        // synthetic signature built around the curve point whose
+        // x-coordinate is n + 3: recovery lifts r = 3 back to x = r + n,
+        // and the recovered key verifies the signature by construction.
+        #[test]
+        fn x_reduced_recovery_ids() {
+            let prehash = hex!("7f83b1657ff1fc53b92dc18148a1d65dfc2d4b1fa3d677284addd200126d9069");
+            let r = hex!("0000000000000000000000000000000000000000000000000000000000000003");
+            let s = hex!("5fbeb35e1e9b4d0e1c1b1a8b9c21a4c6c8e88c9a9f3e9f1b8d5a9e3c2b1a0918");
+            let signature = Signature::from_scalars(r, s).unwrap();
+
+            for recovery_id in [
+                RecoveryId::new(false, true), // byte 2
+                RecoveryId::new(true, true),  // byte 3
+            ] {
+                assert!(recovery_id.is_x_reduced());
+
+                let recovered =
+                    VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id).unwrap();
+                recovered.verify_prehash(&prehash, &signature).unwrap();
+            }
+
+            // the two parities recover distinct keys
+            let even = VerifyingKey::recover_from_prehash(
+                &prehash,
+                &signature,
+                RecoveryId::new(false, true),
+            )
+            .unwrap();
+            let odd = VerifyingKey::recover_from_prehash(
+                &prehash,
+                &signature,
+                RecoveryId::new(true, true),
+            )
+            .unwrap();
+            assert_ne!(even, odd);
+        }
+    }
 }
@@ -0,0 +1,254 @@
+//! Batch ECDSA verification.
+
+use super::{RecoveryId, Signature, VerifyingKey};
+use crate::{AffinePoint, EncodedPoint, FieldBytes, NistP256, ProjectivePoint, Scalar};
+use alloc::{vec, vec::Vec};
+use elliptic_curve::{
+    bigint::{ArrayEncoding, U256},
+    group::Group,
+    ops::Reduce,
+    rand_core::CryptoRngCore,
+    sec1::FromEncodedPoint,
+    Curve, PrimeField,
+};
+use sha2::{Digest, Sha256};
+
+/// Error returned by the batch verifiers.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct BatchVerifyError {
+    /// Index of the first invalid item, located by bisection after the
+    /// batched equation failed.
+    pub invalid_index: Option<usize>,
+}
+
+/// Batch item: a verifying key, a 32-byte prehash, the signature, and its
+/// recovery ID.
+///
+/// P-256 has no endomorphism, so the combination is a plain
+/// Pippenger multiscalar multiplication over the inversion-free relation
+/// `z_i*G + r_i*P_i - s_i*R_i == O`. The nonce point `R_i` is
+/// reconstructed from the signature's [`RecoveryId`]: ECDSA's x-only `r`
+/// loses the nonce parity, and without it the equations cannot be
+/// linearized into one multiexponentiation. Recovery IDs come from
+/// [`SigningKey::sign_prehash_recoverable`].
+///
+/// [`SigningKey::sign_prehash_recoverable`]: super::SigningKey::sign_prehash_recoverable
+pub type BatchItem = (VerifyingKey, [u8; 32], Signature, RecoveryId);
+
+/// Verify a batch of signatures over SHA-256-hashed messages.
+///
+/// Variable time; answers "all valid / not all valid" with a bisection
+/// fallback locating the first invalid index.
+pub fn verify(
+    items: &[(VerifyingKey, &[u8], Signature, RecoveryId)],
+    rng: &mut impl CryptoRngCore,
+) -> Result<(), BatchVerifyError> {
+    let prehashed: Vec<BatchItem> = items
+        .iter()
+        .map(|(vk, msg, sig, recid)| (*vk, Sha256::digest(msg).into(), *sig, *recid))
+        .collect();
+
+    verify_prehash(&prehashed, rng)
+}
+
+/// Verify a batch of signatures over 32-byte prehashes.
+pub fn verify_prehash(
+    items: &[BatchItem],
+    rng: &mut impl CryptoRngCore,
+) -> Result<(), BatchVerifyError> {
+    if check_batch(items, rng) {
+        return Ok(());
+    }
+
+    Err(BatchVerifyError {
+        invalid_index: bisect(items, rng),
+    })
+}
+
+/// Single randomized batch check; `true` means every equation holds.
+fn check_batch(items: &[BatchItem], rng: &mut impl CryptoRngCore) -> bool {
+    if items.is_empty() {
+        return true;
+    }
+
+    let mut points = Vec::with_capacity(2 * items.len() + 1);
+    let mut scalars = Vec::with_capacity(2 * items.len() + 1);
+    let mut z_sum = Scalar::ZERO;
+
+    for (i, (verifying_key, prehash, signature, recovery_id)) in items.iter().enumerate() {
+        let Some(big_r) = recover_nonce_point(signature, recovery_id) else {
+            return false;
+        };
+
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(FieldBytes::from_slice(prehash));
+        let r = <Scalar as Reduce<U256>>::reduce_bytes(&signature.r().to_bytes());
+        let s = <Scalar as Reduce<U256>>::reduce_bytes(&signature.s().to_bytes());
+
+        let a = if i == 0 {
+            Scalar::ONE
+        } else {
+            random_128bit(rng)
+        };
+
+        z_sum += a * z;
+        points.push(ProjectivePoint::from(*verifying_key.as_affine()));
+        scalars.push(a * r);
+        points.push(big_r);
+        scalars.push(-(a * s));
+    }
+
+    points.push(ProjectivePoint::GENERATOR);
+    scalars.push(z_sum);
+
+    pippenger(&points, &scalars).is_identity().into()
+}
+
+/// Locate the first invalid index by bisection over randomized batch
+/// checks, falling back to `None` when the parts verify individually
+/// (possible only when the recovery IDs are inconsistent with otherwise
+/// valid signatures).
+fn bisect(items: &[BatchItem], rng: &mut impl CryptoRngCore) -> Option<usize> {
+    if items.is_empty() {
+        return None;
+    }
+
+    if items.len() == 1 {
+        use ecdsa_core::signature::hazmat::PrehashVerifier;
+
+        let (verifying_key, prehash, signature, _) = &items[0];
+        return if verifying_key.verify_prehash(prehash, signature).is_err() {
+            Some(0)
+        } else {
+            None
+        };
+    }
+
+    let mid = items.len() / 2;
+    let (left, right) = items.split_at(mid);
+
+    if !check_batch(left, rng) {
+        return bisect(left, rng);
+    }
+    if !check_batch(right, rng) {
+        return bisect(right, rng).map(|index| index + mid);
+    }
+
+    None
+}
+
+/// Reconstruct the nonce point from `r` and the recovery ID.
+fn recover_nonce_point(signature: &Signature, recovery_id: &RecoveryId) -> Option<ProjectivePoint> {
+    let mut x = U256::from_be_byte_array(signature.r().to_bytes());
+    if recovery_id.is_x_reduced() {
+        x = x.wrapping_add(&NistP256::ORDER);
+    }
+
+    let mut compressed = [0u8; 33];
+    compressed[0] = if recovery_id.is_y_odd() { 0x03 } else { 0x02 };
+    compressed[1..].copy_from_slice(&x.to_be_byte_array());
+
+    let encoded = EncodedPoint::from_bytes(compressed).ok()?;
+    Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded))
+        .map(ProjectivePoint::from)
+}
+
+/// 128-bit random scalar coefficient.
+fn random_128bit(rng: &mut impl CryptoRngCore) -> Scalar {
+    let mut bytes = [0u8; 32];
+    rng.fill_bytes(&mut bytes[16..]);
+    #[allow(clippy::unwrap_used)]
+    Option::<Scalar>::from(Scalar::from_repr(bytes.into())).unwrap()
+}
+
+/// Straightforward variable-time Pippenger bucket method (P-256 has no
+/// endomorphism to split scalars with).
+fn pippenger(points: &[ProjectivePoint], scalars: &[Scalar]) -> ProjectivePoint {
+    let window = match points.len() {
+        0..=11 => 3,
+        12..=47 => 4,
+        48..=139 => 5,
+        140..=409 => 6,
+        _ => 8,
+    };
+    let windows = (256 + window - 1) / window;
+    let bucket_count = (1usize << window) - 1;
+
+    let limbs: Vec<[u8; 32]> = scalars.iter().map(|s| s.to_repr().into()).collect();
+    let extract = |bytes: &[u8; 32], offset: usize, width: usize| -> usize {
+        let mut value = 0usize;
+        for bit in (0..width).rev() {
+            let pos = offset + bit;
+            if pos < 256 {
+                value = (value << 1) | (((bytes[31 - pos / 8] >> (pos % 8)) & 1) as usize);
+            } else {
+                value <<= 1;
+            }
+        }
+        value
+    };
+
+    let mut result = ProjectivePoint::IDENTITY;
+    for w in (0..windows).rev() {
+        for _ in 0..window {
+            result = result.double();
+        }
+
+        let mut buckets = vec![ProjectivePoint::IDENTITY; bucket_count];
+        for (point, bytes) in points.iter().zip(&limbs) {
+            let digit = extract(bytes, w * window, window);
+            if digit != 0 {
+                buckets[digit - 1] += point;
+            }
+        }
+
+        let mut running = ProjectivePoint::IDENTITY;
+        for bucket in buckets.iter().rev() {
+            running += bucket;
+            result += running;
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{verify, verify_prehash, BatchItem};
+    use crate::ecdsa::SigningKey;
+    use alloc::vec::Vec;
+    use elliptic_curve::rand_core::OsRng;
+
+    fn signed_batch(n: usize) -> Vec<BatchItem> {
+        (0..n)
+            .map(|i| {
+                let sk = SigningKey::random(&mut OsRng);
+                let mut prehash = [0u8; 32];
+                prehash[..8].copy_from_slice(&(i as u64).to_be_bytes());
+                let (sig, recid) = sk.sign_prehash_recoverable(&prehash).unwrap();
+                (*sk.verifying_key(), prehash, sig, recid)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn valid_batches_verify() {
+        verify_prehash(&signed_batch(64), &mut OsRng).unwrap();
+        verify_prehash(&[], &mut OsRng).unwrap();
+
+        // message-level variant
+        let sk = SigningKey::random(&mut OsRng);
+        let msg: &[u8] = b"attestation statement";
+        let (sig, recid) = sk.sign_recoverable(msg).unwrap();
+        verify(&[(*sk.verifying_key(), msg, sig, recid)], &mut OsRng).unwrap();
+    }
+
+    #[test]
+    fn corrupted_item_located_by_bisection() {
+        let mut items = signed_batch(100);
+        items[73].1[0] ^= 1;
+
+        let err = verify_prehash(&items, &mut OsRng).unwrap_err();
+        assert_eq!(err.invalid_index, Some(73));
+    }
+}
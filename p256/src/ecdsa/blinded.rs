@@ -0,0 +1,128 @@
+//! Scalar-blinded ECDSA signing.
+
+use super::{Signature, SigningKey};
+use crate::{NistP256, NonZeroScalar, ProjectivePoint, Scalar};
+use ecdsa_core::{
+    hazmat::bits2field,
+    signature::{Error, Result},
+};
+use elliptic_curve::{
+    ops::{MulByGenerator, Reduce},
+    point::AffineCoordinates,
+    rand_core::CryptoRngCore,
+    zeroize::Zeroize,
+    Curve, FieldBytesEncoding, PrimeField,
+};
+use sha2::Sha256;
+
+/// Deterministic ECDSA signing with multiplicative scalar blinding.
+///
+/// This is a countermeasure against side-channel analysis of the signing
+/// equation on shared hardware: with a blind `b` sampled fresh per
+/// signature, `s = k⁻¹(z + r·d)` is computed as
+/// `s = (b·k)⁻¹ · (b·z + r·(b·d))`, so neither the inversion nor any
+/// multiplication involving the secret key operates on an unmasked secret.
+/// The blind cancels algebraically, so the output is byte-identical to
+/// unblinded [RFC6979] signing for the same key and message.
+///
+/// [RFC6979]: https://datatracker.ietf.org/doc/html/rfc6979
+pub trait BlindedPrehashSigner {
+    /// Sign the given prehash with a freshly sampled multiplicative blind,
+    /// which is zeroized before returning.
+    fn sign_prehash_blinded(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<Signature>;
+}
+
+impl BlindedPrehashSigner for SigningKey {
+    fn sign_prehash_blinded(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<Signature> {
+        let z = bits2field::<NistP256>(prehash)?;
+        let d = self.as_nonzero_scalar();
+
+        // Same derivation as the unblinded RFC 6979 path: the raw (unreduced)
+        // digest bytes seed the HMAC-DRBG.
+        let mut k = Option::<Scalar>::from(Scalar::from_repr(rfc6979::generate_k::<Sha256, _>(
+            &d.to_repr(),
+            &FieldBytesEncoding::<NistP256>::encode_field_bytes(&NistP256::ORDER),
+            &z,
+            &[],
+        )))
+        .ok_or_else(Error::new)?;
+
+        let big_r = ProjectivePoint::mul_by_generator(&k).to_affine();
+        let r = <Scalar as Reduce<crate::U256>>::reduce_bytes(&big_r.x());
+        let z = <Scalar as Reduce<crate::U256>>::reduce_bytes(&z);
+
+        let mut b = *NonZeroScalar::random(rng);
+        let mut bd = b * d.as_ref();
+
+        // (b·k)⁻¹ = b⁻¹·k⁻¹; the product with b·z and r·(b·d) cancels the
+        // blind. Variable-time inversion is safe here because b·k is
+        // uniformly random (the same masking argument as `BlindedScalar`).
+        let bk_inv = Option::<Scalar>::from((b * k).invert_vartime());
+        k.zeroize();
+
+        let s = bk_inv.ok_or_else(Error::new)? * (b * z + r * bd);
+        b.zeroize();
+        bd.zeroize();
+
+        Signature::from_scalars(r, s)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::BlindedPrehashSigner;
+    use crate::ecdsa::{signature::hazmat::PrehashSigner, Signature, SigningKey};
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    // RFC 6979 Appendix 2.5 (NIST P-256 + SHA-256), message "sample"
+    #[test]
+    fn matches_rfc6979_vector() {
+        let x = hex!("c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721");
+        let signer = SigningKey::from_bytes(&x.into()).unwrap();
+        let prehash = Sha256::digest(b"sample");
+
+        let signature = signer.sign_prehash_blinded(&mut OsRng, &prehash).unwrap();
+        assert_eq!(
+            signature.to_bytes().as_slice(),
+            &hex!(
+                "efd48b2aacb6a8fd1140dd9cd45e81d69d2c877b56aaf991c34d0ea84eaf3716
+                 f7cb1c942d657c41d436c7a1b6e29f65f3e900dbb9aff4064dc4ab2f843acda8"
+            )
+        );
+    }
+
+    #[test]
+    fn byte_identical_to_unblinded_signing() {
+        for i in 0..100u32 {
+            let signer = SigningKey::random(&mut OsRng);
+            let prehash = Sha256::digest(i.to_be_bytes());
+
+            let blinded = signer.sign_prehash_blinded(&mut OsRng, &prehash).unwrap();
+            let unblinded: Signature = signer.sign_prehash(&prehash).unwrap();
+            assert_eq!(blinded, unblinded);
+        }
+    }
+
+    // bits2field handling must also agree for prehashes larger than the
+    // field size
+    #[test]
+    fn byte_identical_for_sha384_prehashes() {
+        let signer = SigningKey::random(&mut OsRng);
+        let prehash = sha2::Sha384::digest(b"oversized prehash");
+
+        let blinded = signer.sign_prehash_blinded(&mut OsRng, &prehash).unwrap();
+        let unblinded: Signature = signer.sign_prehash(&prehash).unwrap();
+        assert_eq!(blinded, unblinded);
+    }
+}
@@ -0,0 +1,166 @@
+//! Verification with a precomputed table for a pinned public key.
+
+use super::{Signature, VerifyingKey};
+use crate::{NistP256, ProjectivePoint, Scalar, U256};
+use ecdsa_core::{
+    hazmat::bits2field,
+    signature::{digest::Digest, hazmat::PrehashVerifier, DigestVerifier, Error, Result, Verifier},
+};
+use elliptic_curve::{
+    ops::{Invert, MulByGenerator, Reduce},
+    point::AffineCoordinates,
+};
+use primeorder::FixedBaseTable;
+
+/// A [`VerifyingKey`] paired with a precomputed multiplication table,
+/// amortizing per-verification cost when many signatures are checked
+/// against one pinned key (e.g. a firmware signing key).
+///
+/// Construction builds a fixed-base table of public-key multiples, so the
+/// `u2*Q` term of the verification equation becomes table lookups while
+/// `u1*G` uses the (optionally precomputed) generator path. The type is
+/// `Send + Sync` and is intended to be built once — e.g. in a `OnceLock`
+/// or `lazy_static` — and shared.
+///
+/// Accept/reject behavior is identical to [`VerifyingKey`].
+#[derive(Clone)]
+pub struct PrecomputedVerifyingKey {
+    verifying_key: VerifyingKey,
+    table: FixedBaseTable<NistP256, 33>,
+}
+
+impl PrecomputedVerifyingKey {
+    /// Build the precomputed table for the given verifying key.
+    pub fn new(verifying_key: VerifyingKey) -> Self {
+        let table = FixedBaseTable::new(&ProjectivePoint::from(*verifying_key.as_affine()));
+
+        Self {
+            verifying_key,
+            table,
+        }
+    }
+
+    /// The underlying verifying key.
+    pub fn verifying_key(&self) -> &VerifyingKey {
+        &self.verifying_key
+    }
+}
+
+impl From<VerifyingKey> for PrecomputedVerifyingKey {
+    fn from(verifying_key: VerifyingKey) -> Self {
+        Self::new(verifying_key)
+    }
+}
+
+impl PrehashVerifier<Signature> for PrecomputedVerifyingKey {
+    fn verify_prehash(&self, prehash: &[u8], signature: &Signature) -> Result<()> {
+        // identical prehash handling (incl. truncation/padding) to the
+        // plain verifier
+        let z_bytes = bits2field::<NistP256>(prehash)?;
+        let z = <Scalar as Reduce<U256>>::reduce_bytes(&z_bytes);
+        let (r, s) = signature.split_scalars();
+        let s_inv = *<crate::NonZeroScalar as Invert>::invert_vartime(&s);
+
+        let big_r = ProjectivePoint::mul_by_generator(&(z * s_inv)) + self.table.mul(&(*r * s_inv));
+
+        if *r == <Scalar as Reduce<U256>>::reduce_bytes(&big_r.to_affine().x()) {
+            Ok(())
+        } else {
+            Err(Error::new())
+        }
+    }
+}
+
+impl Verifier<Signature> for PrecomputedVerifyingKey {
+    fn verify(&self, msg: &[u8], signature: &Signature) -> Result<()> {
+        self.verify_digest(sha2::Sha256::new_with_prefix(msg), signature)
+    }
+}
+
+impl<D> DigestVerifier<D, Signature> for PrecomputedVerifyingKey
+where
+    D: Digest,
+{
+    fn verify_digest(&self, msg_digest: D, signature: &Signature) -> Result<()> {
+        self.verify_prehash(&msg_digest.finalize(), signature)
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::PrecomputedVerifyingKey;
+    use crate::ecdsa::{Signature, SigningKey};
+    use ecdsa_core::signature::{
+        hazmat::{PrehashSigner, PrehashVerifier},
+        Signer, Verifier,
+    };
+    use elliptic_curve::rand_core::OsRng;
+
+    #[test]
+    fn identical_accept_reject_behavior() {
+        let signing_key = SigningKey::random(&mut OsRng);
+        let plain = *signing_key.verifying_key();
+        let precomputed = PrecomputedVerifyingKey::new(plain);
+
+        for i in 0u8..16 {
+            let prehash = [i; 32];
+            let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+
+            assert_eq!(
+                plain.verify_prehash(&prehash, &signature).is_ok(),
+                precomputed.verify_prehash(&prehash, &signature).is_ok()
+            );
+
+            // tampered digests reject identically
+            let mut bad = prehash;
+            bad[0] ^= 1;
+            assert!(precomputed.verify_prehash(&bad, &signature).is_err());
+            assert!(plain.verify_prehash(&bad, &signature).is_err());
+
+            // p256 does not enforce a low-s policy; the negated s must be
+            // accepted (or not) identically
+            let flipped = Signature::from_scalars(*signature.r(), -*signature.s()).unwrap();
+            assert_eq!(
+                plain.verify_prehash(&prehash, &flipped).is_ok(),
+                precomputed.verify_prehash(&prehash, &flipped).is_ok()
+            );
+        }
+
+        // non-32-byte prehashes behave identically (bits2field semantics)
+        let prehash48 = [0x5au8; 48];
+        let signature: Signature = signing_key.sign_prehash(&prehash48).unwrap();
+        assert_eq!(
+            plain.verify_prehash(&prehash48, &signature).is_ok(),
+            precomputed.verify_prehash(&prehash48, &signature).is_ok()
+        );
+        assert!(precomputed.verify_prehash(&prehash48, &signature).is_ok());
+
+        // message-level APIs agree as well
+        let msg = b"firmware image digest";
+        let signature: Signature = signing_key.sign(msg);
+        plain.verify(msg, &signature).unwrap();
+        precomputed.verify(msg, &signature).unwrap();
+
+        // wrong key rejects
+        let other = PrecomputedVerifyingKey::new(*SigningKey::random(&mut OsRng).verifying_key());
+        assert!(other.verify(msg, &signature).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn send_sync_and_shareable() {
+        extern crate std;
+
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<PrecomputedVerifyingKey>();
+
+        let signing_key = SigningKey::random(&mut OsRng);
+        let pinned =
+            std::sync::Arc::new(PrecomputedVerifyingKey::new(*signing_key.verifying_key()));
+
+        let prehash = [1u8; 32];
+        let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+        pinned.verify_prehash(&prehash, &signature).unwrap();
+    }
+}
@@ -0,0 +1,369 @@
+//! Elliptic Curve Integrated Encryption Scheme (ECIES) for NIST P-256.
+//!
+//! SEC1/ISO 18033-2 style authenticated public-key encryption as deployed
+//! in the Apple ecosystem and various smartcard formats:
+//!
+//! 1. An ephemeral P-256 key pair is generated per message.
+//! 2. An ECDH shared secret is computed with the recipient key.
+//! 3. A symmetric key is derived from the shared x-coordinate with the
+//!    ephemeral public key as KDF context.
+//! 4. The payload is sealed with AES-GCM under an all-zero 16-byte nonce
+//!    (safe here because the key is fresh per message).
+//!
+//! The wire format is `ephemeral_pub || ciphertext || tag (16)`. The KDF
+//! (X9.63-SHA256 or HKDF-SHA256), AES-GCM key size, and ephemeral key
+//! compression are selected by a [`Profile`];
+//! [`Profile::APPLE_X963_AES_GCM`] interoperates with Apple's
+//! `eciesEncryptionCofactorX963SHA256AESGCM` over P-256 (the cofactor is 1,
+//! so cofactor ECDH coincides with plain ECDH).
+
+use crate::{ecdh, AffinePoint, NistP256, PublicKey, SecretKey};
+use aes_gcm::{
+    aead::{generic_array::GenericArray as AeadArray, Aead, KeyInit, Payload},
+    AesGcm,
+};
+use alloc::vec::Vec;
+use core::fmt;
+use elliptic_curve::{rand_core::CryptoRngCore, sec1::ToEncodedPoint, NonZeroScalar};
+use hkdf::Hkdf;
+use sha2::{Digest, Sha256};
+
+/// AES-GCM with the 16-byte nonce used by this wire format.
+type Aes128Gcm16 = AesGcm<aes_gcm::aes::Aes128, aes_gcm::aead::consts::U16>;
+type Aes256Gcm16 = AesGcm<aes_gcm::aes::Aes256, aes_gcm::aead::consts::U16>;
+
+/// AES-GCM nonce length in this format (all zeroes on the wire).
+const NONCE_LEN: usize = 16;
+
+/// Authentication tag length.
+const TAG_LEN: usize = 16;
+
+/// Key derivation function selection.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Kdf {
+    /// ANSI X9.63 KDF with SHA-256 (`SHA256(Z || counter || SharedInfo)`).
+    X963Sha256,
+
+    /// HKDF-SHA256 with the shared x-coordinate as IKM and the ephemeral
+    /// public key as info.
+    HkdfSha256,
+}
+
+/// AEAD selection.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum AeadCipher {
+    /// AES-128-GCM (16-byte derived key).
+    Aes128Gcm,
+
+    /// AES-256-GCM (32-byte derived key).
+    Aes256Gcm,
+}
+
+/// An ECIES profile: the KDF, the AEAD, and the ephemeral key framing.
+///
+/// Both sides must agree on the profile; it is not encoded in the
+/// ciphertext.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub struct Profile {
+    /// Key derivation function.
+    pub kdf: Kdf,
+
+    /// Authenticated cipher.
+    pub aead: AeadCipher,
+
+    /// Whether the ephemeral public key is SEC1-compressed in the output
+    /// framing (33 bytes instead of 65).
+    pub compress_ephemeral: bool,
+}
+
+impl Profile {
+    /// Apple `eciesEncryptionCofactorX963SHA256AESGCM` over P-256:
+    /// X9.63-SHA256 KDF, AES-128-GCM, uncompressed ephemeral key.
+    pub const APPLE_X963_AES_GCM: Self = Self {
+        kdf: Kdf::X963Sha256,
+        aead: AeadCipher::Aes128Gcm,
+        compress_ephemeral: false,
+    };
+
+    /// Length of the ephemeral public key on the wire.
+    fn ephemeral_len(&self) -> usize {
+        if self.compress_ephemeral {
+            33
+        } else {
+            65
+        }
+    }
+
+    /// Derived AES key length.
+    fn key_len(&self) -> usize {
+        match self.aead {
+            AeadCipher::Aes128Gcm => 16,
+            AeadCipher::Aes256Gcm => 32,
+        }
+    }
+}
+
+/// ECIES errors.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Error {
+    /// The ciphertext is shorter than the ephemeral key plus tag.
+    Truncated,
+
+    /// The ephemeral public key failed to parse as a P-256 point.
+    InvalidEphemeralKey,
+
+    /// The ciphertext failed authentication (wrong key or tampered data).
+    Authentication,
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Truncated => f.write_str("truncated ECIES ciphertext"),
+            Error::InvalidEphemeralKey => f.write_str("invalid ECIES ephemeral public key"),
+            Error::Authentication => f.write_str("ECIES authentication failure"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+impl std::error::Error for Error {}
+
+/// Derive the AES key from the shared x-coordinate with the framed
+/// ephemeral public key as KDF context.
+fn derive_key(profile: &Profile, shared_x: &[u8; 32], ephemeral_bytes: &[u8]) -> Vec<u8> {
+    let mut okm = alloc::vec![0u8; profile.key_len()];
+
+    match profile.kdf {
+        Kdf::X963Sha256 => {
+            // SHA256(Z || counter_be32 || SharedInfo), counter from 1
+            for (counter, chunk) in (1u32..).zip(okm.chunks_mut(32)) {
+                let digest = Sha256::new()
+                    .chain_update(shared_x)
+                    .chain_update(counter.to_be_bytes())
+                    .chain_update(ephemeral_bytes)
+                    .finalize();
+                chunk.copy_from_slice(&digest[..chunk.len()]);
+            }
+        }
+        Kdf::HkdfSha256 => {
+            let hk = Hkdf::<Sha256>::new(None, shared_x);
+            // the requested lengths are always valid HKDF-SHA256 outputs
+            #[allow(clippy::unwrap_used)]
+            hk.expand(ephemeral_bytes, &mut okm).unwrap();
+        }
+    }
+
+    okm
+}
+
+/// Compute the shared x-coordinate between a secret scalar and a public
+/// point.
+fn shared_x(secret: &NonZeroScalar<NistP256>, public: &AffinePoint) -> [u8; 32] {
+    let shared = ecdh::diffie_hellman(secret, public);
+    let mut x = [0u8; 32];
+    x.copy_from_slice(shared.raw_secret_bytes());
+    x
+}
+
+/// Seal or open with the profile's AEAD under the all-zero nonce.
+fn aead_seal(profile: &Profile, key: &[u8], plaintext: &[u8]) -> Vec<u8> {
+    let nonce = [0u8; NONCE_LEN];
+
+    // AEAD encryption with a correctly sized fresh key cannot fail
+    #[allow(clippy::unwrap_used)]
+    match profile.aead {
+        AeadCipher::Aes128Gcm => Aes128Gcm16::new(AeadArray::from_slice(key))
+            .encrypt(AeadArray::from_slice(&nonce), Payload::from(plaintext))
+            .unwrap(),
+        AeadCipher::Aes256Gcm => Aes256Gcm16::new(AeadArray::from_slice(key))
+            .encrypt(AeadArray::from_slice(&nonce), Payload::from(plaintext))
+            .unwrap(),
+    }
+}
+
+fn aead_open(profile: &Profile, key: &[u8], sealed: &[u8]) -> Result<Vec<u8>, Error> {
+    let nonce = [0u8; NONCE_LEN];
+
+    match profile.aead {
+        AeadCipher::Aes128Gcm => Aes128Gcm16::new(AeadArray::from_slice(key))
+            .decrypt(AeadArray::from_slice(&nonce), Payload::from(sealed)),
+        AeadCipher::Aes256Gcm => Aes256Gcm16::new(AeadArray::from_slice(key))
+            .decrypt(AeadArray::from_slice(&nonce), Payload::from(sealed)),
+    }
+    .map_err(|_| Error::Authentication)
+}
+
+/// Encrypt `plaintext` to the given recipient public key under the profile,
+/// using an ephemeral key pair from `rng`.
+pub fn encrypt(
+    profile: &Profile,
+    recipient: &PublicKey,
+    plaintext: &[u8],
+    rng: &mut impl CryptoRngCore,
+) -> Vec<u8> {
+    let ephemeral_secret = NonZeroScalar::<NistP256>::random(rng);
+    let ephemeral_pub = PublicKey::from_secret_scalar(&ephemeral_secret);
+    let ephemeral_bytes = ephemeral_pub.to_encoded_point(profile.compress_ephemeral);
+
+    let key = derive_key(
+        profile,
+        &shared_x(&ephemeral_secret, recipient.as_affine()),
+        ephemeral_bytes.as_bytes(),
+    );
+    let sealed = aead_seal(profile, &key, plaintext);
+
+    let mut out = Vec::with_capacity(profile.ephemeral_len() + sealed.len());
+    out.extend_from_slice(ephemeral_bytes.as_bytes());
+    out.extend_from_slice(&sealed);
+    out
+}
+
+/// Decrypt an ECIES ciphertext with the recipient's secret key.
+pub fn decrypt(
+    profile: &Profile,
+    secret_key: &SecretKey,
+    ciphertext: &[u8],
+) -> Result<Vec<u8>, Error> {
+    if ciphertext.len() < profile.ephemeral_len() + TAG_LEN {
+        return Err(Error::Truncated);
+    }
+
+    let (pub_bytes, sealed) = ciphertext.split_at(profile.ephemeral_len());
+    let ephemeral_pub =
+        PublicKey::from_sec1_bytes(pub_bytes).map_err(|_| Error::InvalidEphemeralKey)?;
+
+    let key = derive_key(
+        profile,
+        &shared_x(&secret_key.to_nonzero_scalar(), ephemeral_pub.as_affine()),
+        pub_bytes,
+    );
+
+    aead_open(profile, &key, sealed)
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{decrypt, encrypt, AeadCipher, Error, Kdf, Profile};
+    use crate::SecretKey;
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+
+    const ALL_PROFILES: [Profile; 8] = {
+        let mut profiles = [Profile::APPLE_X963_AES_GCM; 8];
+        let mut i = 0;
+        let kdfs = [Kdf::X963Sha256, Kdf::HkdfSha256];
+        let aeads = [AeadCipher::Aes128Gcm, AeadCipher::Aes256Gcm];
+        let mut k = 0;
+        while k < 2 {
+            let mut a = 0;
+            while a < 2 {
+                let mut c = 0;
+                while c < 2 {
+                    profiles[i] = Profile {
+                        kdf: kdfs[k],
+                        aead: aeads[a],
+                        compress_ephemeral: c == 1,
+                    };
+                    i += 1;
+                    c += 1;
+                }
+                a += 1;
+            }
+            k += 1;
+        }
+        profiles
+    };
+
+    #[test]
+    fn all_profiles_roundtrip() {
+        let sk = SecretKey::random(&mut OsRng);
+        let pk = sk.public_key();
+        let msg = b"ECIES test message";
+
+        for profile in &ALL_PROFILES {
+            let ct = encrypt(profile, &pk, msg, &mut OsRng);
+            assert_eq!(
+                ct.len(),
+                profile.ephemeral_len() + msg.len() + 16,
+                "{profile:?}"
+            );
+            assert_eq!(decrypt(profile, &sk, &ct).unwrap(), msg, "{profile:?}");
+
+            // ciphertexts are randomized via the ephemeral key
+            assert_ne!(ct, encrypt(profile, &pk, msg, &mut OsRng));
+        }
+    }
+
+    #[test]
+    fn error_variants_distinguished() {
+        let profile = Profile::APPLE_X963_AES_GCM;
+        let sk = SecretKey::random(&mut OsRng);
+        let ct = encrypt(&profile, &sk.public_key(), b"payload", &mut OsRng);
+
+        assert_eq!(
+            decrypt(&profile, &sk, &ct[..64]).unwrap_err(),
+            Error::Truncated
+        );
+
+        // ephemeral x not on the curve
+        let mut bad = ct.clone();
+        bad[1] ^= 1;
+        assert_eq!(
+            decrypt(&profile, &sk, &bad).unwrap_err(),
+            Error::InvalidEphemeralKey
+        );
+
+        // corrupted tag
+        let mut bad = ct.clone();
+        let last = bad.len() - 1;
+        bad[last] ^= 1;
+        assert_eq!(
+            decrypt(&profile, &sk, &bad).unwrap_err(),
+            Error::Authentication
+        );
+
+        // wrong recipient key
+        let other = SecretKey::random(&mut OsRng);
+        assert_eq!(
+            decrypt(&profile, &other, &ct).unwrap_err(),
+            Error::Authentication
+        );
+    }
+
+    #[test]
+    fn empty_plaintext() {
+        let profile = Profile::APPLE_X963_AES_GCM;
+        let sk = SecretKey::random(&mut OsRng);
+        let ct = encrypt(&profile, &sk.public_key(), b"", &mut OsRng);
+        assert_eq!(decrypt(&profile, &sk, &ct).unwrap(), b"");
+    }
+
+    /// Fixture for the Apple `eciesEncryptionCofactorX963SHA256AESGCM`
+    /// construction (X9.63-SHA256, AES-128-GCM, zero IV, ephemeral key as
+    /// SharedInfo), produced with an independent implementation of that
+    /// algorithm on top of PyCA `cryptography`.
+    #[test]
+    fn apple_x963_fixture() {
+        let sk = SecretKey::from_slice(&hex!(
+            "c9afa9d845ba75166b5c215767b1d6934e50c3db36e89b127b8a622b120f6721"
+        ))
+        .unwrap();
+
+        let ciphertext = hex!(
+            "0462a2e6d24cdf1a7f302244f36281746ccaa75e76f1cbf22f4e61a734064de7"
+            "1cc84d06362c9836ce879f3f75b4f57615bf40d05fd0329b1216d78a87b90402"
+            "03e16f67c136ec405b7995a1116b9c13a88fb1a77cb3bebe761a76c3232d638f"
+            "9b5be152"
+        );
+
+        assert_eq!(
+            decrypt(&Profile::APPLE_X963_AES_GCM, &sk, &ciphertext).unwrap(),
+            b"apple ecies interop"
+        );
+    }
+}
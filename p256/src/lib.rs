@@ -26,12 +26,36 @@
 //!
 //! Please see type-specific documentation for more information.
 
+#[cfg(any(
+    feature = "ecies",
+    all(feature = "alloc", feature = "ecdsa", feature = "sha256")
+))]
+extern crate alloc;
+
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
+
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
+#[cfg(all(feature = "hash2curve", feature = "sha256"))]
+pub mod derive;
+
 #[cfg(feature = "ecdh")]
 pub mod ecdh;
 
+#[cfg(feature = "ecies")]
+pub mod ecies;
+
+#[cfg(feature = "pake")]
+pub mod pake;
+
+#[cfg(feature = "vrf")]
+pub mod vrf;
+
+#[cfg(feature = "voprf")]
+pub mod voprf;
+
 #[cfg(feature = "ecdsa-core")]
 pub mod ecdsa;
 
@@ -0,0 +1,137 @@
+//! SPAKE2 / PAKE curve-level building blocks for P-256.
+//!
+//! Exposes the standardized SPAKE2 blinding points [`M`] and [`N`] from
+//! [RFC 9382] together with the [`mask`]/[`unmask`] group operations and a
+//! password-to-scalar hash, as used (with protocol-specific transcripts)
+//! by CTAP2.1 and Matter. This is deliberately *not* a PAKE state machine —
+//! only the curve-level pieces.
+//!
+//! [RFC 9382]: https://www.rfc-editor.org/rfc/rfc9382
+
+use crate::{arithmetic::field::FieldElement, AffinePoint, NistP256, ProjectivePoint, Scalar};
+use elliptic_curve::{
+    hash2curve::{ExpandMsgXmd, GroupDigest},
+    Result,
+};
+use sha2::Sha256;
+
+/// The SPAKE2 point `M`, used to blind the first party's key share.
+///
+/// Generated per RFC 9382 Appendix A from the seed string
+/// `1.2.840.10045.3.1.7 point generation seed (M)`; compressed encoding
+/// `02886e2f97ace46e55ba9dd7242579f2993b64e16ef3dcab95afd497333d8fa12f`.
+/// Membership in the prime-order group is asserted by tests.
+pub const M: AffinePoint = AffinePoint::from_xy_unchecked(
+    FieldElement::from_hex("886e2f97ace46e55ba9dd7242579f2993b64e16ef3dcab95afd497333d8fa12f"),
+    FieldElement::from_hex("5ff355163e43ce224e0b0e65ff02ac8e5c7be09419c785e0ca547d55a12e2d20"),
+);
+
+/// The SPAKE2 point `N`, used to blind the second party's key share.
+///
+/// Generated per RFC 9382 Appendix A from the seed string
+/// `1.2.840.10045.3.1.7 point generation seed (N)`; compressed encoding
+/// `03d8bbd6c639c62937b04d997f38c3770719c629d7014d49a24b4f98baa1292b49`.
+/// Membership in the prime-order group is asserted by tests.
+pub const N: AffinePoint = AffinePoint::from_xy_unchecked(
+    FieldElement::from_hex("d8bbd6c639c62937b04d997f38c3770719c629d7014d49a24b4f98baa1292b49"),
+    FieldElement::from_hex("07d60aa6bfade45008a636337f5168c64d9bd36034808cd564490b1e656edbe7"),
+);
+
+/// Mask a public key share with a password-derived scalar:
+/// `share + w * blind`, where `blind` is [`M`] or [`N`].
+pub fn mask(share: &ProjectivePoint, w: &Scalar, blind: &AffinePoint) -> ProjectivePoint {
+    *share + ProjectivePoint::from(*blind) * w
+}
+
+/// Remove a password blinding from a received masked share:
+/// `masked - w * blind`, the inverse of [`mask`].
+pub fn unmask(masked: &ProjectivePoint, w: &Scalar, blind: &AffinePoint) -> ProjectivePoint {
+    *masked - ProjectivePoint::from(*blind) * w
+}
+
+/// Hash password material to a scalar with `hash_to_field` (SHA-256,
+/// `expand_message_xmd`), for deriving the SPAKE2 `w` value.
+///
+/// Protocols prescribe their own memory-hard preprocessing and domain
+/// separation; pass the protocol's DST and the (pre-stretched) password
+/// bytes.
+pub fn hash_to_scalar(password: &[u8], dst: &[u8]) -> Result<Scalar> {
+    NistP256::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[password], &[dst])
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{hash_to_scalar, mask, unmask, M, N};
+    use crate::{AffinePoint, ProjectivePoint, Scalar};
+    use elliptic_curve::{
+        ops::MulByGenerator,
+        rand_core::OsRng,
+        sec1::{FromEncodedPoint, ToEncodedPoint},
+        Field,
+    };
+    use hex_literal::hex;
+
+    // RFC 9382 byte encodings of the P-256 M and N points
+    #[test]
+    fn m_and_n_match_rfc9382_encodings() {
+        assert_eq!(
+            M.to_encoded_point(true).as_bytes(),
+            hex!("02886e2f97ace46e55ba9dd7242579f2993b64e16ef3dcab95afd497333d8fa12f")
+        );
+        assert_eq!(
+            N.to_encoded_point(true).as_bytes(),
+            hex!("03d8bbd6c639c62937b04d997f38c3770719c629d7014d49a24b4f98baa1292b49")
+        );
+    }
+
+    #[test]
+    fn m_and_n_are_in_the_prime_order_group() {
+        for point in [M, N] {
+            // decodes as a valid curve point
+            let decoded = Option::<AffinePoint>::from(AffinePoint::from_encoded_point(
+                &point.to_encoded_point(false),
+            ))
+            .unwrap();
+            assert_eq!(decoded, point);
+
+            // (n - 1) * P == -P, so P has order n (cofactor is 1)
+            let p = ProjectivePoint::from(point);
+            assert_eq!(p * -Scalar::ONE, -p);
+        }
+    }
+
+    #[test]
+    fn mask_unmask_round_trip() {
+        for blind in [M, N] {
+            let share = ProjectivePoint::mul_by_generator(&Scalar::random(&mut OsRng));
+            let w = Scalar::random(&mut OsRng);
+
+            let masked = mask(&share, &w, &blind);
+            assert_ne!(masked, share);
+            assert_eq!(unmask(&masked, &w, &blind), share);
+
+            // wrong password scalar does not unmask
+            assert_ne!(unmask(&masked, &Scalar::random(&mut OsRng), &blind), share);
+        }
+
+        // masking with M and unmasking with N disagree
+        let share = ProjectivePoint::mul_by_generator(&Scalar::random(&mut OsRng));
+        let w = Scalar::random(&mut OsRng);
+        assert_ne!(unmask(&mask(&share, &w, &M), &w, &N), share);
+    }
+
+    #[test]
+    fn password_hashing_is_domain_separated() {
+        let a = hash_to_scalar(b"correct horse", b"SPAKE2 P-256 test").unwrap();
+        let b = hash_to_scalar(b"correct horse", b"other context").unwrap();
+        let c = hash_to_scalar(b"battery staple", b"SPAKE2 P-256 test").unwrap();
+
+        assert_ne!(a, b);
+        assert_ne!(a, c);
+        assert_eq!(
+            a,
+            hash_to_scalar(b"correct horse", b"SPAKE2 P-256 test").unwrap()
+        );
+    }
+}
@@ -0,0 +1,293 @@
+//! RFC 9497 (V)OPRF group operations for the P256-SHA256 ciphersuite.
+//!
+//! [`VoprfGroup`] packages the prime-order group interface an OPRF
+//! protocol implementation needs on top of this crate: `HashToGroup` and
+//! `HashToScalar` with the RFC 9497 context-string DST construction,
+//! `DeriveKeyPair`, and strict element (de)serialization using 33-byte
+//! compressed SEC1 encodings. Scalar inversion for unblinding is available
+//! via [`NonZeroScalar::invert`].
+//!
+//! [RFC 9497]: https://www.rfc-editor.org/rfc/rfc9497
+
+use crate::{AffinePoint, EncodedPoint, NistP256, NonZeroScalar, ProjectivePoint, Scalar};
+use elliptic_curve::{
+    hash2curve::{ExpandMsgXmd, GroupDigest},
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Error, Result, VoprfParameters,
+};
+use sha2::Sha256;
+
+/// Protocol mode, bound into every derived DST.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Base mode (`0x00`).
+    Oprf,
+
+    /// Verifiable mode (`0x01`).
+    Voprf,
+
+    /// Partially-oblivious mode (`0x02`).
+    Poprf,
+}
+
+impl Mode {
+    fn to_byte(self) -> u8 {
+        match self {
+            Mode::Oprf => 0x00,
+            Mode::Voprf => 0x01,
+            Mode::Poprf => 0x02,
+        }
+    }
+}
+
+/// Length of `"OPRFV1-" || mode || "-" || "P256-SHA256"`.
+const CONTEXT_LEN: usize = 20;
+
+/// RFC 9497 `contextString` for the given mode.
+fn context_string(mode: Mode) -> [u8; CONTEXT_LEN] {
+    let mut out = [0u8; CONTEXT_LEN];
+    out[..7].copy_from_slice(b"OPRFV1-");
+    out[7] = mode.to_byte();
+    out[8] = b'-';
+    out[9..].copy_from_slice(NistP256::ID.as_bytes());
+    out
+}
+
+/// RFC 9497 prime-order group operations for an OPRF ciphersuite.
+///
+/// Implemented for [`NistP256`] as the `P256-SHA256` ciphersuite.
+pub trait VoprfGroup: VoprfParameters {
+    /// `HashToGroup`: hash `input` to a group element with the
+    /// mode-specific `HashToGroup-` DST.
+    fn hash_to_group(mode: Mode, input: &[u8]) -> Result<ProjectivePoint>;
+
+    /// `HashToScalar`: hash `input` to a scalar with the mode-specific
+    /// `HashToScalar-` DST.
+    fn hash_to_scalar(mode: Mode, input: &[u8]) -> Result<Scalar>;
+
+    /// `DeriveKeyPair`: derive a secret key from a seed and key info,
+    /// retrying with an appended counter byte until the result is
+    /// non-zero.
+    fn derive_key_pair(mode: Mode, seed: &[u8], info: &[u8]) -> Result<NonZeroScalar>;
+
+    /// `SerializeElement`: 33-byte compressed SEC1 encoding; the identity
+    /// has no valid encoding and is rejected.
+    fn serialize_element(element: &ProjectivePoint) -> Result<[u8; 33]>;
+
+    /// `DeserializeElement`: strict inverse of [`Self::serialize_element`],
+    /// rejecting malformed encodings and the identity.
+    fn deserialize_element(bytes: &[u8; 33]) -> Result<ProjectivePoint>;
+}
+
+impl VoprfGroup for NistP256 {
+    fn hash_to_group(mode: Mode, input: &[u8]) -> Result<ProjectivePoint> {
+        let mut dst = [0u8; 12 + CONTEXT_LEN];
+        dst[..12].copy_from_slice(b"HashToGroup-");
+        dst[12..].copy_from_slice(&context_string(mode));
+
+        Self::hash_from_bytes::<ExpandMsgXmd<Sha256>>(&[input], &[&dst])
+    }
+
+    fn hash_to_scalar(mode: Mode, input: &[u8]) -> Result<Scalar> {
+        let mut dst = [0u8; 13 + CONTEXT_LEN];
+        dst[..13].copy_from_slice(b"HashToScalar-");
+        dst[13..].copy_from_slice(&context_string(mode));
+
+        <Self as GroupDigest>::hash_to_scalar::<ExpandMsgXmd<Sha256>>(&[input], &[&dst])
+    }
+
+    fn derive_key_pair(mode: Mode, seed: &[u8], info: &[u8]) -> Result<NonZeroScalar> {
+        let mut dst = [0u8; 13 + CONTEXT_LEN];
+        dst[..13].copy_from_slice(b"DeriveKeyPair");
+        dst[13..].copy_from_slice(&context_string(mode));
+
+        let info_len = u16::try_from(info.len()).map_err(|_| Error)?.to_be_bytes();
+
+        for counter in 0u8..=255 {
+            let scalar = <Self as GroupDigest>::hash_to_scalar::<ExpandMsgXmd<Sha256>>(
+                &[seed, &info_len, info, &[counter]],
+                &[&dst],
+            )?;
+
+            if let Some(sk) = Option::<NonZeroScalar>::from(NonZeroScalar::new(scalar)) {
+                return Ok(sk);
+            }
+        }
+
+        Err(Error)
+    }
+
+    fn serialize_element(element: &ProjectivePoint) -> Result<[u8; 33]> {
+        let affine = element.to_affine();
+        if bool::from(affine.is_identity()) {
+            return Err(Error);
+        }
+
+        let mut out = [0u8; 33];
+        out.copy_from_slice(affine.to_encoded_point(true).as_bytes());
+        Ok(out)
+    }
+
+    fn deserialize_element(bytes: &[u8; 33]) -> Result<ProjectivePoint> {
+        let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| Error)?;
+        if !encoded.is_compressed() {
+            return Err(Error);
+        }
+
+        let affine =
+            Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded)).ok_or(Error)?;
+
+        if bool::from(affine.is_identity()) {
+            return Err(Error);
+        }
+
+        Ok(ProjectivePoint::from(affine))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Mode, VoprfGroup};
+    use crate::{NistP256, NonZeroScalar, ProjectivePoint, Scalar};
+    use elliptic_curve::{group::Group, ops::Invert, PrimeField};
+    use hex_literal::hex;
+    use sha2::{Digest, Sha256};
+
+    /// RFC 9497 `Finalize` for the base mode: `Hash(len(input) || input ||
+    /// len(element) || element || "Finalize")`.
+    fn finalize(input: &[u8], unblinded: &[u8; 33]) -> [u8; 32] {
+        Sha256::new()
+            .chain_update(u16::try_from(input.len()).unwrap().to_be_bytes())
+            .chain_update(input)
+            .chain_update(33u16.to_be_bytes())
+            .chain_update(unblinded)
+            .chain_update(b"Finalize")
+            .finalize()
+            .into()
+    }
+
+    // Seed and key info from RFC 9497 Appendix A (P256-SHA256)
+    const SEED: [u8; 32] = hex!("a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3");
+    const KEY_INFO: &[u8] = b"test key";
+
+    #[test]
+    fn derive_key_pair_vectors() {
+        let cases: [(Mode, [u8; 32]); 3] = [
+            (
+                Mode::Oprf,
+                hex!("159749d750713afe245d2d39ccfaae8381c53ce92d098a9375ee70739c7ac0bf"),
+            ),
+            (
+                Mode::Voprf,
+                hex!("ca5d94c8807817669a51b196c34c1b7f8442fde4334a7121ae4736364312fca6"),
+            ),
+            (
+                Mode::Poprf,
+                hex!("6ad2173efa689ef2c27772566ad7ff6e2d59b3b196f00219451fb2c89ee4dae2"),
+            ),
+        ];
+
+        for (mode, sk_sm) in cases {
+            let sk = NistP256::derive_key_pair(mode, &SEED, KEY_INFO).unwrap();
+            assert_eq!(sk.to_bytes().as_slice(), &sk_sm, "{mode:?}");
+        }
+    }
+
+    // Base-mode protocol test vectors from RFC 9497 Appendix A.1.1
+    #[test]
+    fn oprf_base_mode_vectors() {
+        struct TestVector {
+            input: &'static [u8],
+            blinded_element: [u8; 33],
+            evaluation_element: [u8; 33],
+            output: [u8; 32],
+        }
+
+        const BLIND: [u8; 32] =
+            hex!("3338fa65ec36e0290022b48eb562889d89dbfa691d1cde91517fa222ed7ad364");
+
+        const TEST_VECTORS: &[TestVector] = &[
+            TestVector {
+                input: &hex!("00"),
+                blinded_element: hex!(
+                    "03723a1e5c09b8b9c18d1dcbca29e8007e95f14f4732d9346d490ffc195110368d"
+                ),
+                evaluation_element: hex!(
+                    "030de02ffec47a1fd53efcdd1c6faf5bdc270912b8749e783c7ca75bb412958832"
+                ),
+                output: hex!("a0b34de5fa4c5b6da07e72af73cc507cceeb48981b97b7285fc375345fe495dd"),
+            },
+            TestVector {
+                input: &hex!("5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a"),
+                blinded_element: hex!(
+                    "03cc1df781f1c2240a64d1c297b3f3d16262ef5d4cf102734882675c26231b0838"
+                ),
+                evaluation_element: hex!(
+                    "03a0395fe3828f2476ffcd1f4fe540e5a8489322d398be3c4e5a869db7fcb7c52c"
+                ),
+                output: hex!("c748ca6dd327f0ce85f4ae3a8cd6d4d5390bbb804c9e12dcf94f853fece3dcce"),
+            },
+        ];
+
+        let sk = NistP256::derive_key_pair(Mode::Oprf, &SEED, KEY_INFO).unwrap();
+        let blind = NonZeroScalar::new(Scalar::from_repr(BLIND.into()).unwrap()).unwrap();
+
+        for vector in TEST_VECTORS {
+            // client: blind
+            let element = NistP256::hash_to_group(Mode::Oprf, vector.input).unwrap();
+            let blinded = element * blind.as_ref();
+            assert_eq!(
+                NistP256::serialize_element(&blinded).unwrap(),
+                vector.blinded_element
+            );
+
+            // server: evaluate
+            let evaluated =
+                NistP256::deserialize_element(&vector.blinded_element).unwrap() * sk.as_ref();
+            assert_eq!(
+                NistP256::serialize_element(&evaluated).unwrap(),
+                vector.evaluation_element
+            );
+
+            // client: unblind and finalize
+            let unblinded = evaluated * blind.invert().as_ref();
+            let output = finalize(
+                vector.input,
+                &NistP256::serialize_element(&unblinded).unwrap(),
+            );
+            assert_eq!(output, vector.output);
+        }
+    }
+
+    #[test]
+    fn element_serialization_strictness() {
+        // the identity has no encoding
+        assert!(NistP256::serialize_element(&ProjectivePoint::IDENTITY).is_err());
+
+        // uncompressed tag byte rejected
+        let element = NistP256::hash_to_group(Mode::Oprf, b"strict").unwrap();
+        let mut bytes = NistP256::serialize_element(&element).unwrap();
+        assert_eq!(NistP256::deserialize_element(&bytes).unwrap(), element);
+
+        bytes[0] = 0x04;
+        assert!(NistP256::deserialize_element(&bytes).is_err());
+
+        // x-coordinate not on the curve / not canonical
+        let mut bad = NistP256::serialize_element(&element).unwrap();
+        bad[1..].copy_from_slice(&[0xff; 32]);
+        assert!(NistP256::deserialize_element(&bad).is_err());
+    }
+
+    #[test]
+    fn hash_to_scalar_modes_distinct() {
+        let a = <NistP256 as VoprfGroup>::hash_to_scalar(Mode::Oprf, b"input").unwrap();
+        let b = <NistP256 as VoprfGroup>::hash_to_scalar(Mode::Voprf, b"input").unwrap();
+        assert_ne!(a, b);
+
+        let g1 = NistP256::hash_to_group(Mode::Oprf, b"input").unwrap();
+        let g2 = NistP256::hash_to_group(Mode::Voprf, b"input").unwrap();
+        assert_ne!(g1, g2);
+        assert!(!bool::from(g1.is_identity()));
+    }
+}
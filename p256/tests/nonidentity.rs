@@ -0,0 +1,90 @@
+//! `NonIdentity` wrapper integration tests.
+
+#![cfg(feature = "arithmetic")]
+
+use elliptic_curve::{point::NonIdentity, rand_core::OsRng, Field};
+use p256::{AffinePoint, NonZeroScalar, ProjectivePoint, PublicKey, Scalar};
+
+#[test]
+fn projective_try_from_rejects_identity() {
+    assert!(NonIdentity::<ProjectivePoint>::try_from(ProjectivePoint::IDENTITY).is_err());
+
+    let point = ProjectivePoint::GENERATOR * Scalar::random(&mut OsRng);
+    let non_identity = NonIdentity::try_from(point).unwrap();
+    assert_eq!(ProjectivePoint::from(non_identity), point);
+}
+
+#[test]
+fn public_key_conversions() {
+    let non_identity = NonIdentity::<ProjectivePoint>::try_from(ProjectivePoint::GENERATOR)
+        .unwrap()
+        .to_affine();
+
+    let public_key = PublicKey::from(non_identity);
+    assert_eq!(*public_key.as_affine(), AffinePoint::GENERATOR);
+
+    // and back
+    assert_eq!(
+        public_key.to_nonidentity().to_point(),
+        AffinePoint::GENERATOR
+    );
+}
+
+#[test]
+fn mul_by_nonzero_scalar_composes() {
+    let p = NonIdentity::<ProjectivePoint>::try_from(ProjectivePoint::GENERATOR).unwrap();
+    let a = NonZeroScalar::random(&mut OsRng);
+    let b = NonZeroScalar::random(&mut OsRng);
+
+    // the product stays statically non-identity and composes associatively
+    let ab: NonIdentity<ProjectivePoint> = (p * a) * b;
+    let ba = (p * b) * a;
+    assert_eq!(ab.to_point(), ba.to_point());
+    assert_eq!(ab.to_point(), ProjectivePoint::GENERATOR * (*a * *b));
+}
+
+#[cfg(feature = "serde")]
+mod serde_tests {
+    use super::*;
+    use elliptic_curve::group::GroupEncoding;
+
+    #[test]
+    fn round_trips_and_rejects_identity_encoding() {
+        let point = ProjectivePoint::GENERATOR * Scalar::random(&mut OsRng);
+        let non_identity = NonIdentity::try_from(point).unwrap();
+
+        // projective and wrapped points round-trip
+        let json = serde_json::to_string(&point).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ProjectivePoint>(&json).unwrap(),
+            point
+        );
+
+        let json = serde_json::to_string(&non_identity).unwrap();
+        assert_eq!(
+            serde_json::from_str::<NonIdentity<ProjectivePoint>>(&json)
+                .unwrap()
+                .to_point(),
+            point
+        );
+
+        // the identity deserializes as a bare point but not as NonIdentity
+        let identity_json = serde_json::to_string(&ProjectivePoint::IDENTITY).unwrap();
+        assert_eq!(
+            serde_json::from_str::<ProjectivePoint>(&identity_json).unwrap(),
+            ProjectivePoint::IDENTITY
+        );
+        assert!(serde_json::from_str::<NonIdentity<ProjectivePoint>>(&identity_json).is_err());
+
+        // an all-zero compressed encoding is rejected outright
+        let zeros = serde_json::to_string(&hex::encode([0u8; 33])).unwrap();
+        assert!(serde_json::from_str::<AffinePoint>(&zeros).is_err());
+        assert!(serde_json::from_str::<NonIdentity<AffinePoint>>(&zeros).is_err());
+
+        // GroupEncoding path: from_bytes of the identity fails for the wrapper
+        let identity_bytes = ProjectivePoint::IDENTITY.to_bytes();
+        assert!(bool::from(
+            NonIdentity::<ProjectivePoint>::from_repr(&identity_bytes).is_none()
+        ));
+    }
+}
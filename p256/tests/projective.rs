@@ -25,3 +25,85 @@ fn projective_identity_to_bytes() {
     // This is technically an invalid SEC1 encoding, but is preferable to panicking.
     assert_eq!([0; 33], ProjectivePoint::IDENTITY.to_bytes().as_slice());
 }
+
+#[test]
+fn batch_normalize_array() {
+    use elliptic_curve::{ops::MulByGenerator, BatchNormalize, Field};
+    use rand_core::OsRng;
+
+    let g = ProjectivePoint::mul_by_generator(&Scalar::random(&mut OsRng));
+    let h = ProjectivePoint::mul_by_generator(&Scalar::random(&mut OsRng));
+
+    let res = <ProjectivePoint as BatchNormalize<_>>::batch_normalize(&[g, h]);
+    assert_eq!(res, [g.to_affine(), h.to_affine()]);
+
+    // identities must map to the affine identity without poisoning the batch
+    let res = <ProjectivePoint as BatchNormalize<_>>::batch_normalize(&[
+        g,
+        ProjectivePoint::IDENTITY,
+        h,
+        ProjectivePoint::IDENTITY,
+    ]);
+    assert_eq!(
+        res,
+        [
+            g.to_affine(),
+            AffinePoint::IDENTITY,
+            h.to_affine(),
+            AffinePoint::IDENTITY
+        ]
+    );
+}
+
+#[cfg(feature = "alloc")]
+#[test]
+fn batch_normalize_slice() {
+    use elliptic_curve::{ops::MulByGenerator, BatchNormalize, Field};
+    use rand_core::OsRng;
+
+    let mut points: Vec<ProjectivePoint> = (0..100)
+        .map(|_| ProjectivePoint::mul_by_generator(&Scalar::random(&mut OsRng)))
+        .collect();
+    points[17] = ProjectivePoint::IDENTITY;
+    points[99] = ProjectivePoint::IDENTITY;
+
+    let expected: Vec<AffinePoint> = points.iter().map(|p| p.to_affine()).collect();
+    let res = <ProjectivePoint as BatchNormalize<_>>::batch_normalize(points.as_slice());
+    assert_eq!(res, expected);
+
+    // empty batch
+    let empty: Vec<ProjectivePoint> = Vec::new();
+    assert!(<ProjectivePoint as BatchNormalize<_>>::batch_normalize(empty.as_slice()).is_empty());
+}
+
+#[test]
+fn lincomb_vartime_agrees_with_lincomb() {
+    use elliptic_curve::{ops::LinearCombination, Field};
+    use rand_core::OsRng;
+
+    // fixed edge cases
+    let g = ProjectivePoint::GENERATOR;
+    for (a, b) in [
+        (Scalar::ZERO, Scalar::ZERO),
+        (Scalar::ONE, Scalar::ZERO),
+        (Scalar::ZERO, -Scalar::ONE),
+        (-Scalar::ONE, -Scalar::ONE),
+    ] {
+        assert_eq!(
+            ProjectivePoint::lincomb_vartime(&[(g, a), (g.double(), b)]),
+            ProjectivePoint::lincomb(&g, &a, &g.double(), &b),
+        );
+    }
+
+    for _ in 0..1000 {
+        let p1 = g * Scalar::random(&mut OsRng);
+        let p2 = g * Scalar::random(&mut OsRng);
+        let s1 = Scalar::random(&mut OsRng);
+        let s2 = Scalar::random(&mut OsRng);
+
+        assert_eq!(
+            ProjectivePoint::lincomb_vartime(&[(p1, s1), (p2, s2)]),
+            ProjectivePoint::lincomb(&p1, &s1, &p2, &s2),
+        );
+    }
+}
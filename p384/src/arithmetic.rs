@@ -38,11 +38,11 @@ impl PrimeCurveParams for NistP384 {
     type PointArithmetic = point_arithmetic::EquationAIsMinusThree;
 
     /// a = -3 (0xfffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffffeffffffff0000000000000000fffffffc)
-    const EQUATION_A: FieldElement = FieldElement::from_u64(3).neg();
+    const EQUATION_A: FieldElement = FieldElement::EQUATION_A;
 
     /// b = b3312fa7 e23ee7e4 988e056b e3f82d19 181d9c6e fe814112
     ///     0314088f 5013875a c656398d 8a2ed19d 2a85c8ed d3ec2aef
-    const EQUATION_B: FieldElement = FieldElement::from_hex("b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef");
+    const EQUATION_B: FieldElement = FieldElement::EQUATION_B;
 
     /// Base point of P-384.
     ///
@@ -58,4 +58,47 @@ impl PrimeCurveParams for NistP384 {
         FieldElement::from_hex("aa87ca22be8b05378eb1c71ef320ad746e1d3b628ba79b9859f741e082542a385502f25dbf55296c3a545e3872760ab7"),
         FieldElement::from_hex("3617de4a96262c6f5d9e98bf9292dc29f8f41dbd289a147ce9da3113b5f0b8c00a60b1ce1d7e819d7a431d7c90ea0e5f"),
     );
+
+    #[cfg(feature = "precomputed-tables")]
+    fn mul_by_generator(scalar: &Scalar) -> ProjectivePoint {
+        GENERATOR_TABLE.mul(scalar)
+    }
+}
+
+#[cfg(all(
+    feature = "precomputed-tables",
+    not(any(feature = "std", feature = "critical-section"))
+))]
+compile_error!("`precomputed-tables` feature requires either `std` or `critical-section`");
+
+/// Lazily computed fixed-base table for the P-384 generator (49 radix-16
+/// windows spaced two steps apart, ~55 KiB of points), used to accelerate
+/// [`MulByGenerator`][`elliptic_curve::ops::MulByGenerator`] - and with it
+/// `ecdsa::SigningKey` generation and signing.
+#[cfg(feature = "precomputed-tables")]
+static GENERATOR_TABLE: once_cell::sync::Lazy<primeorder::FixedBaseTable<NistP384, 49>> =
+    once_cell::sync::Lazy::new(|| primeorder::FixedBaseTable::new(&ProjectivePoint::GENERATOR));
+
+#[cfg(all(test, feature = "precomputed-tables"))]
+mod generator_table_tests {
+    use super::{ProjectivePoint, Scalar};
+    use elliptic_curve::{ops::MulByGenerator, rand_core::OsRng, Field};
+
+    #[test]
+    fn mul_by_generator_matches_generic_mul() {
+        for scalar in [Scalar::ZERO, Scalar::ONE, -Scalar::ONE] {
+            assert_eq!(
+                ProjectivePoint::mul_by_generator(&scalar),
+                ProjectivePoint::GENERATOR * scalar
+            );
+        }
+
+        for _ in 0..200 {
+            let scalar = Scalar::random(&mut OsRng);
+            assert_eq!(
+                ProjectivePoint::mul_by_generator(&scalar),
+                ProjectivePoint::GENERATOR * scalar
+            );
+        }
+    }
 }
@@ -43,6 +43,31 @@ use primeorder::impl_bernstein_yang_invert;
 pub(crate) const MODULUS: U384 = U384::from_be_hex(FieldElement::MODULUS);
 
 /// Element of the secp384r1 base field used for curve coordinates.
+///
+/// The internal representation is in Montgomery form, which is an
+/// implementation detail: the only stable interchange format is the
+/// canonical big-endian SEC1 encoding used by [`FieldElement::from_bytes`]
+/// (which rejects values `>= p`) and [`FieldElement::to_bytes`].
+///
+/// This type is only exposed under the off-by-default `expose-field`
+/// feature, for out-of-tree constructions which need raw field arithmetic
+/// (SSWU mapping, custom compression, x-only ladders). Verifying the curve
+/// equation for the generator:
+///
+/// ```
+/// # #[cfg(feature = "expose-field")]
+/// # {
+/// use p384::{elliptic_curve::sec1::ToEncodedPoint, AffinePoint, FieldElement};
+///
+/// let encoded = AffinePoint::GENERATOR.to_encoded_point(false);
+/// let x = FieldElement::from_bytes(encoded.x().unwrap()).unwrap();
+/// let y = FieldElement::from_bytes(encoded.y().unwrap()).unwrap();
+///
+/// // y² = x³ + a·x + b with a = -3
+/// let y2 = x.square() * x + FieldElement::EQUATION_A * x + FieldElement::EQUATION_B;
+/// assert_eq!(y.square(), y2);
+/// # }
+/// ```
 #[derive(Clone, Copy)]
 pub struct FieldElement(pub(super) U384);
 
@@ -63,6 +88,14 @@ primeorder::impl_mont_field_element!(
 );
 
 impl FieldElement {
+    /// The `a` coefficient of the short Weierstrass curve equation: `-3`.
+    pub const EQUATION_A: Self = FieldElement::sub(&Self::ZERO, &Self::from_u64(3));
+
+    /// The `b` coefficient of the short Weierstrass curve equation.
+    pub const EQUATION_B: Self = Self::from_hex(
+        "b3312fa7e23ee7e4988e056be3f82d19181d9c6efe8141120314088f5013875ac656398d8a2ed19d2a85c8edd3ec2aef",
+    );
+
     /// Compute [`FieldElement`] inversion: `1 / self`.
     pub fn invert(&self) -> CtOption<Self> {
         CtOption::new(self.invert_unchecked(), !self.is_zero())
@@ -96,7 +96,10 @@ impl FromOkm for Scalar {
 
 #[cfg(test)]
 mod tests {
-    use crate::{arithmetic::field::MODULUS, FieldElement, NistP384, Scalar};
+    use crate::{
+        arithmetic::field::{FieldElement, MODULUS},
+        NistP384, Scalar,
+    };
     use elliptic_curve::{
         bigint::{ArrayEncoding, CheckedSub, NonZero, U384, U576},
         consts::U72,
@@ -244,6 +247,89 @@ mod tests {
         }
     }
 
+    /// `P384_XMD:SHA-384_SSWU_NU_` suite (RFC 9380 `encode_to_curve`):
+    /// single field element, direct SSWU output, no addition step.
+    #[test]
+    fn encode_to_curve() {
+        struct TestVector {
+            msg: &'static [u8],
+            u_0: [u8; 48],
+            p_x: [u8; 48],
+            p_y: [u8; 48],
+        }
+
+        const DST: &[u8] = b"QUUX-V01-CS02-with-P384_XMD:SHA-384_SSWU_NU_";
+
+        const TEST_VECTORS: &[TestVector] = &[
+            TestVector {
+                msg: b"",
+                u_0: hex!("bc7dc1b2cdc5d588a66de3276b0f24310d4aca4977efda7d6272e1be25187b001493d267dc53b56183c9e28282368e60"),
+                p_x: hex!("de5a893c83061b2d7ce6a0d8b049f0326f2ada4b966dc7e72927256b033ef61058029a3bfb13c1c7ececd6641881ae20"),
+                p_y: hex!("63f46da6139785674da315c1947e06e9a0867f5608cf24724eb3793a1f5b3809ee28eb21a0c64be3be169afc6cdb38ca"),
+            },
+            TestVector {
+                msg: b"abc",
+                u_0: hex!("9de6cf41e6e41c03e4a7784ac5c885b4d1e49d6de390b3cdd5a1ac5dd8c40afb3dfd7bb2686923bab644134483fc1926"),
+                p_x: hex!("1f08108b87e703c86c872ab3eb198a19f2b708237ac4be53d7929fb4bd5194583f40d052f32df66afe5249c9915d139b"),
+                p_y: hex!("1369dc8d5bf038032336b989994874a2270adadb67a7fcc32f0f8824bc5118613f0ac8de04a1041d90ff8a5ad555f96c"),
+            },
+            TestVector {
+                msg: b"abcdef0123456789",
+                u_0: hex!("84e2d430a5e2543573e58e368af41821ca3ccc97baba7e9aab51a84543d5a0298638a22ceee6090d9d642921112af5b7"),
+                p_x: hex!("4dac31ec8a82ee3c02ba2d7c9fa431f1e59ffe65bf977b948c59e1d813c2d7963c7be81aa6db39e78ff315a10115c0d0"),
+                p_y: hex!("845333cdb5702ad5c525e603f302904d6fc84879f0ef2ee2014a6b13edd39131bfd66f7bd7cdc2d9ccf778f0c8892c3f"),
+            },
+        ];
+
+        for test_vector in TEST_VECTORS {
+            let mut u = [FieldElement::default()];
+            hash2curve::hash_to_field::<ExpandMsgXmd<Sha384>, FieldElement>(
+                &[test_vector.msg],
+                &[DST],
+                &mut u,
+            )
+            .unwrap();
+            assert_eq!(u[0].to_bytes().as_slice(), test_vector.u_0);
+
+            let point =
+                NistP384::encode_from_bytes::<ExpandMsgXmd<Sha384>>(&[test_vector.msg], &[DST])
+                    .unwrap()
+                    .to_affine()
+                    .to_encoded_point(false);
+            let (x, y) = match point.coordinates() {
+                sec1::Coordinates::Uncompressed { x, y } => (x, y),
+                _ => unreachable!(),
+            };
+            assert_eq!(x.as_slice(), test_vector.p_x);
+            assert_eq!(y.as_slice(), test_vector.p_y);
+        }
+    }
+
+    /// Outputs of both variants are always valid non-identity curve
+    /// points.
+    #[test]
+    fn outputs_on_curve_and_non_identity() {
+        use elliptic_curve::group::Group;
+
+        const DST: &[u8] = b"P384_XMD:SHA-384_SSWU_PROPTEST";
+
+        proptest!(ProptestConfig::with_cases(100), |(msg in proptest::collection::vec(proptest::num::u8::ANY, 0..64))| {
+            let ro = NistP384::hash_from_bytes::<ExpandMsgXmd<Sha384>>(&[&msg], &[DST]).unwrap();
+            let nu = NistP384::encode_from_bytes::<ExpandMsgXmd<Sha384>>(&[&msg], &[DST]).unwrap();
+
+            for point in [ro, nu] {
+                assert!(!bool::from(point.is_identity()));
+                // SEC1 round-trip checks the curve equation
+                let affine = point.to_affine();
+                let encoded = affine.to_encoded_point(false);
+                assert_eq!(crate::AffinePoint::try_from(&encoded).unwrap(), affine);
+            }
+
+            // the two variants are distinct constructions
+            assert_ne!(ro, nu);
+        });
+    }
+
     /// Taken from <https://datatracker.ietf.org/doc/html/draft-irtf-cfrg-voprf#appendix-A.4>.
     #[test]
     fn hash_to_scalar_voprf() {
@@ -406,6 +406,48 @@ mod tests {
         0x7fffffffffffffff,
     ];
 
+    /// The little-endian bit view must equal the bits of the canonical
+    /// big-endian byte encoding, for any backing word size (u32 or u64
+    /// limbs): running this on both layouts proves they produce identical
+    /// bit sequences.
+    #[cfg(feature = "bits")]
+    #[test]
+    fn prime_field_bits_layout_independent() {
+        use elliptic_curve::{
+            group::ff::{Field, PrimeFieldBits},
+            rand_core::OsRng,
+        };
+
+        let mut scalars = [Scalar::ZERO; 103];
+        scalars[1] = Scalar::ONE;
+        scalars[2] = -Scalar::ONE;
+        for slot in scalars.iter_mut().skip(3) {
+            *slot = Scalar::random(&mut OsRng);
+        }
+
+        for scalar in scalars {
+            let bits = scalar.to_le_bits();
+            let bytes = scalar.to_bytes();
+            assert_eq!(bits.len(), 384);
+
+            for (i, bit) in bits.iter().enumerate() {
+                let byte = bytes[bytes.len() - 1 - i / 8];
+                assert_eq!(*bit, (byte >> (i % 8)) & 1 == 1, "bit {i}");
+            }
+        }
+
+        // char_le_bits is the P-384 group order
+        let order = hex_literal::hex!(
+            "ffffffffffffffffffffffffffffffffffffffffffffffffc7634d81f4372ddf
+             581a0db248b0a77aecec196accc52973"
+        );
+        let bits = Scalar::char_le_bits();
+        for (i, bit) in bits.iter().enumerate() {
+            let byte = order[order.len() - 1 - i / 8];
+            assert_eq!(*bit, (byte >> (i % 8)) & 1 == 1, "order bit {i}");
+        }
+    }
+
     impl_field_identity_tests!(Scalar);
     impl_field_invert_tests!(Scalar);
     impl_field_sqrt_tests!(Scalar);
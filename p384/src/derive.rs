@@ -0,0 +1,135 @@
+//! Deterministic secret key derivation from seed material.
+//!
+//! [`DeriveFromSeed`] derives P-384 secret keys from a master secret plus a
+//! label using the [RFC 9380] `hash_to_field` machinery
+//! (`expand_message_xmd` with SHA-384, `L = 72`), so derived scalars are
+//! uniformly distributed mod `n`. The derivation is **deterministic and
+//! stable across releases**: the DST, the message framing
+//! `ikm || I2OSP(len(info), 2) || info || I2OSP(counter, 1)`, and the
+//! counter retry rule (increment on a zero scalar, as in RFC 9497's
+//! `DeriveKeyPair`) are all fixed and pinned by test vectors.
+//!
+//! [RFC 9380]: https://www.rfc-editor.org/rfc/rfc9380
+
+use crate::{NistP384, NonZeroScalar, SecretKey};
+use elliptic_curve::hash2curve::{ExpandMsgXmd, GroupDigest};
+use sha2::Sha384;
+
+/// Domain separation tag; versioned so any future change must use a new
+/// tag rather than silently altering derived keys.
+const DST: &[u8] = b"DeriveSecretKey-P384_XMD:SHA-384_v1";
+
+/// Deterministic derivation of a key from seed material and a label.
+pub trait DeriveFromSeed: Sized {
+    /// Derive a key from initial keying material (e.g. a master secret)
+    /// and a distinguishing label.
+    ///
+    /// Deterministic: the same `(ikm, info)` pair always yields the same
+    /// key. The caller is responsible for the entropy of `ikm`.
+    ///
+    /// # Panics
+    ///
+    /// If `info` exceeds 65535 bytes, which the length-prefixed framing
+    /// cannot represent.
+    fn derive_from_seed(ikm: &[u8], info: &[u8]) -> Self;
+}
+
+impl DeriveFromSeed for SecretKey {
+    fn derive_from_seed(ikm: &[u8], info: &[u8]) -> Self {
+        // the 2-byte length prefix keeps the framing injective; longer
+        // labels cannot be represented, so fail loudly rather than alias
+        #[allow(clippy::expect_used)]
+        let info_len = u16::try_from(info.len())
+            .expect("info must be at most 65535 bytes")
+            .to_be_bytes();
+
+        for counter in 0u8..=255 {
+            let scalar = <NistP384 as GroupDigest>::hash_to_scalar::<ExpandMsgXmd<Sha384>>(
+                &[ikm, &info_len, info, &[counter]],
+                &[DST],
+            )
+            .expect("fixed non-empty DST cannot fail");
+
+            if let Some(scalar) = Option::<NonZeroScalar>::from(NonZeroScalar::new(scalar)) {
+                return SecretKey::from(scalar);
+            }
+        }
+
+        // 256 consecutive zero scalars: probability ~2^-65536
+        unreachable!("hash_to_scalar returned zero for every counter")
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl DeriveFromSeed for crate::ecdsa::SigningKey {
+    fn derive_from_seed(ikm: &[u8], info: &[u8]) -> Self {
+        SecretKey::derive_from_seed(ikm, info).into()
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::DeriveFromSeed;
+    use crate::SecretKey;
+    use hex_literal::hex;
+
+    // Pinned vectors: these must never change across releases. Generated
+    // with an independent implementation of the documented derivation.
+    #[test]
+    fn pinned_derivation_vectors() {
+        let cases: [(&[u8], &[u8], [u8; 48]); 3] = [
+            (
+                b"",
+                b"",
+                hex!(
+                    "019c9ed8a4ac2c540b54e7aed03fa25a05447be9642a76b766d2936eb1fca7a3
+                     54b792761e623a7bd6b45f7b0e53eade"
+                ),
+            ),
+            (
+                &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+                b"signing key v1",
+                hex!(
+                    "44beed74218f1c219f1de449e2909466f580faeef86e76abb663185b621e353f
+                     d6b71a0ddea7ef279fa5cbd97e72460f"
+                ),
+            ),
+            (
+                &hex!("000102030405060708090a0b0c0d0e0f101112131415161718191a1b1c1d1e1f"),
+                b"signing key v2",
+                hex!(
+                    "3bbb2c777715f899d9ea3def6ed1c6e15a308f978df4785ceb39360b624cd501
+                     d1eb643454dcc6c08d81240116492fc3"
+                ),
+            ),
+        ];
+
+        for (ikm, info, expected) in cases {
+            let key = SecretKey::derive_from_seed(ikm, info);
+            assert_eq!(key.to_bytes().as_slice(), &expected);
+        }
+    }
+
+    #[test]
+    fn deterministic_and_label_separated() {
+        let a = SecretKey::derive_from_seed(b"master", b"label-1");
+        let b = SecretKey::derive_from_seed(b"master", b"label-1");
+        let c = SecretKey::derive_from_seed(b"master", b"label-2");
+        let d = SecretKey::derive_from_seed(b"other master", b"label-1");
+
+        assert_eq!(a.to_bytes(), b.to_bytes());
+        assert_ne!(a.to_bytes(), c.to_bytes());
+        assert_ne!(a.to_bytes(), d.to_bytes());
+    }
+
+    #[cfg(feature = "ecdsa")]
+    #[test]
+    fn signing_key_matches_secret_key() {
+        use crate::ecdsa::SigningKey;
+
+        let secret = SecretKey::derive_from_seed(b"master", b"ecdsa");
+        let signing = SigningKey::derive_from_seed(b"master", b"ecdsa");
+        assert_eq!(signing.to_bytes(), secret.to_bytes());
+    }
+}
@@ -39,9 +39,210 @@
 pub use elliptic_curve::ecdh::diffie_hellman;
 
 use crate::NistP384;
+use elliptic_curve::rand_core::CryptoRngCore;
 
 /// NIST P-384 Ephemeral Diffie-Hellman Secret.
 pub type EphemeralSecret = elliptic_curve::ecdh::EphemeralSecret<NistP384>;
 
 /// Shared secret value computed via ECDH key agreement.
 pub type SharedSecret = elliptic_curve::ecdh::SharedSecret<NistP384>;
+
+/// `FixedInfo` context for the [SP 800-56C] one-step KDF, assembled from
+/// the standard `AlgorithmID || PartyUInfo || PartyVInfo` fields with
+/// optional supplementary info.
+///
+/// The subfield encodings (length prefixes, identifiers) are defined by
+/// the key-establishment protocol; this builder concatenates the fields
+/// exactly as provided.
+///
+/// [SP 800-56C]: https://csrc.nist.gov/publications/detail/sp/800-56c/rev-2/final
+#[cfg(feature = "alloc")]
+#[derive(Clone, Debug, Default)]
+pub struct OtherInfo {
+    fixed_info: alloc::vec::Vec<u8>,
+}
+
+#[cfg(feature = "alloc")]
+impl OtherInfo {
+    /// Start building `FixedInfo` from the `AlgorithmID` field.
+    pub fn new(algorithm_id: &[u8]) -> Self {
+        Self {
+            fixed_info: algorithm_id.to_vec(),
+        }
+    }
+
+    /// Append the `PartyUInfo` field (the initiator's identifier).
+    pub fn party_u_info(mut self, info: &[u8]) -> Self {
+        self.fixed_info.extend_from_slice(info);
+        self
+    }
+
+    /// Append the `PartyVInfo` field (the responder's identifier).
+    pub fn party_v_info(mut self, info: &[u8]) -> Self {
+        self.fixed_info.extend_from_slice(info);
+        self
+    }
+
+    /// Append the optional `SuppPubInfo` field.
+    pub fn supp_pub_info(mut self, info: &[u8]) -> Self {
+        self.fixed_info.extend_from_slice(info);
+        self
+    }
+
+    /// Append the optional `SuppPrivInfo` field.
+    pub fn supp_priv_info(mut self, info: &[u8]) -> Self {
+        self.fixed_info.extend_from_slice(info);
+        self
+    }
+}
+
+/// [SP 800-56C] one-step (concatenation) key derivation over an ECDH
+/// shared secret.
+///
+/// [SP 800-56C]: https://csrc.nist.gov/publications/detail/sp/800-56c/rev-2/final
+#[cfg(all(feature = "alloc", feature = "sha2"))]
+pub trait ConcatKdf {
+    /// Derive `okm_len` bytes as
+    /// `Hash(counter || Z || FixedInfo)` for `counter = 1, 2, ...`,
+    /// concatenated and truncated.
+    ///
+    /// Errors if `okm_len` is zero or would require more than `2^32 - 1`
+    /// hash invocations.
+    fn concat_kdf<D: sha2::digest::Digest>(
+        &self,
+        other_info: &OtherInfo,
+        okm_len: usize,
+    ) -> elliptic_curve::Result<elliptic_curve::zeroize::Zeroizing<alloc::vec::Vec<u8>>>;
+}
+
+#[cfg(all(feature = "alloc", feature = "sha2"))]
+impl ConcatKdf for elliptic_curve::ecdh::SharedSecret<NistP384> {
+    fn concat_kdf<D: sha2::digest::Digest>(
+        &self,
+        other_info: &OtherInfo,
+        okm_len: usize,
+    ) -> elliptic_curve::Result<elliptic_curve::zeroize::Zeroizing<alloc::vec::Vec<u8>>> {
+        let hash_len = <D as sha2::digest::Digest>::output_size();
+        let reps = (okm_len + hash_len - 1) / hash_len;
+
+        if okm_len == 0 || u32::try_from(reps).is_err() {
+            return Err(elliptic_curve::Error);
+        }
+
+        let mut okm = elliptic_curve::zeroize::Zeroizing::new(alloc::vec::Vec::with_capacity(
+            reps * hash_len,
+        ));
+        for counter in 1..=reps as u32 {
+            let digest = D::new()
+                .chain_update(counter.to_be_bytes())
+                .chain_update(self.raw_secret_bytes())
+                .chain_update(&other_info.fixed_info)
+                .finalize();
+            okm.extend_from_slice(&digest);
+        }
+
+        okm.truncate(okm_len);
+        Ok(okm)
+    }
+}
+
+/// Static-ephemeral convenience: generate an ephemeral key pair, compute
+/// the shared secret against the recipient's static public key, and
+/// return it with the ephemeral public key to transmit.
+pub fn diffie_hellman_ephemeral(
+    recipient: &crate::PublicKey,
+    rng: &mut impl CryptoRngCore,
+) -> (
+    crate::PublicKey,
+    elliptic_curve::ecdh::SharedSecret<NistP384>,
+) {
+    let ephemeral = crate::NonZeroScalar::random(rng);
+    let ephemeral_pub = crate::PublicKey::from_secret_scalar(&ephemeral);
+    let shared = diffie_hellman(ephemeral, recipient.as_affine());
+    (ephemeral_pub, shared)
+}
+
+#[cfg(all(test, feature = "alloc", feature = "sha2"))]
+mod tests {
+    use super::{diffie_hellman, diffie_hellman_ephemeral, ConcatKdf, OtherInfo};
+    use crate::{PublicKey, SecretKey};
+    use elliptic_curve::rand_core::OsRng;
+    use hex_literal::hex;
+    use sha2::Sha384;
+
+    /// ECC CDH primitive + one-step KDF known answers, cross-checked
+    /// against OpenSSL (`pkeyutl -derive`) and an independent KDF
+    /// implementation.
+    #[test]
+    fn cdh_primitive_and_one_step_kdf() {
+        let d_iut = SecretKey::from_slice(&hex!(
+            "19aa5548c53230756d3452ee18e587a91fd08e7d9bc42beb513a995ab0f8afe2
+             39c9b97f84700590cd78d22e92b445b0"
+        ))
+        .unwrap();
+        let q_cavs = PublicKey::from_sec1_bytes(&hex!(
+            "04710b78b5c8ae5f722b951de35e58bd9b84e8241b148cf8965796230eca7d99
+             b0640ce946adddf5ef7fd41b2bedb163a532c14a525828b3b856563f50487ce3
+             f3b744602d3d66c0a264fcfc917c3701e4947dbaa3823a518a578c47ee9e4683
+             ba"
+        ))
+        .unwrap();
+
+        let z = diffie_hellman(d_iut.to_nonzero_scalar(), q_cavs.as_affine());
+        assert_eq!(
+            z.raw_secret_bytes().as_slice(),
+            &hex!(
+                "49793ce46f1bd04a90585e0c351ad74ea08a8a8b4d0defaa97eb0aad46f19bad
+                 5fb148f2352177bebea6cb37801bd924"
+            )
+        );
+
+        let other_info = OtherInfo::new(b"CNSA-AES-256-GCM")
+            .party_u_info(b"Alice")
+            .party_v_info(b"Bob");
+
+        // multi-block output
+        let okm = z.concat_kdf::<Sha384>(&other_info, 64).unwrap();
+        assert_eq!(
+            okm.as_slice(),
+            &hex!(
+                "33e1ce388fa56bb715417164c299b8d9584277c936690105a0c862639c316a52
+                 311239be447b64406ecf8518b7c462265bd0fe92ac864429025a829ef1662e0a"
+            )
+        );
+
+        // truncated single-block output is a prefix
+        let okm = z.concat_kdf::<Sha384>(&other_info, 32).unwrap();
+        assert_eq!(
+            okm.as_slice(),
+            &hex!("33e1ce388fa56bb715417164c299b8d9584277c936690105a0c862639c316a52")
+        );
+
+        // degenerate length rejected
+        assert!(z.concat_kdf::<Sha384>(&other_info, 0).is_err());
+
+        // distinct FixedInfo, distinct keys
+        let other = OtherInfo::new(b"CNSA-AES-256-GCM")
+            .party_u_info(b"Alice")
+            .party_v_info(b"Mallory");
+        assert_ne!(
+            z.concat_kdf::<Sha384>(&other_info, 32).unwrap(),
+            z.concat_kdf::<Sha384>(&other, 32).unwrap()
+        );
+    }
+
+    #[test]
+    fn static_ephemeral_round_trip() {
+        let static_secret = SecretKey::random(&mut OsRng);
+        let static_public = static_secret.public_key();
+
+        let (ephemeral_pub, sender_shared) = diffie_hellman_ephemeral(&static_public, &mut OsRng);
+
+        let receiver_shared =
+            diffie_hellman(static_secret.to_nonzero_scalar(), ephemeral_pub.as_affine());
+        assert_eq!(
+            sender_shared.raw_secret_bytes(),
+            receiver_shared.raw_secret_bytes()
+        );
+    }
+}
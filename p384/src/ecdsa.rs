@@ -36,8 +36,35 @@
 //! assert!(verifying_key.verify(message, &signature).is_ok());
 //! # }
 //! ```
+//!
+//! ## Recoverable Signatures
+//!
+//! [`SigningKey::sign_prehash_recoverable`] returns a [`RecoveryId`]
+//! alongside the signature, from which
+//! [`VerifyingKey::recover_from_prehash`] can reconstruct the public key —
+//! useful where shipping the 97-byte public key per message is too
+//! expensive:
+//!
+//! ```
+//! # #[cfg(feature = "ecdsa")]
+//! # {
+//! use p384::ecdsa::{SigningKey, VerifyingKey};
+//! use rand_core::OsRng;
+//! use sha2::{Digest, Sha384};
+//!
+//! let signing_key = SigningKey::random(&mut OsRng);
+//! let prehash = Sha384::digest(b"compact telemetry record");
+//!
+//! let (signature, recovery_id) = signing_key.sign_prehash_recoverable(&prehash).unwrap();
+//! let recovered = VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id).unwrap();
+//! assert_eq!(&recovered, signing_key.verifying_key());
+//! # }
+//! ```
 
 pub use ecdsa_core::signature::{self, Error};
+
+#[cfg(feature = "ecdsa")]
+pub use ecdsa_core::RecoveryId;
 #[cfg(feature = "ecdsa")]
 use {
     crate::{AffinePoint, Scalar},
@@ -65,6 +92,38 @@ impl ecdsa_core::hazmat::DigestPrimitive for NistP384 {
     type Digest = sha2::Sha384;
 }
 
+/// Deterministic ECDSA/P-384 using SHA3-384 end to end: the message
+/// digest *and* the RFC 6979 HMAC-DRBG hash are SHA3-384 (the generic
+/// [`DigestSigner`] impl accepts any 48-byte digest for the message but
+/// always runs the nonce DRBG with SHA-384).
+///
+/// Verification needs no special entry point: use
+/// [`DigestVerifier::verify_digest`] with a `Sha3_384` digest.
+///
+/// [`DigestSigner`]: signature::DigestSigner
+/// [`DigestVerifier::verify_digest`]: signature::DigestVerifier::verify_digest
+#[cfg(all(feature = "ecdsa", feature = "sha3"))]
+pub trait Sha3DigestSigner {
+    /// Sign a SHA3-384 message digest with RFC 6979 nonce generation
+    /// parametrized over SHA3-384.
+    fn try_sign_digest_sha3(&self, msg_digest: sha3::Sha3_384) -> Result<Signature, Error>;
+}
+
+#[cfg(all(feature = "ecdsa", feature = "sha3"))]
+impl Sha3DigestSigner for SigningKey {
+    fn try_sign_digest_sha3(&self, msg_digest: sha3::Sha3_384) -> Result<Signature, Error> {
+        use sha2::digest::Digest;
+
+        let z = ecdsa_core::hazmat::bits2field::<NistP384>(&msg_digest.finalize())?;
+        let (signature, _) = self
+            .as_nonzero_scalar()
+            .as_ref()
+            .try_sign_prehashed_rfc6979::<sha3::Sha3_384>(&z, &[])?;
+
+        Ok(signature)
+    }
+}
+
 #[cfg(feature = "ecdsa")]
 impl SignPrimitive<NistP384> for Scalar {}
 
@@ -182,4 +241,178 @@ mod tests {
         use crate::NistP384;
         ecdsa_core::new_wycheproof_test!(wycheproof, "wycheproof", NistP384);
     }
+
+    #[cfg(feature = "sha3")]
+    mod sha3_signing {
+        use crate::ecdsa::{
+            signature::{DigestSigner, DigestVerifier},
+            Sha3DigestSigner, Signature, SigningKey,
+        };
+        use hex_literal::hex;
+        use sha2::{Digest, Sha384};
+        use sha3::Sha3_384;
+
+        // RFC 6979 A.2.6 private key; KAT cross-checked against an
+        // independent implementation of RFC 6979 with SHA3-384 as both the
+        // message and HMAC hash
+        #[test]
+        fn sha3_384_kat() {
+            let sk = SigningKey::from_bytes(
+                &hex!(
+                    "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d8
+                     96d5724e4c70a825f872c9ea60d2edf5"
+                )
+                .into(),
+            )
+            .unwrap();
+
+            let signature = sk
+                .try_sign_digest_sha3(Sha3_384::new_with_prefix(b"sample"))
+                .unwrap();
+            assert_eq!(
+                signature.to_bytes().as_slice(),
+                &hex!(
+                    "57efd06fd30653794ce388d6cc91d8da969225a07b322e334ea2832c5217d3d9
+                     99838eee9dc983d7b328b704dd302d148c051c2b54e5a0bd7a91e11e47c00df4
+                     21a6c8d33ba7c75915f7d5df6597a6f8872cf1d886cd7b1c5e087df24b8114ab"
+                )
+            );
+
+            // deterministic
+            assert_eq!(
+                sk.try_sign_digest_sha3(Sha3_384::new_with_prefix(b"sample"))
+                    .unwrap(),
+                signature
+            );
+        }
+
+        #[test]
+        fn sha2_and_sha3_signatures_differ_but_both_verify() {
+            let sk = SigningKey::from_bytes(
+                &hex!(
+                    "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d8
+                     96d5724e4c70a825f872c9ea60d2edf5"
+                )
+                .into(),
+            )
+            .unwrap();
+            let vk = sk.verifying_key();
+
+            let sha3_sig = sk
+                .try_sign_digest_sha3(Sha3_384::new_with_prefix(b"sample"))
+                .unwrap();
+            let sha2_sig: Signature = sk.sign_digest(Sha384::new_with_prefix(b"sample"));
+
+            assert_ne!(sha3_sig, sha2_sig);
+            vk.verify_digest(Sha3_384::new_with_prefix(b"sample"), &sha3_sig)
+                .unwrap();
+            vk.verify_digest(Sha384::new_with_prefix(b"sample"), &sha2_sig)
+                .unwrap();
+
+            // digests must match the signature they verify
+            assert!(vk
+                .verify_digest(Sha384::new_with_prefix(b"sample"), &sha3_sig)
+                .is_err());
+            assert!(vk
+                .verify_digest(Sha3_384::new_with_prefix(b"sample"), &sha2_sig)
+                .is_err());
+        }
+    }
+
+    mod recovery {
+        use crate::ecdsa::{
+            signature::hazmat::PrehashVerifier, RecoveryId, Signature, SigningKey, VerifyingKey,
+        };
+        use elliptic_curve::rand_core::OsRng;
+        use hex_literal::hex;
+
+        #[test]
+        fn round_trips_for_random_keys() {
+            let mut seen = [false; 2];
+
+            for i in 0..100u32 {
+                let signing_key = SigningKey::random(&mut OsRng);
+                let mut prehash = [0u8; 48];
+                prehash[..4].copy_from_slice(&i.to_be_bytes());
+
+                let (signature, recovery_id) =
+                    signing_key.sign_prehash_recoverable(&prehash).unwrap();
+                assert!(!recovery_id.is_x_reduced());
+                seen[usize::from(recovery_id.to_byte())] = true;
+
+                let recovered =
+                    VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id).unwrap();
+                assert_eq!(&recovered, signing_key.verifying_key());
+            }
+
+            // both y parities occur over 100 random nonces
+            assert!(seen[0] && seen[1]);
+        }
+
+        // The reduced-x case cannot be hit by random signing (p - n is
+        // ~2^190 for P-384), so exercise it synthetically: x = n + 2 lies
+        // on the curve, recovery lifts r = 2 back to that point, and the
+        // recovered key verifies the signature by construction.
+        #[test]
+        fn x_reduced_recovery_ids() {
+            let prehash = hex!(
+                "768412320f7b0aa5812fce428dc4706b3cae50e02a64caa16a782249bfe8efc4b7ef1ccb126255d196047dfedf17a0a9"
+            );
+            let r = hex!(
+                "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000002"
+            );
+            let s = hex!(
+                "5fbeb35e1e9b4d0e1c1b1a8b9c21a4c6c8e88c9a9f3e9f1b8d5a9e3c2b1a09185fbeb35e1e9b4d0e1c1b1a8b9c21a4c6"
+            );
+            let signature = Signature::from_scalars(r, s).unwrap();
+
+            for recovery_id in [RecoveryId::new(false, true), RecoveryId::new(true, true)] {
+                assert!(recovery_id.is_x_reduced());
+
+                let recovered =
+                    VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id).unwrap();
+                recovered.verify_prehash(&prehash, &signature).unwrap();
+            }
+
+            let even = VerifyingKey::recover_from_prehash(
+                &prehash,
+                &signature,
+                RecoveryId::new(false, true),
+            )
+            .unwrap();
+            let odd = VerifyingKey::recover_from_prehash(
+                &prehash,
+                &signature,
+                RecoveryId::new(true, true),
+            )
+            .unwrap();
+            assert_ne!(even, odd);
+        }
+
+        // VerifyingKey construction rejects the identity, so recovery of a
+        // degenerate (identity) public key must error rather than yield an
+        // unusable key.
+        #[test]
+        fn identity_recovery_rejected() {
+            // z == r * x(R) * s_inv arrangement cannot be constructed
+            // directly without solving a discrete log; instead check the
+            // plumbing: an r with no corresponding curve point errors
+            let prehash = [0x24u8; 48];
+            let r = hex!(
+                "000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000000005"
+            );
+            let s = hex!(
+                "5fbeb35e1e9b4d0e1c1b1a8b9c21a4c6c8e88c9a9f3e9f1b8d5a9e3c2b1a09185fbeb35e1e9b4d0e1c1b1a8b9c21a4c6"
+            );
+            let signature = Signature::from_scalars(r, s).unwrap();
+
+            // x = 5 is not on P-384
+            assert!(VerifyingKey::recover_from_prehash(
+                &prehash,
+                &signature,
+                RecoveryId::new(false, false)
+            )
+            .is_err());
+        }
+    }
 }
@@ -19,9 +19,15 @@
 //!
 //! Please see type-specific documentation for more information.
 
+#[cfg(all(feature = "alloc", feature = "ecdh", feature = "sha2"))]
+extern crate alloc;
+
 #[cfg(feature = "arithmetic")]
 mod arithmetic;
 
+#[cfg(all(feature = "hash2curve", feature = "sha384"))]
+pub mod derive;
+
 #[cfg(feature = "ecdh")]
 pub mod ecdh;
 
@@ -31,6 +37,9 @@ pub mod ecdsa;
 #[cfg(any(feature = "test-vectors", test))]
 pub mod test_vectors;
 
+#[cfg(feature = "voprf")]
+pub mod voprf;
+
 pub use elliptic_curve::{self, bigint::U384, consts::U48};
 
 #[cfg(feature = "arithmetic")]
@@ -0,0 +1,314 @@
+//! RFC 9497 (V)OPRF group operations for the P384-SHA384 ciphersuite.
+//!
+//! [`VoprfGroup`] packages the prime-order group interface an OPRF
+//! protocol implementation needs on top of this crate: `HashToGroup` and
+//! `HashToScalar` with the RFC 9497 context-string DST construction,
+//! `DeriveKeyPair`, and strict element (de)serialization using 49-byte
+//! compressed SEC1 encodings. Scalar inversion for unblinding is available
+//! via [`NonZeroScalar::invert`].
+//!
+//! [RFC 9497]: https://www.rfc-editor.org/rfc/rfc9497
+
+use crate::{AffinePoint, EncodedPoint, NistP384, NonZeroScalar, ProjectivePoint, Scalar};
+use elliptic_curve::{
+    hash2curve::{ExpandMsgXmd, GroupDigest},
+    sec1::{FromEncodedPoint, ToEncodedPoint},
+    Error, Result, VoprfParameters,
+};
+use sha2::Sha384;
+
+/// Protocol mode, bound into every derived DST.
+#[derive(Copy, Clone, Debug, Eq, PartialEq)]
+pub enum Mode {
+    /// Base mode (`0x00`).
+    Oprf,
+
+    /// Verifiable mode (`0x01`).
+    Voprf,
+
+    /// Partially-oblivious mode (`0x02`).
+    Poprf,
+}
+
+impl Mode {
+    fn to_byte(self) -> u8 {
+        match self {
+            Mode::Oprf => 0x00,
+            Mode::Voprf => 0x01,
+            Mode::Poprf => 0x02,
+        }
+    }
+}
+
+/// Length of `"OPRFV1-" || mode || "-" || "P384-SHA384"`.
+const CONTEXT_LEN: usize = 20;
+
+/// RFC 9497 `contextString` for the given mode.
+fn context_string(mode: Mode) -> [u8; CONTEXT_LEN] {
+    let mut out = [0u8; CONTEXT_LEN];
+    out[..7].copy_from_slice(b"OPRFV1-");
+    out[7] = mode.to_byte();
+    out[8] = b'-';
+    out[9..].copy_from_slice(NistP384::ID.as_bytes());
+    out
+}
+
+/// RFC 9497 prime-order group operations for an OPRF ciphersuite.
+///
+/// Implemented for [`NistP384`] as the `P384-SHA384` ciphersuite.
+pub trait VoprfGroup: VoprfParameters {
+    /// `HashToGroup`: hash `input` to a group element with the
+    /// mode-specific `HashToGroup-` DST.
+    fn hash_to_group(mode: Mode, input: &[u8]) -> Result<ProjectivePoint>;
+
+    /// `HashToScalar`: hash `input` to a scalar with the mode-specific
+    /// `HashToScalar-` DST.
+    fn hash_to_scalar(mode: Mode, input: &[u8]) -> Result<Scalar>;
+
+    /// `DeriveKeyPair`: derive a secret key from a seed and key info,
+    /// retrying with an appended counter byte until the result is
+    /// non-zero.
+    fn derive_key_pair(mode: Mode, seed: &[u8], info: &[u8]) -> Result<NonZeroScalar>;
+
+    /// `SerializeElement`: 49-byte compressed SEC1 encoding; the identity
+    /// has no valid encoding and is rejected.
+    fn serialize_element(element: &ProjectivePoint) -> Result<[u8; 49]>;
+
+    /// `DeserializeElement`: strict inverse of [`Self::serialize_element`],
+    /// rejecting malformed encodings and the identity.
+    fn deserialize_element(bytes: &[u8; 49]) -> Result<ProjectivePoint>;
+}
+
+impl VoprfGroup for NistP384 {
+    fn hash_to_group(mode: Mode, input: &[u8]) -> Result<ProjectivePoint> {
+        let mut dst = [0u8; 12 + CONTEXT_LEN];
+        dst[..12].copy_from_slice(b"HashToGroup-");
+        dst[12..].copy_from_slice(&context_string(mode));
+
+        Self::hash_from_bytes::<ExpandMsgXmd<Sha384>>(&[input], &[&dst])
+    }
+
+    fn hash_to_scalar(mode: Mode, input: &[u8]) -> Result<Scalar> {
+        let mut dst = [0u8; 13 + CONTEXT_LEN];
+        dst[..13].copy_from_slice(b"HashToScalar-");
+        dst[13..].copy_from_slice(&context_string(mode));
+
+        <Self as GroupDigest>::hash_to_scalar::<ExpandMsgXmd<Sha384>>(&[input], &[&dst])
+    }
+
+    fn derive_key_pair(mode: Mode, seed: &[u8], info: &[u8]) -> Result<NonZeroScalar> {
+        let mut dst = [0u8; 13 + CONTEXT_LEN];
+        dst[..13].copy_from_slice(b"DeriveKeyPair");
+        dst[13..].copy_from_slice(&context_string(mode));
+
+        let info_len = u16::try_from(info.len()).map_err(|_| Error)?.to_be_bytes();
+
+        for counter in 0u8..=255 {
+            let scalar = <Self as GroupDigest>::hash_to_scalar::<ExpandMsgXmd<Sha384>>(
+                &[seed, &info_len, info, &[counter]],
+                &[&dst],
+            )?;
+
+            if let Some(sk) = Option::<NonZeroScalar>::from(NonZeroScalar::new(scalar)) {
+                return Ok(sk);
+            }
+        }
+
+        Err(Error)
+    }
+
+    fn serialize_element(element: &ProjectivePoint) -> Result<[u8; 49]> {
+        let affine = element.to_affine();
+        if bool::from(affine.is_identity()) {
+            return Err(Error);
+        }
+
+        let mut out = [0u8; 49];
+        out.copy_from_slice(affine.to_encoded_point(true).as_bytes());
+        Ok(out)
+    }
+
+    fn deserialize_element(bytes: &[u8; 49]) -> Result<ProjectivePoint> {
+        let encoded = EncodedPoint::from_bytes(bytes).map_err(|_| Error)?;
+        if !encoded.is_compressed() {
+            return Err(Error);
+        }
+
+        let affine =
+            Option::<AffinePoint>::from(AffinePoint::from_encoded_point(&encoded)).ok_or(Error)?;
+
+        if bool::from(affine.is_identity()) {
+            return Err(Error);
+        }
+
+        Ok(ProjectivePoint::from(affine))
+    }
+}
+
+#[cfg(test)]
+#[allow(clippy::unwrap_used)]
+mod tests {
+    use super::{Mode, VoprfGroup};
+    use crate::{NistP384, NonZeroScalar, ProjectivePoint, Scalar};
+    use elliptic_curve::{group::Group, ops::Invert, PrimeField};
+    use hex_literal::hex;
+    use sha2::{Digest, Sha384};
+
+    /// RFC 9497 `Finalize` for the base mode: `Hash(len(input) || input ||
+    /// len(element) || element || "Finalize")`.
+    fn finalize(input: &[u8], unblinded: &[u8; 49]) -> [u8; 48] {
+        Sha384::new()
+            .chain_update(u16::try_from(input.len()).unwrap().to_be_bytes())
+            .chain_update(input)
+            .chain_update(49u16.to_be_bytes())
+            .chain_update(unblinded)
+            .chain_update(b"Finalize")
+            .finalize()
+            .into()
+    }
+
+    // Seed and key info from RFC 9497 Appendix A (P384-SHA384)
+    const SEED: [u8; 32] = hex!("a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3a3");
+    const KEY_INFO: &[u8] = b"test key";
+
+    #[test]
+    fn derive_key_pair_vectors() {
+        let cases: [(Mode, [u8; 48]); 3] = [
+            (
+                Mode::Oprf,
+                hex!(
+                    "dfe7ddc41a4646901184f2b432616c8ba6d452f9bcd0c4f75a5150ef2b2ed02e
+                     f40b8b92f60ae591bcabd72a6518f188"
+                ),
+            ),
+            (
+                Mode::Voprf,
+                hex!(
+                    "051646b9e6e7a71ae27c1e1d0b87b4381db6d3595eeeb1adb41579adbf992f42
+                     78f9016eafc944edaa2b43183581779d"
+                ),
+            ),
+            (
+                Mode::Poprf,
+                hex!(
+                    "5b2690d6954b8fbb159f19935d64133f12770c00b68422559c65431942d721ff
+                     79d47d7a75906c30b7818ec0f38b7fb2"
+                ),
+            ),
+        ];
+
+        for (mode, sk_sm) in cases {
+            let sk = NistP384::derive_key_pair(mode, &SEED, KEY_INFO).unwrap();
+            assert_eq!(sk.to_bytes().as_slice(), &sk_sm, "{mode:?}");
+        }
+    }
+
+    // Base-mode protocol test vectors from RFC 9497 Appendix A.2.1
+    #[test]
+    fn oprf_base_mode_vectors() {
+        struct TestVector {
+            input: &'static [u8],
+            blinded_element: [u8; 49],
+            evaluation_element: [u8; 49],
+            output: [u8; 48],
+        }
+
+        const BLIND: [u8; 48] = hex!(
+            "504650f53df8f16f6861633388936ea23338fa65ec36e0290022b48eb562889d
+             89dbfa691d1cde91517fa222ed7ad364"
+        );
+
+        const TEST_VECTORS: &[TestVector] = &[
+            TestVector {
+                input: &hex!("00"),
+                blinded_element: hex!(
+                    "02a36bc90e6db34096346eaf8b7bc40ee1113582155ad3797003ce614c835a87
+                     4343701d3f2debbd80d97cbe45de6e5f1f"
+                ),
+                evaluation_element: hex!(
+                    "03af2a4fc94770d7a7bf3187ca9cc4faf3732049eded2442ee50fbddda58b70a
+                     e2999366f72498cdbc43e6f2fc184afe30"
+                ),
+                output: hex!(
+                    "ed84ad3f31a552f0456e58935fcc0a3039db42e7f356dcb32aa6d487b6b815a0
+                     7d5813641fb1398c03ddab5763874357"
+                ),
+            },
+            TestVector {
+                input: &hex!("5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a5a"),
+                blinded_element: hex!(
+                    "02def6f418e3484f67a124a2ce1bfb19de7a4af568ede6a1ebb2733882510ddd
+                     43d05f2b1ab5187936a55e50a847a8b900"
+                ),
+                evaluation_element: hex!(
+                    "034e9b9a2960b536f2ef47d8608b21597ba400d5abfa1825fd21c36b75f927f3
+                     96bf3716c96129d1fa4a77fa1d479c8d7b"
+                ),
+                output: hex!(
+                    "dd4f29da869ab9355d60617b60da0991e22aaab243a3460601e48b075859d1c5
+                     26d36597326f1b985778f781a1682e75"
+                ),
+            },
+        ];
+
+        let sk = NistP384::derive_key_pair(Mode::Oprf, &SEED, KEY_INFO).unwrap();
+        let blind = NonZeroScalar::new(Scalar::from_repr(BLIND.into()).unwrap()).unwrap();
+
+        for vector in TEST_VECTORS {
+            // client: blind
+            let element = NistP384::hash_to_group(Mode::Oprf, vector.input).unwrap();
+            let blinded = element * blind.as_ref();
+            assert_eq!(
+                NistP384::serialize_element(&blinded).unwrap(),
+                vector.blinded_element
+            );
+
+            // server: evaluate
+            let evaluated =
+                NistP384::deserialize_element(&vector.blinded_element).unwrap() * sk.as_ref();
+            assert_eq!(
+                NistP384::serialize_element(&evaluated).unwrap(),
+                vector.evaluation_element
+            );
+
+            // client: unblind and finalize
+            let unblinded = evaluated * blind.invert().as_ref();
+            let output = finalize(
+                vector.input,
+                &NistP384::serialize_element(&unblinded).unwrap(),
+            );
+            assert_eq!(output, vector.output);
+        }
+    }
+
+    #[test]
+    fn element_serialization_strictness() {
+        // the identity has no encoding
+        assert!(NistP384::serialize_element(&ProjectivePoint::IDENTITY).is_err());
+
+        // uncompressed tag byte rejected
+        let element = NistP384::hash_to_group(Mode::Oprf, b"strict").unwrap();
+        let mut bytes = NistP384::serialize_element(&element).unwrap();
+        assert_eq!(NistP384::deserialize_element(&bytes).unwrap(), element);
+
+        bytes[0] = 0x04;
+        assert!(NistP384::deserialize_element(&bytes).is_err());
+
+        // x-coordinate not on the curve / not canonical
+        let mut bad = NistP384::serialize_element(&element).unwrap();
+        bad[1..].copy_from_slice(&[0xff; 48]);
+        assert!(NistP384::deserialize_element(&bad).is_err());
+    }
+
+    #[test]
+    fn hash_to_scalar_modes_distinct() {
+        let a = <NistP384 as VoprfGroup>::hash_to_scalar(Mode::Oprf, b"input").unwrap();
+        let b = <NistP384 as VoprfGroup>::hash_to_scalar(Mode::Voprf, b"input").unwrap();
+        assert_ne!(a, b);
+
+        let g1 = NistP384::hash_to_group(Mode::Oprf, b"input").unwrap();
+        let g2 = NistP384::hash_to_group(Mode::Voprf, b"input").unwrap();
+        assert_ne!(g1, g2);
+        assert!(!bool::from(g1.is_identity()));
+    }
+}
@@ -0,0 +1,90 @@
+//! serde round-trip tests.
+
+#![cfg(all(feature = "arithmetic", feature = "serde"))]
+
+use elliptic_curve::{group::GroupEncoding, sec1::ToEncodedPoint, PrimeField};
+use hex_literal::hex;
+use p384::{AffinePoint, NonZeroScalar, ProjectivePoint, Scalar};
+
+/// Private scalar for the test values (RFC 6979 A.2.6 key).
+const D: [u8; 48] = hex!(
+    "6b9d3dad2e1b8c1c05b19875b6659f4de23c3b667bf297ba9aa47740787137d8
+     96d5724e4c70a825f872c9ea60d2edf5"
+);
+
+fn roundtrip<T>(value: &T) -> (T, T)
+where
+    T: serde::Serialize + for<'de> serde::Deserialize<'de>,
+{
+    let json = serde_json::to_string(value).unwrap();
+    let from_json = serde_json::from_str(&json).unwrap();
+
+    let bytes = bincode::serialize(value).unwrap();
+    let from_bincode = bincode::deserialize(&bytes).unwrap();
+
+    (from_json, from_bincode)
+}
+
+#[test]
+fn scalar_roundtrip() {
+    let scalar = Scalar::from_repr(D.into()).unwrap();
+    let (json, bin) = roundtrip(&scalar);
+    assert_eq!(json, scalar);
+    assert_eq!(bin, scalar);
+
+    // out-of-range (>= n) rejected
+    let too_big = serde_json::to_string(&hex::encode([0xffu8; 48])).unwrap();
+    assert!(serde_json::from_str::<Scalar>(&too_big).is_err());
+}
+
+#[test]
+fn non_zero_scalar_roundtrip() {
+    let scalar = NonZeroScalar::new(Scalar::from_repr(D.into()).unwrap()).unwrap();
+    let (json, bin) = roundtrip(&scalar);
+    assert_eq!(json.to_bytes(), scalar.to_bytes());
+    assert_eq!(bin.to_bytes(), scalar.to_bytes());
+
+    // zero rejected
+    let zero = serde_json::to_string(&hex::encode([0u8; 48])).unwrap();
+    assert!(serde_json::from_str::<NonZeroScalar>(&zero).is_err());
+}
+
+#[test]
+fn projective_point_roundtrip() {
+    let point = ProjectivePoint::GENERATOR * Scalar::from_repr(D.into()).unwrap();
+    let (json, bin) = roundtrip(&point);
+    assert_eq!(json, point);
+    assert_eq!(bin, point);
+
+    // serialized form is the SEC1 compressed encoding
+    let json = serde_json::to_string(&point).unwrap();
+    let expected = point.to_affine().to_encoded_point(true);
+    assert!(json
+        .to_lowercase()
+        .contains(&hex::encode(expected.as_bytes())));
+
+    // off-curve x rejected
+    let off_curve = serde_json::to_string(&format!("02{}", hex::encode([0xffu8; 48]))).unwrap();
+    assert!(serde_json::from_str::<ProjectivePoint>(&off_curve).is_err());
+
+    // the identity round-trips for the bare point type
+    let identity_json = serde_json::to_string(&ProjectivePoint::IDENTITY).unwrap();
+    assert_eq!(
+        serde_json::from_str::<ProjectivePoint>(&identity_json).unwrap(),
+        ProjectivePoint::IDENTITY
+    );
+}
+
+#[test]
+fn affine_point_roundtrip() {
+    let point = (ProjectivePoint::GENERATOR * Scalar::from_repr(D.into()).unwrap()).to_affine();
+    let (json, bin) = roundtrip(&point);
+    assert_eq!(json, point);
+    assert_eq!(bin, point);
+
+    // garbage length rejected
+    let short = serde_json::to_string(&hex::encode([2u8; 10])).unwrap();
+    assert!(serde_json::from_str::<AffinePoint>(&short).is_err());
+
+    let _ = point.to_bytes();
+}
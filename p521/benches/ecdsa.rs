@@ -0,0 +1,27 @@
+//! End-to-end ECDSA benchmarks.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use p521::ecdsa::{
+    signature::hazmat::{PrehashSigner, PrehashVerifier},
+    Signature, SigningKey,
+};
+use rand_core::OsRng;
+
+fn bench_ecdsa(c: &mut Criterion) {
+    let signing_key = SigningKey::random(&mut OsRng);
+    let verifying_key = signing_key.verifying_key();
+    let prehash = [0xabu8; 64];
+    let signature: Signature = signing_key.sign_prehash(&prehash).unwrap();
+
+    let mut group = c.benchmark_group("ecdsa");
+    group.bench_function("sign_prehash", |b| {
+        b.iter(|| signing_key.sign_prehash(&prehash).unwrap())
+    });
+    group.bench_function("verify_prehash", |b| {
+        b.iter(|| verifying_key.verify_prehash(&prehash, &signature).unwrap())
+    });
+    group.finish();
+}
+
+criterion_group!(benches, bench_ecdsa);
+criterion_main!(benches);
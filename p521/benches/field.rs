@@ -0,0 +1,19 @@
+//! Field arithmetic benchmarks.
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use elliptic_curve::{rand_core::OsRng, Field};
+use p521::FieldElement;
+
+fn bench_field(c: &mut Criterion) {
+    let x = FieldElement::random(&mut OsRng);
+    let y = FieldElement::random(&mut OsRng);
+
+    let mut group = c.benchmark_group("field operations");
+    group.bench_function("mul", |b| b.iter(|| x * y));
+    group.bench_function("square", |b| b.iter(|| x.square()));
+    group.bench_function("invert", |b| b.iter(|| x.invert()));
+    group.finish();
+}
+
+criterion_group!(benches, bench_field);
+criterion_main!(benches);
@@ -2,6 +2,13 @@
 //!
 //! Arithmetic implementations have been synthesized using fiat-crypto.
 //!
+//! The synthesized code is the *unsaturated Solinas* form specialized for
+//! p = 2^521 − 1: elements are nine 58-bit limbs with no Montgomery
+//! conversion, and `fiat_p521_carry_mul`/`fiat_p521_carry_square` fold the
+//! high product limbs straight back in via the Mersenne identity
+//! `2^521 ≡ 1 (mod p)` — the shift-and-add reduction the special prime
+//! admits, not a generic Montgomery backend.
+//!
 //! # License
 //!
 //! Copyright (c) 2015-2020 the fiat-crypto authors
@@ -437,12 +444,10 @@ impl Field for FieldElement {
         Self::ZERO.ct_eq(self)
     }
 
-    #[must_use]
     fn square(&self) -> Self {
         self.square()
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         self.double()
     }
@@ -631,7 +636,7 @@ impl Neg for FieldElement {
 
 impl Sum for FieldElement {
     fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Add::add).unwrap_or(Self::ZERO)
+        iter.reduce(Add::add).unwrap_or(Self::ZERO)
     }
 }
 
@@ -643,7 +648,7 @@ impl<'a> Sum<&'a FieldElement> for FieldElement {
 
 impl Product for FieldElement {
     fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
-        iter.reduce(core::ops::Mul::mul).unwrap_or(Self::ONE)
+        iter.reduce(Mul::mul).unwrap_or(Self::ONE)
     }
 }
 
@@ -689,6 +694,72 @@ mod tests {
     impl_field_sqrt_tests!(FieldElement);
     impl_primefield_tests!(FieldElement, T);
 
+    /// Differential check of the unsaturated-limb arithmetic against an
+    /// independent generic big-integer implementation, over boundary
+    /// values and random elements.
+    #[test]
+    fn differential_against_generic_modular_arithmetic() {
+        use super::MODULUS;
+        use elliptic_curve::{
+            bigint::{
+                modular::runtime_mod::{DynResidue, DynResidueParams},
+                Encoding, NonZero, U576,
+            },
+            rand_core::OsRng,
+            Field,
+        };
+
+        let p = NonZero::new(MODULUS).unwrap();
+        let params = DynResidueParams::new(&p);
+
+        let to_uint = |fe: &FieldElement| {
+            let mut padded = [0u8; 72];
+            padded[6..].copy_from_slice(&fe.to_bytes());
+            U576::from_be_bytes(padded)
+        };
+        let from_residue = |r: DynResidue<9>| {
+            let bytes = r.retrieve().to_be_bytes();
+            let mut fe_bytes = crate::FieldBytes::default();
+            fe_bytes.copy_from_slice(&bytes[6..]);
+            FieldElement::from_bytes(&fe_bytes).unwrap()
+        };
+
+        let boundary = [
+            FieldElement::ZERO,
+            FieldElement::ONE,
+            FieldElement::ONE.neg(), // p - 1
+            FieldElement::from_u64(u64::MAX),
+        ];
+        let mut cases = [FieldElement::ZERO; 104];
+        cases[..4].copy_from_slice(&boundary);
+        for slot in cases.iter_mut().skip(4) {
+            *slot = FieldElement::random(&mut OsRng);
+        }
+
+        for (i, a) in cases.iter().enumerate() {
+            // pair each element with its successor (wrapping) for binary ops
+            let b = &cases[(i + 1) % cases.len()];
+            let (ra, rb) = (
+                DynResidue::new(&to_uint(a), params),
+                DynResidue::new(&to_uint(b), params),
+            );
+
+            assert_eq!(a.multiply(b), from_residue(ra * rb), "mul case {i}");
+            assert_eq!(a.square(), from_residue(ra * ra), "square case {i}");
+            assert_eq!(a.add(b), from_residue(ra + rb), "add case {i}");
+            assert_eq!(a.sub(b), from_residue(ra - rb), "sub case {i}");
+            assert_eq!(a.neg(), from_residue(-ra), "neg case {i}");
+
+            if !bool::from(a.is_zero()) {
+                assert_eq!(
+                    a.invert().unwrap(),
+                    from_residue(ra.invert().0),
+                    "invert case {i}"
+                );
+            }
+        }
+    }
+
     /// Regression test for RustCrypto/elliptic-curves#965
     #[test]
     fn decode_invalid_field_element_returns_err() {
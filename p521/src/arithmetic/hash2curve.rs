@@ -117,6 +117,35 @@ mod tests {
     use proptest::{num, prelude::ProptestConfig, proptest};
     use sha2::Sha512;
 
+    /// Each field element draws `L = 98 = ceil((521 + 256) / 8)` bytes of
+    /// expander output (k = 256 security parameter); an off-by-one here
+    /// would bias the distribution.
+    #[test]
+    fn expansion_length_rule() {
+        use elliptic_curve::generic_array::typenum::Unsigned;
+
+        assert_eq!(<FieldElement as FromOkm>::Length::USIZE, 98);
+        assert_eq!(<Scalar as FromOkm>::Length::USIZE, 98);
+    }
+
+    /// Outputs are always valid non-identity curve points.
+    #[test]
+    fn outputs_on_curve_and_non_identity() {
+        use elliptic_curve::group::Group;
+
+        const DST: &[u8] = b"P521_XMD:SHA-512_SSWU_RO_PROPTEST";
+
+        proptest!(ProptestConfig::with_cases(50), |(msg in proptest::collection::vec(num::u8::ANY, 0..64))| {
+            let point = NistP521::hash_from_bytes::<ExpandMsgXmd<Sha512>>(&[&msg], &[DST]).unwrap();
+            assert!(!bool::from(point.is_identity()));
+
+            // SEC1 round-trip checks the curve equation
+            let affine = point.to_affine();
+            let encoded = affine.to_encoded_point(false);
+            assert_eq!(crate::AffinePoint::try_from(&encoded).unwrap(), affine);
+        });
+    }
+
     #[test]
     fn params() {
         let params = <FieldElement as OsswuMap>::PARAMS;
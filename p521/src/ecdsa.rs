@@ -41,26 +41,30 @@
 
 pub use ecdsa_core::signature::{self, Error, Result};
 
+#[cfg(feature = "ecdsa")]
+pub use ecdsa_core::RecoveryId;
+
 #[cfg(feature = "ecdsa")]
 use {
     crate::{AffinePoint, EncodedPoint, FieldBytes, NonZeroScalar, Scalar},
     ecdsa_core::{
         hazmat::{bits2field, sign_prehashed, SignPrimitive, VerifyPrimitive},
         signature::{
-            hazmat::{PrehashVerifier, RandomizedPrehashSigner},
+            digest::FixedOutput,
+            hazmat::{PrehashSigner, PrehashVerifier, RandomizedPrehashSigner},
             rand_core::CryptoRngCore,
-            RandomizedSigner, Verifier,
+            DigestSigner, DigestVerifier, RandomizedSigner, Signer, Verifier,
         },
     },
-    elliptic_curve::Field,
+    elliptic_curve::{
+        bigint::U576, ops::Reduce, subtle::ConstantTimeLess, Curve, Field, PrimeField,
+    },
+    hmac::{Hmac, Mac},
     sha2::{Digest, Sha512},
 };
 
 #[cfg(all(feature = "ecdsa", feature = "getrandom"))]
-use {
-    ecdsa_core::signature::{hazmat::PrehashSigner, Signer},
-    rand_core::OsRng,
-};
+use rand_core::OsRng;
 
 use super::NistP521;
 
@@ -70,6 +74,94 @@ pub type Signature = ecdsa_core::Signature<NistP521>;
 /// ECDSA/P-521 signature (ASN.1 DER encoded)
 pub type DerSignature = ecdsa_core::der::Signature<NistP521>;
 
+/// Convert a message hash to a 66-byte field element per [SEC1] § 4.1.3:
+/// take the leftmost 521 bits of the input bit string. Inputs of at most
+/// 65 bytes are left-padded (their value is below 2^521 already); longer
+/// inputs are truncated to 66 bytes and right-shifted by 7 bits, the step
+/// the byte-oriented `bits2field` cannot express for a 521-bit order.
+///
+/// [SEC1]: https://www.secg.org/sec1-v2.pdf
+#[cfg(feature = "ecdsa")]
+fn prepare_prehash(prehash: &[u8]) -> Result<FieldBytes> {
+    if prehash.len() <= 65 {
+        return bits2field::<NistP521>(prehash);
+    }
+
+    let mut bytes = FieldBytes::default();
+    bytes.copy_from_slice(&prehash[..66]);
+
+    let mut carry = 0u8;
+    for byte in bytes.iter_mut() {
+        let next_carry = *byte << 1;
+        *byte = (*byte >> 7) | carry;
+        carry = next_carry;
+    }
+
+    Ok(bytes)
+}
+
+/// [RFC 6979] deterministic nonce generation for the 521-bit order with
+/// HMAC-SHA-512.
+///
+/// The generic `rfc6979` crate requires the HMAC output length to equal
+/// the field size, which no standard hash satisfies for P-521; this is the
+/// § 3.2 procedure for `qlen = 521 > hlen = 512`: each candidate is built
+/// from two HMAC blocks and the leftmost 521 of those 1024 bits are kept.
+///
+/// [RFC 6979]: https://datatracker.ietf.org/doc/html/rfc6979
+#[cfg(feature = "ecdsa")]
+fn rfc6979_nonce(x: &FieldBytes, z: &FieldBytes) -> Scalar {
+    // bits2octets: reduce the (already bits2int-converted) digest mod n
+    let h1 = <Scalar as Reduce<U576>>::reduce_bytes(z).to_bytes();
+
+    let hmac = |key: &[u8], chunks: &[&[u8]]| -> [u8; 64] {
+        // HMAC-SHA-512 is infallible for any key size
+        #[allow(clippy::unwrap_used)]
+        let mut mac = <Hmac<Sha512> as Mac>::new_from_slice(key).unwrap();
+        for chunk in chunks {
+            mac.update(chunk);
+        }
+        mac.finalize().into_bytes().into()
+    };
+
+    let mut v = [0x01u8; 64];
+    let mut k = [0x00u8; 64];
+
+    k = hmac(&k, &[&v, &[0x00], x, &h1]);
+    v = hmac(&k, &[&v]);
+    k = hmac(&k, &[&v, &[0x01], x, &h1]);
+    v = hmac(&k, &[&v]);
+
+    loop {
+        // qlen = 521 needs two 512-bit HMAC outputs
+        v = hmac(&k, &[&v]);
+        let mut t = [0u8; 128];
+        t[..64].copy_from_slice(&v);
+        v = hmac(&k, &[&v]);
+        t[64..].copy_from_slice(&v);
+
+        // bits2int: leftmost 521 bits of the 1024-bit string
+        let mut candidate = FieldBytes::default();
+        candidate.copy_from_slice(&t[..66]);
+        let mut carry = 0u8;
+        for byte in candidate.iter_mut() {
+            let next_carry = *byte << 1;
+            *byte = (*byte >> 7) | carry;
+            carry = next_carry;
+        }
+
+        let mut padded = [0u8; 72];
+        padded[6..].copy_from_slice(&candidate);
+        let candidate = U576::from_be_slice(&padded);
+        if bool::from(candidate.ct_lt(&NistP521::ORDER)) && candidate != U576::ZERO {
+            return Scalar::from_uint_unchecked(candidate);
+        }
+
+        k = hmac(&k, &[&v, &[0x00]]);
+        v = hmac(&k, &[&v]);
+    }
+}
+
 #[cfg(feature = "ecdsa")]
 impl SignPrimitive<NistP521> for Scalar {}
 
@@ -115,10 +207,34 @@ impl SigningKey {
     }
 
     /// Get the [`VerifyingKey`] which corresponds to this [`SigningKey`].
-    #[cfg(feature = "verifying")]
     pub fn verifying_key(&self) -> VerifyingKey {
         VerifyingKey::from(self)
     }
+
+    /// Sign the given 66-byte (or shorter, left-padded) prehash, returning
+    /// the signature together with its [`RecoveryId`].
+    ///
+    /// On P-521 the field and group orders differ by only ~2^259 out of
+    /// ~2^521, so the reduced-x recovery bit (`RecoveryId` bit 1) is set
+    /// with probability ~2^-262 per nonce: it cannot occur in practice,
+    /// but both signing and [`VerifyingKey::recover_from_prehash`] handle
+    /// it generically rather than assuming it away.
+    #[cfg(feature = "getrandom")]
+    pub fn sign_prehash_recoverable(&self, prehash: &[u8]) -> Result<(Signature, RecoveryId)> {
+        self.sign_prehash_recoverable_with_rng(&mut OsRng, prehash)
+    }
+
+    /// Sign the given prehash with nonce entropy from the provided RNG,
+    /// returning the signature together with its [`RecoveryId`].
+    pub fn sign_prehash_recoverable_with_rng(
+        &self,
+        rng: &mut impl CryptoRngCore,
+        prehash: &[u8],
+    ) -> Result<(Signature, RecoveryId)> {
+        let z = prepare_prehash(prehash)?;
+        let k = Scalar::random(rng);
+        sign_prehashed(self.0.as_nonzero_scalar().as_ref(), k, &z)
+    }
 }
 
 #[cfg(feature = "ecdsa")]
@@ -128,10 +244,18 @@ impl From<ecdsa_core::SigningKey<NistP521>> for SigningKey {
     }
 }
 
-#[cfg(all(feature = "ecdsa", feature = "getrandom"))]
+/// Sign the given prehash deterministically per [RFC 6979] with
+/// HMAC-SHA-512, applying the SEC1 § 4.1.3 truncation rule to prehashes
+/// longer than 65 bytes.
+///
+/// [RFC 6979]: https://datatracker.ietf.org/doc/html/rfc6979
+#[cfg(feature = "ecdsa")]
 impl PrehashSigner<Signature> for SigningKey {
     fn sign_prehash(&self, prehash: &[u8]) -> Result<Signature> {
-        self.sign_prehash_with_rng(&mut OsRng, prehash)
+        let z = prepare_prehash(prehash)?;
+        let x = self.0.as_nonzero_scalar();
+        let k = rfc6979_nonce(&x.to_repr(), &z);
+        sign_prehashed(x.as_ref(), k, &z).map(|sig| sig.0)
     }
 }
 
@@ -142,7 +266,7 @@ impl RandomizedPrehashSigner<Signature> for SigningKey {
         rng: &mut impl CryptoRngCore,
         prehash: &[u8],
     ) -> Result<Signature> {
-        let z = bits2field::<NistP521>(prehash)?;
+        let z = prepare_prehash(prehash)?;
         let k = Scalar::random(rng);
         sign_prehashed(self.0.as_nonzero_scalar().as_ref(), k, &z).map(|sig| sig.0)
     }
@@ -155,10 +279,20 @@ impl RandomizedSigner<Signature> for SigningKey {
     }
 }
 
-#[cfg(all(feature = "ecdsa", feature = "getrandom"))]
+#[cfg(feature = "ecdsa")]
 impl Signer<Signature> for SigningKey {
     fn try_sign(&self, msg: &[u8]) -> Result<Signature> {
-        self.try_sign_with_rng(&mut OsRng, msg)
+        self.sign_prehash(&Sha512::digest(msg))
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl<D> DigestSigner<D, Signature> for SigningKey
+where
+    D: Digest + FixedOutput,
+{
+    fn try_sign_digest(&self, msg_digest: D) -> Result<Signature> {
+        self.sign_prehash(&msg_digest.finalize_fixed())
     }
 }
 
@@ -197,6 +331,23 @@ impl VerifyingKey {
     pub fn as_affine(&self) -> &AffinePoint {
         self.0.as_affine()
     }
+
+    /// Recover the public key from a signature and [`RecoveryId`] over the
+    /// given prehash, handling the 66-byte big-endian scalar encodings
+    /// (shorter prehashes are left-padded, longer ones truncated, per
+    /// `bits2field`).
+    pub fn recover_from_prehash(
+        prehash: &[u8],
+        signature: &Signature,
+        recovery_id: RecoveryId,
+    ) -> Result<Self> {
+        ecdsa_core::VerifyingKey::recover_from_prehash(
+            &prepare_prehash(prehash)?,
+            signature,
+            recovery_id,
+        )
+        .map(Into::into)
+    }
 }
 
 #[cfg(feature = "ecdsa")]
@@ -216,7 +367,17 @@ impl From<ecdsa_core::VerifyingKey<NistP521>> for VerifyingKey {
 #[cfg(feature = "ecdsa")]
 impl PrehashVerifier<Signature> for VerifyingKey {
     fn verify_prehash(&self, prehash: &[u8], signature: &Signature) -> Result<()> {
-        self.0.verify_prehash(prehash, signature)
+        self.0.verify_prehash(&prepare_prehash(prehash)?, signature)
+    }
+}
+
+#[cfg(feature = "ecdsa")]
+impl<D> DigestVerifier<D, Signature> for VerifyingKey
+where
+    D: Digest + FixedOutput,
+{
+    fn verify_digest(&self, msg_digest: D, signature: &Signature) -> Result<()> {
+        self.verify_prehash(&msg_digest.finalize_fixed(), signature)
     }
 }
 
@@ -229,7 +390,103 @@ impl Verifier<Signature> for VerifyingKey {
 
 #[cfg(all(test, feature = "ecdsa", feature = "getrandom"))]
 mod tests {
-    // TODO(tarcieri): RFC6979 support + test vectors
+    /// RFC 6979 deterministic signing with SHA-512 (A.2.7 key), verified
+    /// against OpenSSL and an independent implementation of the RFC 6979
+    /// `qlen = 521 > hlen` procedure.
+    #[allow(clippy::unwrap_used)]
+    mod rfc6979 {
+        use crate::ecdsa::{
+            signature::{
+                hazmat::{PrehashSigner, PrehashVerifier},
+                DigestSigner, DigestVerifier, Signer,
+            },
+            Signature, SigningKey,
+        };
+        use hex_literal::hex;
+        use sha2::{Digest, Sha512};
+
+        fn signing_key() -> SigningKey {
+            SigningKey::from_slice(&hex!(
+                "00fad06daa62ba3b25d2fb40133da757205de67f5bb0018fee8c86e1b68c7e75
+                 caa896eb32f1f47c70855836a6d16fcc1466f6d8fbec67db89ec0c08b0e996b8
+                 3538"
+            ))
+            .unwrap()
+        }
+
+        #[test]
+        fn deterministic_signing_vectors() {
+            let sk = signing_key();
+
+            let signature: Signature = sk.sign(b"sample");
+            assert_eq!(
+                signature.to_bytes().as_slice(),
+                &hex!(
+                    "00c328fafcbd79dd77850370c46325d987cb525569fb63c5d3bc53950e6d4c5f
+                     174e25a1ee9017b5d450606add152b534931d7d4e8455cc91f9b15bf05ec36e3
+                     77fa00617cce7cf5064806c467f678d3b4080d6f1cc50af26ca209417308281b
+                     68af282623eaa63e5b5c0723d8b8c37ff0777b1a20f8ccb1dccc43997f1ee0e4
+                     4da4a67a"
+                )
+            );
+
+            let signature: Signature = sk.sign(b"test");
+            assert_eq!(
+                signature.to_bytes().as_slice(),
+                &hex!(
+                    "013e99020abf5cee7525d16b69b229652ab6bdf2affcaef38773b4b7d08725f1
+                     0cdb93482fdcc54edcee91eca4166b2a7c6265ef0ce2bd7051b7cef945babd47
+                     ee6d01fbd0013c674aa79cb39849527916ce301c66ea7ce8b80682786ad60f98
+                     f7e78a19ca69eff5c57400e3b3a0ad66ce0978214d13baf4e9ac60752f7b155e
+                     2de4dce3"
+                )
+            );
+        }
+
+        // Two 66-byte prehashes that agree in their leftmost 521 bits must
+        // produce identical signatures: SEC1 § 4.1.3 discards the low 7
+        // bits, the step the byte-oriented generic conversion misses.
+        #[test]
+        fn sec1_truncation_of_oversized_prehashes() {
+            let sk = signing_key();
+            let vk = sk.verifying_key();
+
+            let mut a = [0x37u8; 66];
+            a[65] = 0x80;
+            let mut b = a;
+            b[65] |= 0x7f;
+
+            let sig_a: Signature = sk.sign_prehash(&a).unwrap();
+            let sig_b: Signature = sk.sign_prehash(&b).unwrap();
+            assert_eq!(sig_a, sig_b);
+            vk.verify_prehash(&a, &sig_a).unwrap();
+            vk.verify_prehash(&b, &sig_a).unwrap();
+
+            // a difference within the leftmost 521 bits does change it
+            let mut c = a;
+            c[0] ^= 0x80;
+            let sig_c: Signature = sk.sign_prehash(&c).unwrap();
+            assert_ne!(sig_a, sig_c);
+            assert!(vk.verify_prehash(&c, &sig_a).is_err());
+        }
+
+        // 64-byte SHA-512 digests and 66-byte prehashes are both accepted
+        // through the digest and prehash entry points
+        #[test]
+        fn digest_and_prehash_entry_points_agree() {
+            let sk = signing_key();
+            let vk = sk.verifying_key();
+
+            let via_digest: Signature = sk.sign_digest(Sha512::new_with_prefix(b"sample"));
+            let via_prehash: Signature = sk.sign_prehash(&Sha512::digest(b"sample")).unwrap();
+            let via_message: Signature = sk.sign(b"sample");
+            assert_eq!(via_digest, via_prehash);
+            assert_eq!(via_digest, via_message);
+
+            vk.verify_digest(Sha512::new_with_prefix(b"sample"), &via_digest)
+                .unwrap();
+        }
+    }
 
     mod sign {
         use crate::{test_vectors::ecdsa::ECDSA_TEST_VECTORS, NistP521};
@@ -241,6 +498,59 @@ mod tests {
         ecdsa_core::new_verification_test!(NistP521, ECDSA_TEST_VECTORS);
     }
 
+    mod recovery {
+        use crate::ecdsa::{SigningKey, VerifyingKey};
+        use ecdsa_core::signature::hazmat::PrehashVerifier;
+        use rand_core::OsRng;
+
+        #[test]
+        fn round_trips_for_random_keys() {
+            let mut seen = [false; 2];
+
+            for i in 0..50u32 {
+                let signing_key = SigningKey::random(&mut OsRng);
+                let mut prehash = [0u8; 66];
+                prehash[..4].copy_from_slice(&i.to_be_bytes());
+
+                let (signature, recovery_id) =
+                    signing_key.sign_prehash_recoverable(&prehash).unwrap();
+                // the reduced-x bit is unreachable in practice (~2^-262)
+                assert!(!recovery_id.is_x_reduced());
+                seen[usize::from(recovery_id.to_byte())] = true;
+
+                let recovered =
+                    VerifyingKey::recover_from_prehash(&prehash, &signature, recovery_id).unwrap();
+                assert_eq!(
+                    recovered.to_encoded_point(true),
+                    signing_key.verifying_key().to_encoded_point(true)
+                );
+            }
+
+            // both y parities occur over 50 random nonces
+            assert!(seen[0] && seen[1]);
+        }
+
+        // bits2field handling: 64-byte SHA-512 prehashes (left-padded) and
+        // 66-byte prehashes whose value exceeds the group order must both
+        // round-trip
+        #[test]
+        fn oversized_and_padded_prehashes() {
+            let signing_key = SigningKey::random(&mut OsRng);
+
+            for prehash in [&[0xffu8; 66][..], &[0xffu8; 64][..], &[0x01u8; 48][..]] {
+                let (signature, recovery_id) =
+                    signing_key.sign_prehash_recoverable(prehash).unwrap();
+                let recovered =
+                    VerifyingKey::recover_from_prehash(prehash, &signature, recovery_id).unwrap();
+                assert_eq!(
+                    recovered.to_encoded_point(true),
+                    signing_key.verifying_key().to_encoded_point(true)
+                );
+                recovered.verify_prehash(prehash, &signature).unwrap();
+            }
+        }
+    }
+
     mod wycheproof {
         use crate::{
             ecdsa::{Signature, Verifier, VerifyingKey},
@@ -40,6 +40,9 @@ pub mod test_vectors;
 #[cfg(feature = "arithmetic")]
 pub use arithmetic::{scalar::Scalar, AffinePoint, ProjectivePoint};
 
+#[cfg(feature = "expose-field")]
+pub use arithmetic::field::FieldElement;
+
 pub use elliptic_curve::{self, bigint::U576};
 
 #[cfg(feature = "pkcs8")]
@@ -109,6 +112,10 @@ pub type PublicKey = elliptic_curve::PublicKey<NistP521>;
 /// NIST P-521 secret key.
 pub type SecretKey = elliptic_curve::SecretKey<NistP521>;
 
+/// Bit representation of a NIST P-521 scalar field element.
+#[cfg(feature = "bits")]
+pub type ScalarBits = elliptic_curve::scalar::ScalarBits<NistP521>;
+
 #[cfg(feature = "voprf")]
 impl elliptic_curve::VoprfParameters for NistP521 {
     /// See <https://www.ietf.org/archive/id/draft-irtf-cfrg-voprf-19.html#section-4.5-1>.
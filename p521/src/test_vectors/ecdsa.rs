@@ -11,7 +11,6 @@ use hex_literal::hex;
 ///
 /// The `m` field contains a SHA-512 prehash of the `Msg` field in the
 /// original `SigTen.txt`.
-
 pub const ECDSA_TEST_VECTORS: &[TestVector] = &[
      TestVector {
          m: &hex!("000065f83408092261bda599389df03382c5be01a81fe00a36f3f4bb6541263f801627c440e50809712b0cace7c217e6e5051af81de9bfec3204dcd63c4f9a741047"),
@@ -58,6 +58,15 @@ where
         infinity: 0,
     };
 
+    /// Create a point from its raw affine coordinates.
+    ///
+    /// Intended for defining precomputed protocol constants (e.g. PAKE
+    /// blinding points) in curve crates; the caller is responsible for
+    /// ensuring the coordinates satisfy the curve equation.
+    pub const fn from_xy_unchecked(x: C::FieldElement, y: C::FieldElement) -> Self {
+        Self { x, y, infinity: 0 }
+    }
+
     /// Is this point the point at infinity?
     pub fn is_identity(&self) -> Choice {
         Choice::from(self.infinity)
@@ -18,9 +18,10 @@ mod affine;
 #[cfg(feature = "dev")]
 mod dev;
 mod field;
+mod mul;
 mod projective;
 
-pub use crate::{affine::AffinePoint, projective::ProjectivePoint};
+pub use crate::{affine::AffinePoint, mul::FixedBaseTable, projective::ProjectivePoint};
 pub use elliptic_curve::{
     self, generic_array, point::Double, Field, FieldBytes, PrimeCurve, PrimeField,
 };
@@ -50,4 +51,16 @@ pub trait PrimeCurveParams:
 
     /// Generator point's affine coordinates: (x, y).
     const GENERATOR: (Self::FieldElement, Self::FieldElement);
+
+    /// Multiply the curve generator by the given scalar.
+    ///
+    /// The default implementation uses generic scalar multiplication; curves
+    /// with a precomputed basepoint table (e.g. a [`FixedBaseTable`]) can
+    /// override this to accelerate it.
+    fn mul_by_generator(scalar: &<Self as CurveArithmetic>::Scalar) -> ProjectivePoint<Self>
+    where
+        ProjectivePoint<Self>: Double,
+    {
+        ProjectivePoint::<Self>::GENERATOR * scalar
+    }
 }
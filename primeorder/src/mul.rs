@@ -0,0 +1,261 @@
+//! Scalar multiplication helpers.
+
+use crate::{PrimeCurveParams, ProjectivePoint};
+use elliptic_curve::{
+    ff::PrimeField,
+    point::Double,
+    subtle::{Choice, ConditionallySelectable, ConstantTimeEq},
+    CurveArithmetic,
+};
+
+/// Lookup table containing the precomputed values `[p, 2p, 3p, ..., 8p]`.
+#[derive(Copy, Clone)]
+struct LookupTable<C: PrimeCurveParams>([ProjectivePoint<C>; 8]);
+
+impl<C> From<&ProjectivePoint<C>> for LookupTable<C>
+where
+    C: PrimeCurveParams,
+{
+    fn from(p: &ProjectivePoint<C>) -> Self {
+        let mut points = [*p; 8];
+        for j in 0..7 {
+            points[j + 1] = p + &points[j];
+        }
+        LookupTable(points)
+    }
+}
+
+impl<C> LookupTable<C>
+where
+    C: PrimeCurveParams,
+{
+    /// Given `-8 <= x <= 8`, returns `x * p` in constant time.
+    fn select(&self, x: i8) -> ProjectivePoint<C> {
+        debug_assert!((-8..=8).contains(&x));
+
+        // Compute xabs = |x|
+        let xmask = x >> 7;
+        let xabs = (x + xmask) ^ xmask;
+
+        // Get an array element in constant time
+        let mut t = ProjectivePoint::IDENTITY;
+        for j in 1..9 {
+            let c = (xabs as u8).ct_eq(&(j as u8));
+            t.conditional_assign(&self.0[j - 1], c);
+        }
+        // Now t == |x| * p.
+
+        let neg_mask = Choice::from((xmask & 1) as u8);
+        t.conditional_assign(&-t, neg_mask);
+        // Now t == x * p.
+
+        t
+    }
+}
+
+/// Precomputed table for accelerating scalar multiplication by a fixed base
+/// point, e.g. the curve generator.
+///
+/// `N` is the number of radix-16 lookup tables, each spaced two radix steps
+/// apart: for a curve with an `L`-byte scalar encoding, `N` must be `L + 1`
+/// (e.g. 33 for 256-bit curves, 49 for 384-bit curves).
+#[derive(Clone)]
+pub struct FixedBaseTable<C: PrimeCurveParams, const N: usize> {
+    tables: [LookupTable<C>; N],
+}
+
+impl<C, const N: usize> FixedBaseTable<C, N>
+where
+    C: PrimeCurveParams,
+    ProjectivePoint<C>: Double,
+{
+    /// Precompute a table for the given base point.
+    pub fn new(base: &ProjectivePoint<C>) -> Self {
+        let mut point = *base;
+        let mut tables = [LookupTable::from(base); N];
+
+        for table in tables.iter_mut() {
+            *table = LookupTable::from(&point);
+            // Tables are spaced by two radix steps to halve the table size.
+            for _ in 0..8 {
+                point = point.double();
+            }
+        }
+
+        Self { tables }
+    }
+
+    /// Compute `k * B` in constant time, where `B` is the base point this
+    /// table was built from.
+    pub fn mul(&self, k: &<C as CurveArithmetic>::Scalar) -> ProjectivePoint<C> {
+        // Digit buffer sized for scalars up to 66 bytes (P-521)
+        let mut buf = [0i8; 133];
+        let digits = &mut buf[..2 * N - 1];
+        radix16_decompose::<C>(k, digits);
+
+        let mut acc = self.tables[N - 1].select(digits[2 * N - 2]);
+        let mut acc2 = ProjectivePoint::IDENTITY;
+
+        for i in (0..N - 1).rev() {
+            acc2 += &self.tables[i].select(digits[i * 2 + 1]);
+            acc += &self.tables[i].select(digits[i * 2]);
+        }
+
+        // This is the price of halving the precomputed table size:
+        // the odd-window accumulator needs a radix-step shift at the end.
+        for _ in 0..4 {
+            acc2 = acc2.double();
+        }
+
+        acc + acc2
+    }
+}
+
+/// Decompose a scalar into radix-16 digits `[a_0, ..., a_D]` such that
+/// `sum(a_j * 2^(j * 4)) == k` with `-8 <= a_j <= 7` (the final digit may
+/// be 8).
+fn radix16_decompose<C>(k: &<C as CurveArithmetic>::Scalar, output: &mut [i8])
+where
+    C: PrimeCurveParams,
+{
+    let bytes = k.to_repr();
+    let bytes = bytes.as_ref();
+    debug_assert_eq!(output.len(), bytes.len() * 2 + 1);
+
+    // Step 1: change radix.
+    // Convert from radix 256 (big-endian bytes) to radix 16 (nibbles)
+    for i in 0..bytes.len() {
+        output[2 * i] = (bytes[bytes.len() - 1 - i] & 0xf) as i8;
+        output[2 * i + 1] = ((bytes[bytes.len() - 1 - i] >> 4) & 0xf) as i8;
+    }
+
+    // Step 2: recenter coefficients from [0,16) to [-8,8)
+    for i in 0..(output.len() - 1) {
+        let carry = (output[i] + 8) >> 4;
+        output[i] -= carry << 4;
+        output[i + 1] += carry;
+    }
+}
+
+/// Maximum scalar size this module supports, in 64-bit limbs (P-521).
+const MAX_LIMBS: usize = 9;
+
+/// Width of the wNAF windows used by [`lincomb_vartime`].
+const WNAF_WIDTH: u32 = 5;
+
+/// Variable-time Strauss–Shamir simultaneous multiplication:
+/// `s1 * p1 + s2 * p2` with width-5 wNAF encodings of both scalars.
+///
+/// ⚠️ WARNING!
+///
+/// Variable time in both scalars; only for use with public values such as
+/// the ECDSA verification equation. Constant-time paths must use
+/// [`LinearCombination::lincomb`].
+///
+/// [`LinearCombination::lincomb`]: elliptic_curve::ops::LinearCombination::lincomb
+pub(crate) fn lincomb_vartime<C>(
+    points_and_scalars: &[(ProjectivePoint<C>, <C as CurveArithmetic>::Scalar); 2],
+) -> ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+    ProjectivePoint<C>: Double,
+{
+    // odd multiples [p, 3p, 5p, ..., 15p] of each point
+    let tables = points_and_scalars.map(|(p, _)| {
+        let twice = p.double();
+        let mut multiples = [p; 8];
+        for j in 0..7 {
+            multiples[j + 1] = multiples[j] + twice;
+        }
+        multiples
+    });
+
+    let mut digits = [[0i8; MAX_LIMBS * 64 + 1]; 2];
+    let mut high = 0;
+    for (digits, (_, scalar)) in digits.iter_mut().zip(points_and_scalars) {
+        high = high.max(wnaf_decompose::<C>(scalar, digits));
+    }
+
+    let mut acc = ProjectivePoint::<C>::IDENTITY;
+    for i in (0..=high).rev() {
+        acc = acc.double();
+
+        for (table, digits) in tables.iter().zip(&digits) {
+            let digit = digits[i];
+            if digit > 0 {
+                acc += table[digit as usize / 2];
+            } else if digit < 0 {
+                acc -= table[(-digit) as usize / 2];
+            }
+        }
+    }
+
+    acc
+}
+
+/// Variable-time width-5 wNAF decomposition; writes signed odd digits in
+/// `-15..=15` to `output` (little-endian positions) and returns the highest
+/// non-zero position.
+fn wnaf_decompose<C>(k: &<C as CurveArithmetic>::Scalar, output: &mut [i8]) -> usize
+where
+    C: PrimeCurveParams,
+{
+    // load the big-endian scalar encoding into little-endian limbs
+    let repr = k.to_repr();
+    let bytes = repr.as_ref();
+    let mut limbs = [0u64; MAX_LIMBS];
+    for (i, byte) in bytes.iter().rev().enumerate() {
+        limbs[i / 8] |= u64::from(*byte) << ((i % 8) * 8);
+    }
+
+    let is_zero = |limbs: &[u64; MAX_LIMBS]| limbs.iter().all(|l| *l == 0);
+    let shift_right_1 = |limbs: &mut [u64; MAX_LIMBS]| {
+        for i in 0..MAX_LIMBS {
+            limbs[i] >>= 1;
+            if i + 1 < MAX_LIMBS {
+                limbs[i] |= limbs[i + 1] << 63;
+            }
+        }
+    };
+
+    let mut pos = 0;
+    let mut high = 0;
+    while !is_zero(&limbs) {
+        if limbs[0] & 1 == 1 {
+            let window = (limbs[0] & ((1 << WNAF_WIDTH) - 1)) as i8;
+            let digit = if window > 1 << (WNAF_WIDTH - 1) {
+                window - (1 << WNAF_WIDTH)
+            } else {
+                window
+            };
+
+            // subtract the digit; a negative digit becomes an addition
+            // with carry propagation
+            if digit > 0 {
+                let (low, borrow) = limbs[0].overflowing_sub(digit as u64);
+                limbs[0] = low;
+                debug_assert!(!borrow);
+            } else {
+                let mut carry = (-digit) as u64;
+                for limb in limbs.iter_mut() {
+                    let (sum, overflow) = limb.overflowing_add(carry);
+                    *limb = sum;
+                    carry = u64::from(overflow);
+                    if carry == 0 {
+                        break;
+                    }
+                }
+            }
+
+            output[pos] = digit;
+            high = pos;
+        } else {
+            output[pos] = 0;
+        }
+
+        shift_right_1(&mut limbs);
+        pos += 1;
+    }
+
+    high
+}
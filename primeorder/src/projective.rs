@@ -18,7 +18,7 @@ use elliptic_curve::{
         Group, GroupEncoding,
     },
     ops::{BatchInvert, Invert, LinearCombination, MulByGenerator},
-    point::Double,
+    point::{Double, NonIdentity},
     rand_core::RngCore,
     sec1::{
         CompressedPoint, EncodedPoint, FromEncodedPoint, ModulusSize, ToEncodedPoint,
@@ -26,12 +26,15 @@ use elliptic_curve::{
     },
     subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption},
     zeroize::DefaultIsZeroes,
-    BatchNormalize, Error, FieldBytes, FieldBytesSize, PublicKey, Result, Scalar,
+    BatchNormalize, CurveArithmetic, Error, FieldBytes, FieldBytesSize, PublicKey, Result, Scalar,
 };
 
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+#[cfg(feature = "serde")]
+use serdect::serde::{de, ser, Deserialize, Serialize};
+
 /// Point on a Weierstrass curve in projective coordinates.
 #[derive(Clone, Copy, Debug)]
 pub struct ProjectivePoint<C: PrimeCurveParams> {
@@ -292,7 +295,6 @@ where
         self.ct_eq(&Self::IDENTITY)
     }
 
-    #[must_use]
     fn double(&self) -> Self {
         Double::double(self)
     }
@@ -391,6 +393,11 @@ where
     let points = points.as_ref();
     let out = out.as_mut();
 
+    // an empty batch would otherwise fail the all-or-nothing batch inversion
+    if points.is_empty() {
+        return;
+    }
+
     for i in 0..points.len() {
         // Even a single zero value will fail inversion for the entire batch.
         // Put a dummy value (above `FieldElement::ONE`) so inversion succeeds
@@ -412,6 +419,34 @@ where
     }
 }
 
+// NOTE: `group::WnafGroup` is deliberately not implemented for
+// `ProjectivePoint`. The `group` crate's `Wnaf` machinery feeds
+// `Scalar::to_repr()` into its w-NAF recoding as little-endian limbs,
+// while every curve in this workspace uses the big-endian SEC1 form for
+// its repr — a conforming impl would therefore make `Wnaf` silently
+// multiply by the byte-reversed scalar. `lincomb_vartime` below (and the
+// per-curve table types) cover the vartime multi-scalar use cases.
+
+impl<C> ProjectivePoint<C>
+where
+    Self: Double,
+    C: PrimeCurveParams,
+{
+    /// Variable-time Strauss–Shamir simultaneous multiplication:
+    /// `s1 * p1 + s2 * p2` with width-5 wNAF encodings of both scalars.
+    ///
+    /// ⚠️ WARNING!
+    ///
+    /// Variable time in both scalars; only for use with public values such
+    /// as the ECDSA verification equation. Constant-time callers must use
+    /// [`LinearCombination::lincomb`].
+    pub fn lincomb_vartime(
+        points_and_scalars: &[(Self, <C as CurveArithmetic>::Scalar); 2],
+    ) -> Self {
+        crate::mul::lincomb_vartime(points_and_scalars)
+    }
+}
+
 impl<C> LinearCombination for ProjectivePoint<C>
 where
     Self: Double,
@@ -425,8 +460,7 @@ where
     C: PrimeCurveParams,
 {
     fn mul_by_generator(scalar: &Self::Scalar) -> Self {
-        // TODO(tarcieri): precomputed basepoint tables
-        Self::generator() * scalar
+        C::mul_by_generator(scalar)
     }
 }
 
@@ -496,6 +530,26 @@ where
     }
 }
 
+impl<C> TryFrom<ProjectivePoint<C>> for NonIdentity<ProjectivePoint<C>>
+where
+    C: PrimeCurveParams,
+{
+    type Error = Error;
+
+    fn try_from(point: ProjectivePoint<C>) -> Result<Self> {
+        Option::from(NonIdentity::new(point)).ok_or(Error)
+    }
+}
+
+impl<C> From<NonIdentity<ProjectivePoint<C>>> for ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+{
+    fn from(point: NonIdentity<ProjectivePoint<C>>) -> Self {
+        point.to_point()
+    }
+}
+
 //
 // Arithmetic trait impls
 //
@@ -769,7 +823,7 @@ where
     }
 }
 
-impl<'a, C> Neg for &'a ProjectivePoint<C>
+impl<C> Neg for &ProjectivePoint<C>
 where
     C: PrimeCurveParams,
 {
@@ -779,3 +833,35 @@ where
         ProjectivePoint::neg(self)
     }
 }
+
+#[cfg(feature = "serde")]
+impl<C> Serialize for ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+    FieldBytesSize<C>: ModulusSize,
+    CompressedPoint<C>: Copy,
+    <UncompressedPointSize<C> as ArrayLength<u8>>::ArrayType: Copy,
+{
+    fn serialize<S>(&self, serializer: S) -> core::result::Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.to_affine().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C> Deserialize<'de> for ProjectivePoint<C>
+where
+    C: PrimeCurveParams,
+    FieldBytes<C>: Copy,
+    FieldBytesSize<C>: ModulusSize,
+    CompressedPoint<C>: Copy,
+{
+    fn deserialize<D>(deserializer: D) -> core::result::Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        AffinePoint::<C>::deserialize(deserializer).map(Self::from)
+    }
+}
@@ -15,7 +15,7 @@
     clippy::cast_sign_loss,
     clippy::cast_possible_truncation,
     clippy::identity_op,
-    clippy::integer_arithmetic,
+    clippy::arithmetic_side_effects,
     clippy::too_many_arguments,
     clippy::unnecessary_cast
 )]
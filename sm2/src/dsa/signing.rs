@@ -183,6 +183,7 @@ impl KeypairRef for SigningKey {
 }
 
 /// Compute a signature using RFC6979 to deterministically derive `k`.
+#[allow(clippy::arithmetic_side_effects)] // modular arithmetic on `Scalar`s
 fn sign_prehash_rfc6979(secret_scalar: &Scalar, prehash: &[u8], data: &[u8]) -> Result<Signature> {
     if prehash.len() != <Sm2 as Curve>::FieldBytesSize::USIZE {
         return Err(Error::new());
@@ -128,6 +128,7 @@ impl VerifyingKey {
 //
 
 impl PrehashVerifier<Signature> for VerifyingKey {
+    #[allow(clippy::arithmetic_side_effects)] // modular arithmetic on `Scalar`s
     fn verify_prehash(&self, prehash: &[u8], signature: &Signature) -> Result<()> {
         if prehash.len() != <Sm2 as Curve>::FieldBytesSize::USIZE {
             return Err(Error::new());
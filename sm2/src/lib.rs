@@ -15,7 +15,7 @@
     clippy::cast_sign_loss,
     clippy::checked_conversions,
     clippy::implicit_saturating_sub,
-    clippy::integer_arithmetic,
+    clippy::arithmetic_side_effects,
     clippy::panic,
     clippy::panic_in_result_fn,
     clippy::unwrap_used,
@@ -128,4 +128,4 @@ impl elliptic_curve::sec1::ValidatePublicKey for Sm2 {}
 
 /// Bit representation of a SM2 scalar field element.
 #[cfg(feature = "bits")]
-pub type ScalarBits = elliptic_curve::ScalarBits<Sm2>;
+pub type ScalarBits = elliptic_curve::scalar::ScalarBits<Sm2>;